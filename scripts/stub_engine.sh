@@ -0,0 +1,24 @@
+#!/bin/sh
+# A minimal engine for testing the protocol: it always plays the first empty square.
+board=""
+while read -r cmd arg; do
+    case "$cmd" in
+        ttt)
+            echo "id name stub"
+            echo "tttok"
+            ;;
+        newgame) board="" ;;
+        position) board="$arg" ;;
+        go)
+            i=1
+            rest="$board"
+            while [ -n "$rest" ] && [ "${rest%"${rest#?}"}" != "-" ]; do
+                rest="${rest#?}"
+                i=$((i + 1))
+            done
+            echo "info string first empty square"
+            echo "bestmove $i"
+            ;;
+        quit) exit 0 ;;
+    esac
+done
@@ -1,11 +1,156 @@
-use std::collections::HashMap;
-use std::convert::TryInto;
 use itertools::Itertools;
+use std::collections::BTreeMap;
 
-const BOARD_SIZE: usize = 9;
 const PLAYER_NONE: u8 = 0;
 const PLAYER_X: u8 = 1;
 const PLAYER_O: u8 = 2;
+// Two bits per cell in a u128
+const MAX_CELLS: usize = 64;
+// Boards up to this size search every permutation for the position mapping
+const EXHAUSTIVE_SEARCH_CELLS: usize = 9;
+const SEARCH_RESTARTS: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Geometry {
+    pub width: usize,
+    pub height: usize,
+    pub win_length: usize,
+}
+
+impl Geometry {
+    pub fn new(width: usize, height: usize, win_length: usize) -> Result<Geometry, String> {
+        if width == 0 || height == 0 || width * height > MAX_CELLS {
+            return Err(format!("boards need between 1 and {} cells", MAX_CELLS));
+        }
+        // The same rule as `MnkState`
+        if win_length == 0 || win_length > width.min(height) {
+            return Err("the win length must fit on the board".to_string());
+        }
+        Ok(Geometry {
+            width,
+            height,
+            win_length,
+        })
+    }
+
+    pub fn cells(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.width == self.height
+    }
+
+    // The smallest unsigned integer type that fits two bits per cell
+    pub fn raw_type(&self) -> &'static str {
+        match self.cells() {
+            0..=16 => "u32",
+            17..=32 => "u64",
+            _ => "u128",
+        }
+    }
+
+    fn binary(&self, value: u128) -> String {
+        format!("{:#0width$b}", value, width = self.cells() * 2 + 2)
+    }
+}
+
+// Every horizontal, vertical and diagonal run of `win_length` cells
+pub fn winning_lines(geometry: &Geometry) -> Vec<Vec<usize>> {
+    let (width, height, k) = (
+        geometry.width as isize,
+        geometry.height as isize,
+        geometry.win_length as isize,
+    );
+    let mut lines = vec![];
+    for (d_row, d_col) in [(0, 1), (1, 0), (1, 1), (1, -1)] {
+        for row in 0..height {
+            for col in 0..width {
+                let end_row = row + d_row * (k - 1);
+                let end_col = col + d_col * (k - 1);
+                if end_row < 0 || end_row >= height || end_col < 0 || end_col >= width {
+                    continue;
+                }
+                let line = (0..k)
+                    .map(|i| ((row + d_row * i) * width + col + d_col * i) as usize)
+                    .collect_vec();
+                if !lines.contains(&line) {
+                    lines.push(line);
+                }
+            }
+        }
+    }
+    lines
+}
+
+// Every row, column and diagonal that runs all the way through a cube of `size` cells a
+// side, numbering cells layer by layer and then row by row
+pub fn cube_winning_lines(size: usize) -> Vec<Vec<usize>> {
+    let n = size as isize;
+    let mut lines = vec![];
+    let directions = (0..3)
+        .map(|_| -1..=1)
+        .multi_cartesian_product()
+        .filter(|d| d.iter().any(|&v| v != 0));
+    for direction in directions {
+        for start in (0..3).map(|_| 0..n).multi_cartesian_product() {
+            let cells = (0..n)
+                .map(|i| {
+                    let coords = start.iter().zip(direction.iter()).map(|(&c, &d)| c + d * i);
+                    coords.collect_vec()
+                })
+                .collect_vec();
+            if cells.iter().flatten().any(|&c| c < 0 || c >= n) {
+                continue;
+            }
+            let line = cells
+                .iter()
+                .map(|c| ((c[0] * n + c[1]) * n + c[2]) as usize)
+                .sorted()
+                .collect_vec();
+            if !lines.contains(&line) {
+                lines.push(line);
+            }
+        }
+    }
+    lines
+}
+
+// All 48 symmetries of the cube as mappings like `rotated_mapping`. The first 8 turn and
+// flip every layer in place, in the same order as `get_equivalent_mappings`, so they line
+// up with the `rotations` and `flip` arguments of `transform`.
+pub fn cube_mappings(size: usize) -> Vec<Vec<i32>> {
+    let layer_cells = size * size;
+    let square = Geometry::new(size, size, size).unwrap();
+    let identity = (0..layer_cells as i32).collect_vec();
+    let mut mappings = get_equivalent_mappings(&identity, &square)
+        .iter()
+        .map(|layer| {
+            (0..size as i32)
+                .flat_map(|l| layer.iter().map(move |&c| l * layer_cells as i32 + c))
+                .collect_vec()
+        })
+        .collect_vec();
+    for axes in (0..3).permutations(3) {
+        for signs in 0..8 {
+            let mut mapping = vec![0; size * layer_cells];
+            for pos in 0..size * layer_cells {
+                let coords = [pos / layer_cells, pos / size % size, pos % size];
+                let moved = (0..3)
+                    .map(|k| match signs >> k & 1 {
+                        0 => coords[axes[k]],
+                        _ => size - 1 - coords[axes[k]],
+                    })
+                    .collect_vec();
+                mapping[(moved[0] * size + moved[1]) * size + moved[2]] = pos as i32;
+            }
+            if !mappings.contains(&mapping) {
+                mappings.push(mapping);
+            }
+        }
+    }
+    mappings
+}
 
 // 012
 // 345
@@ -14,20 +159,15 @@ const PLAYER_O: u8 = 2;
 // 630
 // 741
 // 852
-pub fn rotated_mapping(mapping: &[i32; BOARD_SIZE], repeated_num: u32) -> [i32; BOARD_SIZE] {
-    let mut rotated = [0; 9];
-    let mut temp = mapping.clone();
-    for _ in 0..repeated_num {
-        rotated[0] = temp[6];
-        rotated[1] = temp[3];
-        rotated[2] = temp[0];
-        rotated[3] = temp[7];
-        rotated[4] = temp[4];
-        rotated[5] = temp[1];
-        rotated[6] = temp[8];
-        rotated[7] = temp[5];
-        rotated[8] = temp[2];
-        temp.copy_from_slice(&rotated);
+pub fn rotated_mapping(mapping: &[i32], width: usize, repeated_num: u32) -> Vec<i32> {
+    let mut rotated = mapping.to_vec();
+    for _ in 0..repeated_num % 4 {
+        let temp = rotated.clone();
+        for row in 0..width {
+            for col in 0..width {
+                rotated[row * width + col] = temp[(width - 1 - col) * width + row];
+            }
+        }
     }
     rotated
 }
@@ -36,148 +176,347 @@ pub fn rotated_mapping(mapping: &[i32; BOARD_SIZE], repeated_num: u32) -> [i32;
 // 345
 // 678
 
+// 876
+// 543
+// 210
+pub fn half_turn_mapping(mapping: &[i32]) -> Vec<i32> {
+    mapping.iter().rev().copied().collect()
+}
+
+// 012
+// 345
+// 678
+
 // 678
 // 345
 // 012
+pub fn flipped_mapping(mapping: &[i32], width: usize) -> Vec<i32> {
+    mapping.chunks(width).rev().flatten().copied().collect()
+}
 
-pub fn flipped_mapping(mapping: &[i32; BOARD_SIZE]) -> [i32; BOARD_SIZE] {
-    let mut flipped = [0; BOARD_SIZE];
-    flipped[0] = mapping[6];
-    flipped[1] = mapping[7];
-    flipped[2] = mapping[8];
-    flipped[3] = mapping[3];
-    flipped[4] = mapping[4];
-    flipped[5] = mapping[5];
-    flipped[6] = mapping[0];
-    flipped[7] = mapping[1];
-    flipped[8] = mapping[2];
-    flipped
-}
-
-fn get_equivalent_mappings(mapping: &[i32; BOARD_SIZE]) -> [[i32; BOARD_SIZE]; 8] {
-    [
-        mapping.clone(),
-        rotated_mapping(mapping, 1),
-        rotated_mapping(mapping, 2),
-        rotated_mapping(mapping, 3),
-        flipped_mapping(mapping),
-        rotated_mapping(&flipped_mapping(mapping), 1),
-        rotated_mapping(&flipped_mapping(mapping), 2),
-        rotated_mapping(&flipped_mapping(mapping), 3),
-    ]
+// In the same order as the `rotations` and `flip` arguments of `GameState::transform`.
+// Rectangles only have the half turn, so they get 4 transforms instead of 8.
+fn get_equivalent_mappings(mapping: &[i32], geometry: &Geometry) -> Vec<Vec<i32>> {
+    let flipped = flipped_mapping(mapping, geometry.width);
+    if geometry.is_square() {
+        let width = geometry.width;
+        (0..4)
+            .map(|r| rotated_mapping(mapping, width, r))
+            .chain((0..4).map(|r| rotated_mapping(&flipped, width, r)))
+            .collect()
+    } else {
+        vec![
+            mapping.to_vec(),
+            half_turn_mapping(mapping),
+            flipped.clone(),
+            half_turn_mapping(&flipped),
+        ]
+    }
 }
 
-pub fn get_shift_map(initial_mapping: &[i32; BOARD_SIZE],
-                     transform_mapping: &[i32; BOARD_SIZE]) -> HashMap<i32, u32> {
-    let mut shift_map: HashMap<i32, u32> = HashMap::new();
-    let offsets = initial_mapping.iter()
-        .enumerate()
-        .map(|(i, pos)| (i, i as i32 - transform_mapping.iter().position(|&p| p == *pos).unwrap() as i32));
-    for (board_index, offset) in offsets.clone() {
-        let shift = shift_map.entry(offset).or_insert(0);
-        *shift |= 3u32 << (board_index * 2);
+/// Groups cells by how far their bits move in the raw board under a transform, keyed by
+/// the shift in cells and valued by the mask of internal cells that move that far.
+pub fn get_shift_map(initial_mapping: &[i32], transform_mapping: &[i32]) -> BTreeMap<i32, u128> {
+    let mut destinations = vec![0; initial_mapping.len()];
+    for (i, &pos) in transform_mapping.iter().enumerate() {
+        destinations[pos as usize] = i;
+    }
+    let mut shift_map: BTreeMap<i32, u128> = BTreeMap::new();
+    for &internal in initial_mapping.iter() {
+        let destination = initial_mapping[destinations[internal as usize]];
+        let shift = shift_map.entry(internal - destination).or_insert(0);
+        *shift |= 3u128 << (internal * 2);
     }
     shift_map
 }
 
-fn get_all_shift_maps(mapping: &[i32; BOARD_SIZE]) -> Vec<HashMap<i32, u32>> {
-    let mut shift_maps= vec![];
-    for eq_mapping in get_equivalent_mappings(mapping).iter() {
-        shift_maps.push(get_shift_map(mapping, &eq_mapping));
-    }
-    shift_maps
+fn get_all_shift_maps(mapping: &[i32], geometry: &Geometry) -> Vec<BTreeMap<i32, u128>> {
+    get_equivalent_mappings(mapping, geometry)
+        .iter()
+        .map(|eq_mapping| get_shift_map(mapping, eq_mapping))
+        .collect()
 }
 
-fn score_mapping(mapping: &[i32; BOARD_SIZE]) -> (f32, f32) {
-    // println!("{:?}", mapping);
-    let shift_maps = get_all_shift_maps(mapping);
-    let num_shifts: Vec<f32> = shift_maps.iter().map(
-        |sm| sm.len() as f32).collect();
-    // println!("{:?}", num_shifts);
-    let shift_totals = shift_maps.iter().map(|sm| sm.keys().sum::<i32>() as f32).collect_vec();
+fn score_mapping(mapping: &[i32], geometry: &Geometry) -> (f32, f32) {
+    let shift_maps = get_all_shift_maps(mapping, geometry);
+    let shift_totals = shift_maps
+        .iter()
+        .map(|sm| sm.keys().sum::<i32>() as f32)
+        .collect_vec();
     let t: f32 = shift_totals.iter().map(|v| v.abs()).sum();
-    (t + 9f32, variance(shift_totals))
+    (t + geometry.cells() as f32, variance(shift_totals))
 }
 
 fn variance(num_vec: Vec<f32>) -> f32 {
-    let mean= num_vec.iter().sum::<f32>() / num_vec.len() as f32;
+    let mean = num_vec.iter().sum::<f32>() / num_vec.len() as f32;
     let s = num_vec.iter().map(|n| (n - mean).powf(2.)).sum::<f32>();
     s / (num_vec.len() - 1) as f32
 }
 
-pub fn find_shift_optimized_mapping() -> [i32; BOARD_SIZE] {
-    let mut min_shift_score = f32::INFINITY;
-    let mut min_variance_score = f32::INFINITY;
-    let mut best_mapping = [0; 9];
-    for perm in (0..BOARD_SIZE as i32).permutations(BOARD_SIZE) {
-        let arr_mapping: [i32; 9] = match perm.try_into() {
-            Ok(arr) => arr,
-            Err(arr) => panic!("Permutation {:?} is not the correct length", arr)
-        };
-        let (shift_score, variance_score) = score_mapping(&arr_mapping);
-        println!("{} {} {:?}", shift_score, variance_score, &arr_mapping);
-        if (shift_score < min_shift_score) ||
-            (shift_score == min_shift_score && variance_score < min_variance_score) {
-            min_shift_score = shift_score;
-            min_variance_score = variance_score;
-            best_mapping = arr_mapping;
-        }
-    }
-    return best_mapping
+fn is_better(score: (f32, f32), best: (f32, f32)) -> bool {
+    score.0 < best.0 || (score.0 == best.0 && score.1 < best.1)
 }
 
-pub fn const_str(name: &str, var_type: &str, value: &str) -> String {
-    format!("pub const {}: {} = {};", name, var_type, value)
+// Deterministic xorshift so the generated tables are reproducible
+fn next_random(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    *state
 }
 
-pub fn codegen_is_win_masks(mapping: &[i32; BOARD_SIZE]) -> String {
-    let winning_positions = [
-        vec![0, 1, 2],
-        vec![3, 4, 5],
-        vec![6, 7, 8],
-        vec![0, 3, 6],
-        vec![1, 4, 7],
-        vec![2, 5, 8],
-        vec![0, 4, 8],
-        vec![2, 4, 6],
-    ];
-    let mut win_masks: [u32; 8] = [0; 8];
-    for (i, positions) in winning_positions.iter().enumerate() {
-        let mut pos_mask = 0;
-        for pos in positions {
-            let index = mapping[*pos as usize] as u32;
-            pos_mask |= (PLAYER_X as u32) << (index * 2);
+fn shuffled(cells: usize, state: &mut u64) -> Vec<i32> {
+    let mut mapping = (0..cells as i32).collect_vec();
+    for i in (1..cells).rev() {
+        let j = (next_random(state) % (i as u64 + 1)) as usize;
+        mapping.swap(i, j);
+    }
+    mapping
+}
 
+// Swaps pairs of cells for as long as that improves the score
+fn hill_climb(mut mapping: Vec<i32>, geometry: &Geometry) -> (Vec<i32>, (f32, f32)) {
+    let mut score = score_mapping(&mapping, geometry);
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for (i, j) in (0..mapping.len()).tuple_combinations() {
+            mapping.swap(i, j);
+            let swapped_score = score_mapping(&mapping, geometry);
+            if is_better(swapped_score, score) {
+                score = swapped_score;
+                improved = true;
+            } else {
+                mapping.swap(i, j);
+            }
         }
-        win_masks[i] = pos_mask;
     }
-    let win_str = format!("[{}]", win_masks.iter().map(|wm| format!("{:#020b}", wm)).join(", "));
-    const_str("WIN_MASKS",
-              format!("[u32; {}]", win_masks.len()).as_str(),
-              win_str.as_str())
+    (mapping, score)
+}
 
+/// Finds the cell to bit position mapping that needs the fewest shifts to transform.
+/// Small boards try every permutation. Larger boards cannot (16! or 25! mappings), so
+/// they hill climb over pairwise swaps from the identity and from seeded restarts.
+pub fn find_shift_optimized_mapping(geometry: &Geometry) -> Vec<i32> {
+    let cells = geometry.cells();
+    if cells <= EXHAUSTIVE_SEARCH_CELLS {
+        let mut best_score = (f32::INFINITY, f32::INFINITY);
+        let mut best_mapping = vec![];
+        for perm in (0..cells as i32).permutations(cells) {
+            let score = score_mapping(&perm, geometry);
+            if is_better(score, best_score) {
+                best_score = score;
+                best_mapping = perm;
+            }
+        }
+        return best_mapping;
+    }
+    let mut state = 0x9E37_79B9_7F4A_7C15;
+    let (mut best_mapping, mut best_score) = hill_climb((0..cells as i32).collect(), geometry);
+    for _ in 0..SEARCH_RESTARTS {
+        let (mapping, score) = hill_climb(shuffled(cells, &mut state), geometry);
+        if is_better(score, best_score) {
+            best_mapping = mapping;
+            best_score = score;
+        }
+    }
+    best_mapping
 }
 
+pub fn const_str(name: &str, var_type: &str, value: &str) -> String {
+    format!("pub const {}: {} = {};", name, var_type, value)
+}
 
-fn codegen_transform_shifts(mapping: &[i32; BOARD_SIZE]) -> String {
-    let shifts = get_all_shift_maps(mapping);
+pub fn win_masks(mapping: &[i32], geometry: &Geometry) -> Vec<u128> {
+    winning_lines(geometry)
+        .iter()
+        .map(|positions| {
+            positions.iter().fold(0, |mask, &pos| {
+                mask | (PLAYER_X as u128) << (mapping[pos] as u32 * 2)
+            })
+        })
+        .collect()
+}
+
+pub fn codegen_is_win_masks(mapping: &[i32], geometry: &Geometry) -> String {
+    let win_masks = win_masks(mapping, geometry);
+    let win_str = format!(
+        "[{}]",
+        win_masks.iter().map(|wm| geometry.binary(*wm)).join(", ")
+    );
+    const_str(
+        "WIN_MASKS",
+        format!("[{}; {}]", geometry.raw_type(), win_masks.len()).as_str(),
+        win_str.as_str(),
+    )
+}
+
+fn shift_maps_str(shifts: &[BTreeMap<i32, u128>], geometry: &Geometry) -> Vec<String> {
     let mut transform_strs = vec![];
     for transforms in shifts.iter() {
-        let inner_transform_str = transforms.iter()
-            .map(|(k, v)| format!("({}, {:#020b})", *k, *v))
+        let inner_transform_str = transforms
+            .iter()
+            .map(|(k, v)| format!("({}, {})", *k, geometry.binary(*v)))
             .collect_vec()
             .join(", ");
         let transform_str = format!("&[{}]", inner_transform_str);
         transform_strs.push(transform_str);
     }
+    transform_strs
+}
+
+fn codegen_transform_shifts(mapping: &[i32], geometry: &Geometry) -> String {
+    let transform_strs = shift_maps_str(&get_all_shift_maps(mapping, geometry), geometry);
     let value = format!("[{}]", transform_strs.join(", "));
-    let type_name = format!("[&[(i32, u32)]; {}]", transform_strs.len());
+    let type_name = format!(
+        "[&[(i32, {})]; {}]",
+        geometry.raw_type(),
+        transform_strs.len()
+    );
     const_str("TRANSFORM_SHIFTS", type_name.as_str(), value.as_str())
 }
 
+// Cubes are small enough to number their cells in order, so they skip the mapping search.
+// Their tables are long, so every mask and shift goes on its own line.
+fn codegen_cube(size: usize) -> String {
+    let cells = size * size * size;
+    // Only used to format the masks. Every cube is stored in a u128, like `MnkState`
+    let geometry = Geometry {
+        width: cells,
+        height: 1,
+        win_length: size,
+    };
+    let masks = cube_winning_lines(size)
+        .iter()
+        .map(|line| {
+            line.iter()
+                .fold(0, |mask, &pos| mask | (PLAYER_X as u128) << (pos * 2))
+        })
+        .collect_vec();
+    let identity = (0..cells as i32).collect_vec();
+    let shifts = cube_mappings(size)
+        .iter()
+        .map(|mapping| get_shift_map(&identity, mapping))
+        .collect_vec();
+    let mask_lines = masks
+        .iter()
+        .map(|&m| format!("    {},\n", geometry.binary(m)))
+        .join("");
+    let shift_lines = shifts
+        .iter()
+        .map(|shift_map| {
+            let pairs = shift_map
+                .iter()
+                .map(|(k, v)| format!("        ({}, {}),\n", k, geometry.binary(*v)))
+                .join("");
+            format!("    &[\n{}    ],\n", pairs)
+        })
+        .join("");
+    let prefix = format!("CUBE{}", size);
+    [
+        const_str(
+            &format!("{}_WIN_MASKS", prefix),
+            &format!("[u128; {}]", masks.len()),
+            &format!("[\n{}]", mask_lines),
+        ),
+        const_str(
+            &format!("{}_TRANSFORM_SHIFTS", prefix),
+            &format!("[&[(i32, u128)]; {}]", shifts.len()),
+            &format!("[\n{}]", shift_lines),
+        ),
+    ]
+    .join("\n")
+}
+
+fn parse_geometry(args: &[String]) -> Result<Geometry, String> {
+    if args.is_empty() {
+        return Geometry::new(3, 3, 3);
+    }
+    let dimensions = args
+        .iter()
+        .map(|a| {
+            a.parse::<usize>()
+                .map_err(|_| format!("invalid number {}", a))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match dimensions.as_slice() {
+        [width, height, win_length] => Geometry::new(*width, *height, *win_length),
+        _ => Err("expected WIDTH HEIGHT WIN_LENGTH".to_string()),
+    }
+}
+
+fn usage_error(error: &str) -> ! {
+    eprintln!("{}", error);
+    eprintln!("Usage: codegen [WIDTH HEIGHT WIN_LENGTH]");
+    eprintln!("       codegen cube SIZE...");
+    std::process::exit(1);
+}
+
+fn main() {
+    let args = std::env::args().skip(1).collect_vec();
+    if args.first().map(String::as_str) == Some("cube") {
+        let sizes = args[1..].iter().map(|a| a.parse::<usize>()).collect_vec();
+        if sizes.is_empty() || !sizes.iter().all(|s| matches!(s, Ok(2..=4))) {
+            usage_error("cubes need sizes between 2 and 4");
+        }
+        println!(
+            "// Generated by `codegen cube {}` from `make codegen`. Do not edit by hand.",
+            args[1..].join(" ")
+        );
+        for size in sizes.into_iter().flatten() {
+            println!("{}", codegen_cube(size));
+        }
+        return;
+    }
+    let geometry = match parse_geometry(&args) {
+        Ok(geometry) => geometry,
+        Err(e) => usage_error(&e),
+    };
+    let cells = geometry.cells();
+    let x_bits = (0..cells).fold(0u128, |mask, i| mask | (PLAYER_X as u128) << (i * 2));
+    let optimized_mapping = find_shift_optimized_mapping(&geometry);
+    let win_mask_str = codegen_is_win_masks(&optimized_mapping, &geometry);
+    let transform_shifts_str = codegen_transform_shifts(&optimized_mapping, &geometry);
+    let board_size_str = const_str("BOARD_SIZE", "usize", cells.to_string().as_str());
+    let player_none_str = const_str("PLAYER_NONE", "u8", PLAYER_NONE.to_string().as_str());
+    let player_x_str = const_str("PLAYER_X", "u8", PLAYER_X.to_string().as_str());
+    let player_o_str = const_str("PLAYER_O", "u8", PLAYER_O.to_string().as_str());
+    let x_mask = const_str(
+        "X_BIT_MASK",
+        geometry.raw_type(),
+        geometry.binary(x_bits).as_str(),
+    );
+    let o_mask = const_str(
+        "O_BIT_MASK",
+        geometry.raw_type(),
+        geometry.binary(x_bits << 1).as_str(),
+    );
+    let position_map = const_str(
+        "POSITION_MAP",
+        "[usize; BOARD_SIZE]",
+        format!("{:?}", optimized_mapping).as_str(),
+    );
+    let consts_str = [
+        player_none_str,
+        player_x_str,
+        player_o_str,
+        transform_shifts_str,
+        win_mask_str,
+        board_size_str,
+        x_mask,
+        o_mask,
+        position_map,
+    ]
+    .join("\n");
+    println!("{}", consts_str)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
+    use ttt::game::consts::{TRANSFORM_SHIFTS, WIN_MASKS};
+    use ttt::game::cube_consts::{CUBE3_TRANSFORM_SHIFTS, CUBE4_WIN_MASKS};
 
     #[test]
     fn test_rotated_mapping() {
@@ -189,7 +528,7 @@ mod tests {
             (&[0, 1, 2, 3, 4, 5, 6, 7, 8], 5, [6, 3, 0, 7, 4, 1, 8, 5, 2]),
         ];
         for (initial_mapping, num_rotations, result_mapping) in test_cases {
-            let mapping = rotated_mapping(initial_mapping, num_rotations);
+            let mapping = rotated_mapping(initial_mapping, 3, num_rotations);
             assert_eq!(mapping, result_mapping)
         }
     }
@@ -201,35 +540,143 @@ mod tests {
             (&[8, 7, 4, 1, 0, 3, 2, 5, 6], [2, 5, 6, 1, 0, 3, 8, 7, 4]),
         ];
         for (initial_mapping, result_mapping) in test_cases {
-            let mapping = flipped_mapping(initial_mapping);
+            let mapping = flipped_mapping(initial_mapping, 3);
             assert_eq!(mapping, result_mapping)
         }
     }
-}
 
+    #[test]
+    fn test_winning_lines() {
+        let test_cases = vec![
+            ((3, 3, 3), 8),
+            ((4, 4, 3), 24),
+            ((4, 4, 4), 10),
+            ((5, 5, 4), 28),
+            ((7, 7, 5), 60),
+            ((4, 3, 3), 14),
+        ];
+        for ((width, height, win_length), count) in test_cases {
+            let geometry = Geometry::new(width, height, win_length).unwrap();
+            assert_eq!(winning_lines(&geometry).len(), count);
+        }
+    }
 
+    #[test]
+    fn test_matches_game_state_consts() {
+        // consts.rs was generated with the identity mapping
+        let geometry = Geometry::new(3, 3, 3).unwrap();
+        let mapping = (0..9).collect_vec();
+        let masks: HashSet<u128> = win_masks(&mapping, &geometry).into_iter().collect();
+        let expected: HashSet<u128> = WIN_MASKS.iter().map(|&m| m as u128).collect();
+        assert_eq!(masks, expected);
+        let shift_maps = get_all_shift_maps(&mapping, &geometry);
+        for (shift_map, expected) in shift_maps.iter().zip(TRANSFORM_SHIFTS.iter()) {
+            let expected: BTreeMap<i32, u128> =
+                expected.iter().map(|&(k, v)| (k, v as u128)).collect();
+            assert_eq!(shift_map, &expected);
+        }
+    }
 
-fn main() {
-    let optimized_mapping = find_shift_optimized_mapping();
-    let win_mask_str = codegen_is_win_masks(&optimized_mapping);
-    let transform_shifts_str = codegen_transform_shifts(&optimized_mapping);
-    let board_size_str = const_str("BOARD_SIZE", "usize", "9");
-    let player_none_str = const_str("PLAYER_NONE", "u8", "0");
-    let player_x_str = const_str("PLAYER_X", "u8", "1");
-    let player_o_str = const_str("PLAYER_O", "u8", "2");
-    let x_mask = const_str("X_BIT_MASK", "u32", "0b010101010101010101");
-    let o_mask = const_str("O_BIT_MASK", "u32", "0b101010101010101010");
-    let position_map =  const_str("POSITION_MAP", "[usize; BOARD_SIZE]",
-                                  format!("{:?}", optimized_mapping).as_str());
-    let consts_str = vec!(
-                             player_none_str,
-                             player_x_str,
-                             player_o_str,
-                             transform_shifts_str,
-                             win_mask_str,
-                             board_size_str,
-                             x_mask,
-                             o_mask,
-                             position_map).join("\n");
-    println!("{}", consts_str)
-}
\ No newline at end of file
+    #[test]
+    fn test_shift_maps_with_shuffled_mapping() {
+        let geometry = Geometry::new(4, 4, 3).unwrap();
+        let mut state = 1;
+        let mapping = shuffled(geometry.cells(), &mut state);
+        let shift_maps = get_all_shift_maps(&mapping, &geometry);
+        assert_eq!(shift_maps.len(), 8);
+        // Clockwise rotation moves cell 0 (top left) to cell 3 (top right)
+        let source = mapping[0];
+        let (shift, _) = shift_maps[1]
+            .iter()
+            .find(|(_, mask)| *mask >> (source * 2) & 3 == 3)
+            .unwrap();
+        assert_eq!(source - shift, mapping[3]);
+        // Every cell is moved by exactly one shift
+        for shift_map in shift_maps.iter() {
+            let masks = shift_map.values().fold(0, |all, mask| {
+                assert_eq!(all & mask, 0);
+                all | mask
+            });
+            assert_eq!(masks, (1u128 << 32) - 1);
+        }
+        let rectangle = Geometry::new(4, 3, 3).unwrap();
+        assert_eq!(
+            get_all_shift_maps(&(0..12).collect_vec(), &rectangle).len(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_geometry_matches_mnk_state() {
+        assert!(Geometry::new(4, 3, 3).is_ok());
+        assert!(Geometry::new(4, 3, 4).is_err());
+        assert!(Geometry::new(3, 3, 0).is_err());
+    }
+
+    #[test]
+    fn test_find_mapping_is_permutation() {
+        let geometry = Geometry::new(4, 4, 4).unwrap();
+        let mapping = find_shift_optimized_mapping(&geometry);
+        assert_eq!(
+            mapping.iter().sorted().copied().collect_vec(),
+            (0..16).collect_vec()
+        );
+        let identity_score = score_mapping(&(0..16).collect_vec(), &geometry);
+        assert!(!is_better(
+            identity_score,
+            score_mapping(&mapping, &geometry)
+        ));
+    }
+
+    #[test]
+    fn test_cube_winning_lines() {
+        assert_eq!(cube_winning_lines(3).len(), 49);
+        assert_eq!(cube_winning_lines(4).len(), 76);
+        let lines = cube_winning_lines(3);
+        // The four lines through the center of every layer and the space diagonals
+        assert!(lines.contains(&vec![4, 13, 22]));
+        assert!(lines.contains(&vec![0, 13, 26]));
+        assert!(lines.contains(&vec![2, 13, 24]));
+        let masks: HashSet<u128> = lines
+            .iter()
+            .map(|line| line.iter().fold(0, |mask, &pos| mask | 1 << (pos * 2)))
+            .collect();
+        assert_eq!(masks.len(), 49);
+    }
+
+    #[test]
+    fn test_cube_mappings() {
+        for size in [3, 4] {
+            let mappings = cube_mappings(size);
+            assert_eq!(mappings.len(), 48);
+            assert_eq!(mappings.iter().unique().count(), 48);
+            assert_eq!(mappings[0], (0..(size * size * size) as i32).collect_vec());
+            let lines: HashSet<Vec<usize>> = cube_winning_lines(size).into_iter().collect();
+            for mapping in mappings.iter() {
+                for line in lines.iter() {
+                    let moved = line
+                        .iter()
+                        .map(|&pos| mapping.iter().position(|&m| m == pos as i32).unwrap())
+                        .sorted()
+                        .collect_vec();
+                    assert!(lines.contains(&moved));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_cube_consts() {
+        // cube_consts.rs was generated with `codegen cube 3 4`
+        let masks = cube_winning_lines(4)
+            .iter()
+            .map(|line| line.iter().fold(0, |mask, &pos| mask | 1 << (pos * 2)))
+            .collect_vec();
+        assert_eq!(masks, CUBE4_WIN_MASKS.to_vec());
+        let identity = (0..27).collect_vec();
+        for (mapping, expected) in cube_mappings(3).iter().zip(CUBE3_TRANSFORM_SHIFTS.iter()) {
+            let expected: BTreeMap<i32, u128> = expected.iter().copied().collect();
+            assert_eq!(get_shift_map(&identity, mapping), expected);
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use crate::game::errors::TicTacToeError;
+use crate::game::game_state::PlayerEnum;
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// The operations `Game` and the players need from a board, so that boards of any size
+/// can be played. Positions are numbered row by row starting at 0.
+pub trait Board:
+    'static + Copy + Eq + Hash + fmt::Debug + fmt::Display + FromStr<Err = TicTacToeError>
+{
+    /// The packed representation returned by `get_raw`.
+    type Raw: Copy + Eq + Ord + Hash + fmt::Debug;
+
+    /// Number of positions on the board.
+    const SIZE: usize;
+
+    fn new() -> Self;
+
+    fn set(&mut self, pos: usize, value: PlayerEnum) -> Result<(), TicTacToeError>;
+
+    fn get(&self, pos: usize) -> Result<PlayerEnum, TicTacToeError>;
+
+    fn get_raw(&self) -> Self::Raw;
+
+    /// A hash of the pieces on the board, equal for equal boards and updated
+    /// incrementally by `set`.
+    fn zobrist_key(&self) -> u64;
+
+    fn is_winner(&self, player: PlayerEnum) -> bool;
+
+    fn is_tie(&self) -> bool;
+
+    fn transform(&self, rotations: i32, flip: bool) -> Self;
+
+    fn get_display(&self) -> String;
+
+    fn get_numbered_display(&self) -> String;
+
+    /// Every board that is equivalent to this one by symmetry, starting with itself.
+    fn symmetries(&self) -> Vec<Self> {
+        (0..8).map(|i| self.transform(i % 4, i > 3)).collect()
+    }
+
+    fn get_turn(&self) -> PlayerEnum {
+        let mut x_count = 0;
+        let mut o_count = 0;
+        for i in 0..Self::SIZE {
+            match self.get(i).unwrap() {
+                PlayerEnum::None => (),
+                PlayerEnum::X => x_count += 1,
+                PlayerEnum::O => o_count += 1,
+            }
+        }
+        if x_count == o_count {
+            PlayerEnum::X
+        } else {
+            PlayerEnum::O
+        }
+    }
+
+    fn auto_set(&mut self, pos: usize) -> Result<(), TicTacToeError> {
+        self.set(pos, self.get_turn())
+    }
+
+    fn is_empty(&self, pos: usize) -> Result<bool, TicTacToeError> {
+        Ok(self.get(pos)? == PlayerEnum::None)
+    }
+
+    /// The positions the player to move can play. Boards with rules beyond an empty cell,
+    /// like `UltimateState`, only give the moves those rules allow.
+    fn empty_positions(&self) -> Vec<usize> {
+        (0..Self::SIZE)
+            .filter(|&i| self.is_empty(i).unwrap())
+            .collect()
+    }
+
+    /// How many pieces of either kind are on the board.
+    fn piece_count(&self) -> usize {
+        (0..Self::SIZE)
+            .filter(|&i| !self.is_empty(i).unwrap())
+            .count()
+    }
+
+    fn is_over(&self) -> bool {
+        self.is_winner(PlayerEnum::X) || self.is_winner(PlayerEnum::O) || self.is_tie()
+    }
+}
+
+/// A piece placed at a position. Unless the variant lets the mover choose, the piece is
+/// always the mover's own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub position: usize,
+    pub piece: PlayerEnum,
+}
+
+impl Move {
+    pub fn new(position: usize, piece: PlayerEnum) -> Move {
+        Move { position, piece }
+    }
+}
+
+/// The rules deciding who wins once a line is completed, and which pieces can be played.
+/// Players are still called X and O in every variant, X moving first, even when either
+/// of them can place either piece.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Variant {
+    #[default]
+    Standard,
+    /// Whoever completes a line loses.
+    Misere,
+    /// Either player places X or O, and whoever completes a line of either wins.
+    Wild,
+    /// Either player places X or O. X plays Order, who wins by completing a line of
+    /// either, and O plays Chaos, who wins by filling the board without one. Usually
+    /// played on a 6x6 board with lines of 5.
+    OrderAndChaos,
+    /// Both players place X, usually across several boards, and a board is dead once it
+    /// has a line. Whoever kills the last board loses.
+    Notakto,
+}
+
+impl Variant {
+    pub fn from_name(name: &str) -> Option<Variant> {
+        match name.to_lowercase().as_str() {
+            "standard" => Some(Variant::Standard),
+            "misere" | "misère" => Some(Variant::Misere),
+            "wild" => Some(Variant::Wild),
+            "order-and-chaos" | "orderandchaos" => Some(Variant::OrderAndChaos),
+            "notakto" => Some(Variant::Notakto),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Variant::Standard => "Standard",
+            Variant::Misere => "Misere",
+            Variant::Wild => "Wild",
+            Variant::OrderAndChaos => "OrderAndChaos",
+            Variant::Notakto => "Notakto",
+        }
+    }
+
+    /// Whether the mover picks which piece to place instead of always placing their own.
+    pub fn chooses_piece(&self) -> bool {
+        matches!(self, Variant::Wild | Variant::OrderAndChaos)
+    }
+
+    /// Whether each player always places their own piece.
+    pub fn own_pieces(&self) -> bool {
+        matches!(self, Variant::Standard | Variant::Misere)
+    }
+
+    /// The piece `turn` places when it is not chosen.
+    pub fn piece(&self, turn: PlayerEnum) -> PlayerEnum {
+        match self {
+            Variant::Notakto => PlayerEnum::X,
+            _ => turn,
+        }
+    }
+
+    /// The player to move. When pieces are chosen or shared they no longer say whose
+    /// turn it is, so turns are counted instead.
+    pub fn turn<B: Board>(&self, board: &B) -> PlayerEnum {
+        if self.own_pieces() {
+            board.get_turn()
+        } else if board.piece_count().is_multiple_of(2) {
+            PlayerEnum::X
+        } else {
+            PlayerEnum::O
+        }
+    }
+
+    /// Every move the player to move can make, by position and then piece.
+    pub fn moves<B: Board>(&self, board: &B) -> Vec<Move> {
+        let pieces = if self.chooses_piece() {
+            vec![PlayerEnum::X, PlayerEnum::O]
+        } else {
+            vec![self.piece(self.turn(board))]
+        };
+        board
+            .empty_positions()
+            .into_iter()
+            .flat_map(|pos| pieces.iter().map(move |&piece| Move::new(pos, piece)))
+            .collect()
+    }
+
+    /// The player who has won `board` under these rules.
+    pub fn winner<B: Board>(&self, board: &B) -> Option<PlayerEnum> {
+        let line = [PlayerEnum::X, PlayerEnum::O]
+            .iter()
+            .copied()
+            .find(|&player| board.is_winner(player));
+        match (self, line) {
+            // Chaos wins once the board is full
+            (Variant::OrderAndChaos, None) if board.is_tie() => Some(PlayerEnum::O),
+            (_, None) => None,
+            (Variant::Standard, Some(line)) => Some(line),
+            (Variant::Misere, Some(PlayerEnum::X)) => Some(PlayerEnum::O),
+            (Variant::Misere, Some(_)) => Some(PlayerEnum::X),
+            // Whoever moved last completed the line
+            (Variant::Wild, Some(_)) if self.turn(board) == PlayerEnum::X => Some(PlayerEnum::O),
+            (Variant::Wild, Some(_)) => Some(PlayerEnum::X),
+            (Variant::OrderAndChaos, Some(_)) => Some(PlayerEnum::X),
+            // The board only shows a line once every board is dead, and the player who
+            // killed the last one has just moved
+            (Variant::Notakto, Some(_)) => Some(self.turn(board)),
+        }
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
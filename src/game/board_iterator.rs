@@ -1,63 +1,197 @@
-use crate::game::consts::BOARD_SIZE;
+use crate::game::board::Board;
 use crate::game::game_state::{GameState, PlayerEnum};
-use itertools::structs::Permutations;
-use itertools::Itertools;
 use std::collections::HashSet;
-use std::hash::Hash;
-use std::ops::Range;
+use std::fmt;
 
+/// Every position reachable from the empty board, each yielded once and in order of ply.
+/// Each ply is found from the one before it, so only one ply is held in memory at a time.
 pub struct BoardIterator {
-    board: GameState,
-    iteration_count: usize,
-    permutations: Permutations<Range<usize>>,
-    current_turn_order: Option<Vec<usize>>,
-    turn_index: usize,
+    canonical: bool,
+    ply: usize,
+    layer: Vec<GameState>,
+    index: usize,
 }
 
 impl BoardIterator {
     pub fn new() -> BoardIterator {
         BoardIterator {
-            board: GameState::new(),
-            iteration_count: 0,
-            permutations: (0..9).permutations(9),
-            current_turn_order: None,
-            turn_index: 0,
+            canonical: false,
+            ply: 0,
+            layer: vec![GameState::new()],
+            index: 0,
         }
     }
-}
 
-impl Iterator for BoardIterator {
-    type Item = GameState;
+    /// Only yields the canonical form of each position, one per symmetry class.
+    pub fn canonical(mut self) -> BoardIterator {
+        self.canonical = true;
+        self.layer = self.layer.iter().map(|b| b.canonical().0).collect();
+        self
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.iteration_count == 0 {
-            self.iteration_count += 1;
-            return Some(GameState::new());
+    /// The number of pieces on the positions currently being yielded.
+    pub fn ply(&self) -> usize {
+        self.ply
+    }
+
+    /// The rest of the current ply, moving on to the next one. Returns `None` once every
+    /// ply has been yielded.
+    pub fn next_layer(&mut self) -> Option<Vec<GameState>> {
+        if self.index >= self.layer.len() && !self.advance() {
+            return None;
         }
-        if self.turn_index >= BOARD_SIZE {
-            self.current_turn_order = None
+        let layer = self.layer[self.index..].to_vec();
+        self.index = self.layer.len();
+        Some(layer)
+    }
+
+    /// How many positions of each ply are won, tied or still being played.
+    pub fn counts(mut self) -> Vec<PlyCounts> {
+        let mut counts = vec![];
+        while let Some(layer) = self.next_layer() {
+            let mut ply_counts = PlyCounts {
+                ply: self.ply,
+                ..PlyCounts::default()
+            };
+            for board in layer {
+                if board.is_winner(PlayerEnum::X) {
+                    ply_counts.x_wins += 1;
+                } else if board.is_winner(PlayerEnum::O) {
+                    ply_counts.o_wins += 1;
+                } else if board.is_tie() {
+                    ply_counts.ties += 1;
+                } else {
+                    ply_counts.ongoing += 1;
+                }
+            }
+            counts.push(ply_counts);
         }
-        let mut turn_order = self.current_turn_order.as_ref();
-        if turn_order.is_none() {
-            self.turn_index = 0;
-            self.board = GameState::new();
-            self.current_turn_order = self.permutations.next();
-            turn_order = self.current_turn_order.as_ref();
-            if turn_order.is_none() {
-                return None;
+        counts
+    }
+
+    // Replaces the current ply with every position one move on from it
+    fn advance(&mut self) -> bool {
+        let mut seen = HashSet::new();
+        let mut next = vec![];
+        for board in self.layer.iter().filter(|b| !b.is_over()) {
+            for pos in board.empty_positions() {
+                let mut child = *board;
+                child.auto_set(pos).unwrap();
+                if self.canonical {
+                    child = child.canonical().0;
+                }
+                if seen.insert(child) {
+                    next.push(child);
+                }
             }
         }
-        let pos = turn_order.unwrap()[self.turn_index];
-        let mut player_enum = PlayerEnum::X;
-        if self.turn_index % 2 != 0 {
-            player_enum = PlayerEnum::O;
+        if next.is_empty() {
+            self.layer.clear();
+            self.index = 0;
+            return false;
         }
-        self.board.set(pos, player_enum).unwrap();
-        if self.board.is_winner(player_enum) {
-            self.current_turn_order = None
+        self.ply += 1;
+        self.layer = next;
+        self.index = 0;
+        true
+    }
+}
+
+impl Default for BoardIterator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for BoardIterator {
+    type Item = GameState;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.layer.len() && !self.advance() {
+            return None;
         }
-        self.turn_index += 1;
-        self.iteration_count += 1;
-        return Some(self.board);
+        self.index += 1;
+        Some(self.layer[self.index - 1])
+    }
+}
+
+/// The positions of one ply, by outcome.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlyCounts {
+    pub ply: usize,
+    pub x_wins: usize,
+    pub o_wins: usize,
+    pub ties: usize,
+    pub ongoing: usize,
+}
+
+impl PlyCounts {
+    pub fn total(&self) -> usize {
+        self.x_wins + self.o_wins + self.ties + self.ongoing
+    }
+}
+
+impl fmt::Display for PlyCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Ply {}: {} positions, {} X wins, {} O wins, {} ties, {} ongoing",
+            self.ply,
+            self.total(),
+            self.x_wins,
+            self.o_wins,
+            self.ties,
+            self.ongoing
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_reachable_positions() {
+        let boards = BoardIterator::new().collect_vec();
+        assert_eq!(boards.len(), 5478);
+        assert_eq!(boards.iter().unique().count(), boards.len());
+        assert!(boards.iter().all(|b| b.is_valid()));
+        let canonical = BoardIterator::new().canonical().collect_vec();
+        assert_eq!(canonical.len(), 765);
+        assert!(canonical.iter().all(|b| b.canonical().0 == *b));
+    }
+
+    #[test]
+    fn test_layers() {
+        let mut boards = BoardIterator::new();
+        assert_eq!(boards.next(), Some(GameState::new()));
+        assert_eq!(
+            boards.next().map(|b| b.get(0).unwrap()),
+            Some(PlayerEnum::X)
+        );
+        assert_eq!(boards.ply(), 1);
+        // The first ply has already been started, so only the rest of it is returned
+        assert_eq!(boards.next_layer().unwrap().len(), 8);
+        assert_eq!(boards.next_layer().unwrap().len(), 72);
+        assert_eq!(boards.ply(), 2);
+        let sizes = BoardIterator::new()
+            .canonical()
+            .counts()
+            .iter()
+            .map(PlyCounts::total)
+            .collect_vec();
+        assert_eq!(sizes, vec![1, 3, 12, 38, 108, 174, 204, 153, 57, 15]);
+    }
+
+    #[test]
+    fn test_counts() {
+        let counts = BoardIterator::new().counts();
+        assert_eq!(counts.len(), 10);
+        assert_eq!(counts.iter().map(|c| c.x_wins).sum::<usize>(), 626);
+        assert_eq!(counts.iter().map(|c| c.o_wins).sum::<usize>(), 316);
+        assert_eq!(counts.iter().map(|c| c.ties).sum::<usize>(), 16);
+        assert_eq!(counts[5].x_wins, 120);
+        assert_eq!(counts[9].total(), 78);
     }
 }
@@ -1,7 +1,7 @@
 pub const PLAYER_NONE: u8 = 0;
 pub const PLAYER_X: u8 = 1;
 pub const PLAYER_O: u8 = 2;
-pub const TRANSFORM_SHIFTS: [&[(i32, u32)]; 8] = [&[(0, 0b111111111111111111)], &[(0, 0b000000001100000000), (-2, 0b000000110000000011), (4, 0b001100000000000000), (-6, 0b000000000000110000), (-4, 0b000000000000001100), (2, 0b110000000011000000), (6, 0b000011000000000000)], &[(4, 0b000011000000000000), (8, 0b110000000000000000), (-6, 0b000000000000001100), (-8, 0b000000000000000011), (-4, 0b000000000000110000), (2, 0b000000110000000000), (-2, 0b000000000011000000), (6, 0b001100000000000000), (0, 0b000000001100000000)], &[(-4, 0b000000000011000000), (-6, 0b000000000000000011), (4, 0b000000110000000000), (6, 0b110000000000000000), (2, 0b001100000000110000), (-2, 0b000011000000001100), (0, 0b000000001100000000)], &[(-6, 0b000000000000111111), (6, 0b111111000000000000), (0, 0b000000111111000000)], &[(-2, 0b000000110000001100), (0, 0b110000001100000011), (2, 0b001100000011000000), (-4, 0b000000000000110000), (4, 0b000011000000000000)], &[(-2, 0b000011000011000011), (0, 0b001100001100001100), (2, 0b110000110000110000)], &[(8, 0b110000000000000000), (4, 0b001100110000000000), (-8, 0b000000000000000011), (-4, 0b000000000011001100), (0, 0b000011001100110000)]];
+pub const TRANSFORM_SHIFTS: [&[(i32, u32)]; 8] = [&[(0, 0b111111111111111111)], &[(-6, 0b000000000000110000), (-4, 0b000000000000001100), (-2, 0b000000110000000011), (0, 0b000000001100000000), (2, 0b110000000011000000), (4, 0b001100000000000000), (6, 0b000011000000000000)], &[(-8, 0b000000000000000011), (-6, 0b000000000000001100), (-4, 0b000000000000110000), (-2, 0b000000000011000000), (0, 0b000000001100000000), (2, 0b000000110000000000), (4, 0b000011000000000000), (6, 0b001100000000000000), (8, 0b110000000000000000)], &[(-6, 0b000000000000000011), (-4, 0b000000000011000000), (-2, 0b000011000000001100), (0, 0b000000001100000000), (2, 0b001100000000110000), (4, 0b000000110000000000), (6, 0b110000000000000000)], &[(-6, 0b000000000000111111), (0, 0b000000111111000000), (6, 0b111111000000000000)], &[(-4, 0b000000000000110000), (-2, 0b000000110000001100), (0, 0b110000001100000011), (2, 0b001100000011000000), (4, 0b000011000000000000)], &[(-2, 0b000011000011000011), (0, 0b001100001100001100), (2, 0b110000110000110000)], &[(-8, 0b000000000000000011), (-4, 0b000000000011001100), (0, 0b000011001100110000), (4, 0b001100110000000000), (8, 0b110000000000000000)]];
 pub const WIN_MASKS: [u32; 8] = [0b000000000000010101, 0b000000010101000000, 0b010101000000000000, 0b000001000001000001, 0b000100000100000100, 0b010000010000010000, 0b010000000100000001, 0b000001000100010000];
 pub const BOARD_SIZE: usize = 9;
 pub const X_BIT_MASK: u32 = 0b010101010101010101;
@@ -0,0 +1,2509 @@
+// Generated by `codegen cube 3 4` from `make codegen`. Do not edit by hand.
+pub const CUBE3_WIN_MASKS: [u128; 49] = [
+    0b010000000000000000000000000100000000000000000000000001,
+    0b000001000000000000000000000001000000000000000000000001,
+    0b000100000000000000000000000100000000000000000000000100,
+    0b010000000000000000000000010000000000000000000000010000,
+    0b000001000000000000000000000100000000000000000000010000,
+    0b000000000000010000000000000000000100000000000000000001,
+    0b000000010000000000000000000100000000000000000001000000,
+    0b010000000000000000000100000000000000000001000000000000,
+    0b000000000000000001000000000000000001000000000000000001,
+    0b000000000000000100000000000000000100000000000000000100,
+    0b000000000000010000000000000000010000000000000000010000,
+    0b000000000001000000000000000001000000000000000001000000,
+    0b000000000100000000000000000100000000000000000100000000,
+    0b000000010000000000000000010000000000000000010000000000,
+    0b000001000000000000000001000000000000000001000000000000,
+    0b000100000000000000000100000000000000000100000000000000,
+    0b010000000000000000010000000000000000010000000000000000,
+    0b000000000000000001000000000000000100000000000000010000,
+    0b000000000001000000000000000100000000000000010000000000,
+    0b000001000000000000000100000000000000010000000000000000,
+    0b000000000000010000000000000100000000000001000000000000,
+    0b000000000000000001000000000001000000000001000000000000,
+    0b000000000000000100000000000100000000000100000000000000,
+    0b000000000000010000000000010000000000010000000000000000,
+    0b000000000000000001000000000100000000010000000000000000,
+    0b000000000000000000000000000000000000010000000100000001,
+    0b000000000000000000010000000100000001000000000000000000,
+    0b010000000100000001000000000000000000000000000000000000,
+    0b000000000000000000000000000000000000000001000001000001,
+    0b000000000000000000000000000000000000000100000100000100,
+    0b000000000000000000000000000000000000010000010000010000,
+    0b000000000000000000000001000001000001000000000000000000,
+    0b000000000000000000000100000100000100000000000000000000,
+    0b000000000000000000010000010000010000000000000000000000,
+    0b000001000001000001000000000000000000000000000000000000,
+    0b000100000100000100000000000000000000000000000000000000,
+    0b010000010000010000000000000000000000000000000000000000,
+    0b000000000000000000000000000000000000000001000100010000,
+    0b000000000000000000000001000100010000000000000000000000,
+    0b000001000100010000000000000000000000000000000000000000,
+    0b000000000000000000000000000000000000000000000000010101,
+    0b000000000000000000000000000000000000000000010101000000,
+    0b000000000000000000000000000000000000010101000000000000,
+    0b000000000000000000000000000000010101000000000000000000,
+    0b000000000000000000000000010101000000000000000000000000,
+    0b000000000000000000010101000000000000000000000000000000,
+    0b000000000000010101000000000000000000000000000000000000,
+    0b000000010101000000000000000000000000000000000000000000,
+    0b010101000000000000000000000000000000000000000000000000,
+];
+pub const CUBE3_TRANSFORM_SHIFTS: [&[(i32, u128)]; 48] = [
+    &[
+        (0, 0b111111111111111111111111111111111111111111111111111111),
+    ],
+    &[
+        (-6, 0b000000000000110000000000000000110000000000000000110000),
+        (-4, 0b000000000000001100000000000000001100000000000000001100),
+        (-2, 0b000000110000000011000000110000000011000000110000000011),
+        (0, 0b000000001100000000000000001100000000000000001100000000),
+        (2, 0b110000000011000000110000000011000000110000000011000000),
+        (4, 0b001100000000000000001100000000000000001100000000000000),
+        (6, 0b000011000000000000000011000000000000000011000000000000),
+    ],
+    &[
+        (-8, 0b000000000000000011000000000000000011000000000000000011),
+        (-6, 0b000000000000001100000000000000001100000000000000001100),
+        (-4, 0b000000000000110000000000000000110000000000000000110000),
+        (-2, 0b000000000011000000000000000011000000000000000011000000),
+        (0, 0b000000001100000000000000001100000000000000001100000000),
+        (2, 0b000000110000000000000000110000000000000000110000000000),
+        (4, 0b000011000000000000000011000000000000000011000000000000),
+        (6, 0b001100000000000000001100000000000000001100000000000000),
+        (8, 0b110000000000000000110000000000000000110000000000000000),
+    ],
+    &[
+        (-6, 0b000000000000000011000000000000000011000000000000000011),
+        (-4, 0b000000000011000000000000000011000000000000000011000000),
+        (-2, 0b000011000000001100000011000000001100000011000000001100),
+        (0, 0b000000001100000000000000001100000000000000001100000000),
+        (2, 0b001100000000110000001100000000110000001100000000110000),
+        (4, 0b000000110000000000000000110000000000000000110000000000),
+        (6, 0b110000000000000000110000000000000000110000000000000000),
+    ],
+    &[
+        (-6, 0b000000000000111111000000000000111111000000000000111111),
+        (0, 0b000000111111000000000000111111000000000000111111000000),
+        (6, 0b111111000000000000111111000000000000111111000000000000),
+    ],
+    &[
+        (-4, 0b000000000000110000000000000000110000000000000000110000),
+        (-2, 0b000000110000001100000000110000001100000000110000001100),
+        (0, 0b110000001100000011110000001100000011110000001100000011),
+        (2, 0b001100000011000000001100000011000000001100000011000000),
+        (4, 0b000011000000000000000011000000000000000011000000000000),
+    ],
+    &[
+        (-2, 0b000011000011000011000011000011000011000011000011000011),
+        (0, 0b001100001100001100001100001100001100001100001100001100),
+        (2, 0b110000110000110000110000110000110000110000110000110000),
+    ],
+    &[
+        (-8, 0b000000000000000011000000000000000011000000000000000011),
+        (-4, 0b000000000011001100000000000011001100000000000011001100),
+        (0, 0b000011001100110000000011001100110000000011001100110000),
+        (4, 0b001100110000000000001100110000000000001100110000000000),
+        (8, 0b110000000000000000110000000000000000110000000000000000),
+    ],
+    &[
+        (-18, 0b000000000000000000000000000000000000111111111111111111),
+        (0, 0b000000000000000000111111111111111111000000000000000000),
+        (18, 0b111111111111111111000000000000000000000000000000000000),
+    ],
+    &[
+        (-24, 0b000000000000000000000000000000000000000000000000111111),
+        (-18, 0b000000000000000000000000000000000000000000111111000000),
+        (-12, 0b000000000000000000000000000000000000111111000000000000),
+        (-6, 0b000000000000000000000000000000111111000000000000000000),
+        (0, 0b000000000000000000000000111111000000000000000000000000),
+        (6, 0b000000000000000000111111000000000000000000000000000000),
+        (12, 0b000000000000111111000000000000000000000000000000000000),
+        (18, 0b000000111111000000000000000000000000000000000000000000),
+        (24, 0b111111000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-20, 0b000000000000000000000000000000000000000011000011000011),
+        (-18, 0b000000000000000000000000000000000000001100001100001100),
+        (-16, 0b000000000000000000000000000000000000110000110000110000),
+        (-2, 0b000000000000000000000011000011000011000000000000000000),
+        (0, 0b000000000000000000001100001100001100000000000000000000),
+        (2, 0b000000000000000000110000110000110000000000000000000000),
+        (16, 0b000011000011000011000000000000000000000000000000000000),
+        (18, 0b001100001100001100000000000000000000000000000000000000),
+        (20, 0b110000110000110000000000000000000000000000000000000000),
+    ],
+    &[
+        (-26, 0b000000000000000000000000000000000000000000000000000011),
+        (-24, 0b000000000000000000000000000000000000000000000000001100),
+        (-22, 0b000000000000000000000000000000000000000000000000110000),
+        (-20, 0b000000000000000000000000000000000000000000000011000000),
+        (-18, 0b000000000000000000000000000000000000000000001100000000),
+        (-16, 0b000000000000000000000000000000000000000000110000000000),
+        (-14, 0b000000000000000000000000000000000000000011000000000000),
+        (-12, 0b000000000000000000000000000000000000001100000000000000),
+        (-10, 0b000000000000000000000000000000000000110000000000000000),
+        (-8, 0b000000000000000000000000000000000011000000000000000000),
+        (-6, 0b000000000000000000000000000000001100000000000000000000),
+        (-4, 0b000000000000000000000000000000110000000000000000000000),
+        (-2, 0b000000000000000000000000000011000000000000000000000000),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b000000000000000000000000110000000000000000000000000000),
+        (4, 0b000000000000000000000011000000000000000000000000000000),
+        (6, 0b000000000000000000001100000000000000000000000000000000),
+        (8, 0b000000000000000000110000000000000000000000000000000000),
+        (10, 0b000000000000000011000000000000000000000000000000000000),
+        (12, 0b000000000000001100000000000000000000000000000000000000),
+        (14, 0b000000000000110000000000000000000000000000000000000000),
+        (16, 0b000000000011000000000000000000000000000000000000000000),
+        (18, 0b000000001100000000000000000000000000000000000000000000),
+        (20, 0b000000110000000000000000000000000000000000000000000000),
+        (22, 0b000011000000000000000000000000000000000000000000000000),
+        (24, 0b001100000000000000000000000000000000000000000000000000),
+        (26, 0b110000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-22, 0b000000000000000000000000000000000000000000000000110000),
+        (-20, 0b000000000000000000000000000000000000000000110000001100),
+        (-18, 0b000000000000000000000000000000000000110000001100000011),
+        (-16, 0b000000000000000000000000000000000000001100000011000000),
+        (-14, 0b000000000000000000000000000000000000000011000000000000),
+        (-4, 0b000000000000000000000000000000110000000000000000000000),
+        (-2, 0b000000000000000000000000110000001100000000000000000000),
+        (0, 0b000000000000000000110000001100000011000000000000000000),
+        (2, 0b000000000000000000001100000011000000000000000000000000),
+        (4, 0b000000000000000000000011000000000000000000000000000000),
+        (14, 0b000000000000110000000000000000000000000000000000000000),
+        (16, 0b000000110000001100000000000000000000000000000000000000),
+        (18, 0b110000001100000011000000000000000000000000000000000000),
+        (20, 0b001100000011000000000000000000000000000000000000000000),
+        (22, 0b000011000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-24, 0b000000000000000000000000000000000000000000000000000011),
+        (-22, 0b000000000000000000000000000000000000000000000011000000),
+        (-20, 0b000000000000000000000000000000000000000011000000001100),
+        (-18, 0b000000000000000000000000000000000000000000001100000000),
+        (-16, 0b000000000000000000000000000000000000001100000000110000),
+        (-14, 0b000000000000000000000000000000000000000000110000000000),
+        (-12, 0b000000000000000000000000000000000000110000000000000000),
+        (-6, 0b000000000000000000000000000000000011000000000000000000),
+        (-4, 0b000000000000000000000000000011000000000000000000000000),
+        (-2, 0b000000000000000000000011000000001100000000000000000000),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b000000000000000000001100000000110000000000000000000000),
+        (4, 0b000000000000000000000000110000000000000000000000000000),
+        (6, 0b000000000000000000110000000000000000000000000000000000),
+        (12, 0b000000000000000011000000000000000000000000000000000000),
+        (14, 0b000000000011000000000000000000000000000000000000000000),
+        (16, 0b000011000000001100000000000000000000000000000000000000),
+        (18, 0b000000001100000000000000000000000000000000000000000000),
+        (20, 0b001100000000110000000000000000000000000000000000000000),
+        (22, 0b000000110000000000000000000000000000000000000000000000),
+        (24, 0b110000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-24, 0b000000000000000000000000000000000000000000000000110000),
+        (-22, 0b000000000000000000000000000000000000000000000000001100),
+        (-20, 0b000000000000000000000000000000000000000000110000000011),
+        (-18, 0b000000000000000000000000000000000000000000001100000000),
+        (-16, 0b000000000000000000000000000000000000110000000011000000),
+        (-14, 0b000000000000000000000000000000000000001100000000000000),
+        (-12, 0b000000000000000000000000000000000000000011000000000000),
+        (-6, 0b000000000000000000000000000000110000000000000000000000),
+        (-4, 0b000000000000000000000000000000001100000000000000000000),
+        (-2, 0b000000000000000000000000110000000011000000000000000000),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b000000000000000000110000000011000000000000000000000000),
+        (4, 0b000000000000000000001100000000000000000000000000000000),
+        (6, 0b000000000000000000000011000000000000000000000000000000),
+        (12, 0b000000000000110000000000000000000000000000000000000000),
+        (14, 0b000000000000001100000000000000000000000000000000000000),
+        (16, 0b000000110000000011000000000000000000000000000000000000),
+        (18, 0b000000001100000000000000000000000000000000000000000000),
+        (20, 0b110000000011000000000000000000000000000000000000000000),
+        (22, 0b001100000000000000000000000000000000000000000000000000),
+        (24, 0b000011000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-26, 0b000000000000000000000000000000000000000000000000000011),
+        (-22, 0b000000000000000000000000000000000000000000000011001100),
+        (-18, 0b000000000000000000000000000000000000000011001100110000),
+        (-14, 0b000000000000000000000000000000000000001100110000000000),
+        (-10, 0b000000000000000000000000000000000000110000000000000000),
+        (-8, 0b000000000000000000000000000000000011000000000000000000),
+        (-4, 0b000000000000000000000000000011001100000000000000000000),
+        (0, 0b000000000000000000000011001100110000000000000000000000),
+        (4, 0b000000000000000000001100110000000000000000000000000000),
+        (8, 0b000000000000000000110000000000000000000000000000000000),
+        (10, 0b000000000000000011000000000000000000000000000000000000),
+        (14, 0b000000000011001100000000000000000000000000000000000000),
+        (18, 0b000011001100110000000000000000000000000000000000000000),
+        (22, 0b001100110000000000000000000000000000000000000000000000),
+        (26, 0b110000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-12, 0b000000000000000000000000000000000000111111000000000000),
+        (-6, 0b000000000000000000111111000000000000000000111111000000),
+        (0, 0b111111000000000000000000111111000000000000000000111111),
+        (6, 0b000000111111000000000000000000111111000000000000000000),
+        (12, 0b000000000000111111000000000000000000000000000000000000),
+    ],
+    &[
+        (-18, 0b000000000000000000000000000000000000000000000000111111),
+        (-12, 0b000000000000000000000000000000111111000000000000000000),
+        (-6, 0b000000000000111111000000000000000000000000111111000000),
+        (0, 0b000000000000000000000000111111000000000000000000000000),
+        (6, 0b000000111111000000000000000000000000111111000000000000),
+        (12, 0b000000000000000000111111000000000000000000000000000000),
+        (18, 0b111111000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-18, 0b000000000000000000000000000000000000111111000000000000),
+        (-12, 0b000000000000000000000000000000000000000000111111000000),
+        (-6, 0b000000000000000000111111000000000000000000000000111111),
+        (0, 0b000000000000000000000000111111000000000000000000000000),
+        (6, 0b111111000000000000000000000000111111000000000000000000),
+        (12, 0b000000111111000000000000000000000000000000000000000000),
+        (18, 0b000000000000111111000000000000000000000000000000000000),
+    ],
+    &[
+        (-24, 0b000000000000000000000000000000000000000000000000111111),
+        (-12, 0b000000000000000000000000000000111111000000111111000000),
+        (0, 0b000000000000111111000000111111000000111111000000000000),
+        (12, 0b000000111111000000111111000000000000000000000000000000),
+        (24, 0b111111000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-14, 0b000000000000000000000000000000000000000011000000000000),
+        (-12, 0b000000000000000000000000000000000000001100000000000000),
+        (-10, 0b000000000000000000000000000000000000110000000000000000),
+        (-8, 0b000000000000000000000011000000000000000000000011000000),
+        (-6, 0b000000000000000000001100000000000000000000001100000000),
+        (-4, 0b000000000000000000110000000000000000000000110000000000),
+        (-2, 0b000011000000000000000000000011000000000000000000000011),
+        (0, 0b001100000000000000000000001100000000000000000000001100),
+        (2, 0b110000000000000000000000110000000000000000000000110000),
+        (4, 0b000000000011000000000000000000000011000000000000000000),
+        (6, 0b000000001100000000000000000000001100000000000000000000),
+        (8, 0b000000110000000000000000000000110000000000000000000000),
+        (10, 0b000000000000000011000000000000000000000000000000000000),
+        (12, 0b000000000000001100000000000000000000000000000000000000),
+        (14, 0b000000000000110000000000000000000000000000000000000000),
+    ],
+    &[
+        (-20, 0b000000000000000000000000000000000000000000000000000011),
+        (-18, 0b000000000000000000000000000000000000000000000000001100),
+        (-16, 0b000000000000000000000000000000000000000000000000110000),
+        (-14, 0b000000000000000000000000000000000011000000000000000000),
+        (-12, 0b000000000000000000000000000000001100000000000000000000),
+        (-10, 0b000000000000000000000000000000110000000000000000000000),
+        (-8, 0b000000000000000011000000000000000000000000000011000000),
+        (-6, 0b000000000000001100000000000000000000000000001100000000),
+        (-4, 0b000000000000110000000000000000000000000000110000000000),
+        (-2, 0b000000000000000000000000000011000000000000000000000000),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b000000000000000000000000110000000000000000000000000000),
+        (4, 0b000000000011000000000000000000000000000011000000000000),
+        (6, 0b000000001100000000000000000000000000001100000000000000),
+        (8, 0b000000110000000000000000000000000000110000000000000000),
+        (10, 0b000000000000000000000011000000000000000000000000000000),
+        (12, 0b000000000000000000001100000000000000000000000000000000),
+        (14, 0b000000000000000000110000000000000000000000000000000000),
+        (16, 0b000011000000000000000000000000000000000000000000000000),
+        (18, 0b001100000000000000000000000000000000000000000000000000),
+        (20, 0b110000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-20, 0b000000000000000000000000000000000000000011000000000000),
+        (-18, 0b000000000000000000000000000000000000001100000000000000),
+        (-16, 0b000000000000000000000000000000000000110000000000000000),
+        (-14, 0b000000000000000000000000000000000000000000000011000000),
+        (-12, 0b000000000000000000000000000000000000000000001100000000),
+        (-10, 0b000000000000000000000000000000000000000000110000000000),
+        (-8, 0b000000000000000000000011000000000000000000000000000011),
+        (-6, 0b000000000000000000001100000000000000000000000000001100),
+        (-4, 0b000000000000000000110000000000000000000000000000110000),
+        (-2, 0b000000000000000000000000000011000000000000000000000000),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b000000000000000000000000110000000000000000000000000000),
+        (4, 0b000011000000000000000000000000000011000000000000000000),
+        (6, 0b001100000000000000000000000000001100000000000000000000),
+        (8, 0b110000000000000000000000000000110000000000000000000000),
+        (10, 0b000000000011000000000000000000000000000000000000000000),
+        (12, 0b000000001100000000000000000000000000000000000000000000),
+        (14, 0b000000110000000000000000000000000000000000000000000000),
+        (16, 0b000000000000000011000000000000000000000000000000000000),
+        (18, 0b000000000000001100000000000000000000000000000000000000),
+        (20, 0b000000000000110000000000000000000000000000000000000000),
+    ],
+    &[
+        (-26, 0b000000000000000000000000000000000000000000000000000011),
+        (-24, 0b000000000000000000000000000000000000000000000000001100),
+        (-22, 0b000000000000000000000000000000000000000000000000110000),
+        (-14, 0b000000000000000000000000000000000011000000000011000000),
+        (-12, 0b000000000000000000000000000000001100000000001100000000),
+        (-10, 0b000000000000000000000000000000110000000000110000000000),
+        (-2, 0b000000000000000011000000000011000000000011000000000000),
+        (0, 0b000000000000001100000000001100000000001100000000000000),
+        (2, 0b000000000000110000000000110000000000110000000000000000),
+        (10, 0b000000000011000000000011000000000000000000000000000000),
+        (12, 0b000000001100000000001100000000000000000000000000000000),
+        (14, 0b000000110000000000110000000000000000000000000000000000),
+        (22, 0b000011000000000000000000000000000000000000000000000000),
+        (24, 0b001100000000000000000000000000000000000000000000000000),
+        (26, 0b110000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-16, 0b000000000000000000000000000000000000110000000000000000),
+        (-14, 0b000000000000000000000000000000000000001100000000000000),
+        (-12, 0b000000000000000000000000000000000000000011000000000000),
+        (-10, 0b000000000000000000000000000000000000000000110000000000),
+        (-8, 0b000000000000000000110000000000000000000000001100000000),
+        (-6, 0b000000000000000000001100000000000000000000000011000000),
+        (-4, 0b000000000000000000000011000000000000000000000000110000),
+        (-2, 0b000000000000000000000000110000000000000000000000001100),
+        (0, 0b110000000000000000000000001100000000000000000000000011),
+        (2, 0b001100000000000000000000000011000000000000000000000000),
+        (4, 0b000011000000000000000000000000110000000000000000000000),
+        (6, 0b000000110000000000000000000000001100000000000000000000),
+        (8, 0b000000001100000000000000000000000011000000000000000000),
+        (10, 0b000000000011000000000000000000000000000000000000000000),
+        (12, 0b000000000000110000000000000000000000000000000000000000),
+        (14, 0b000000000000001100000000000000000000000000000000000000),
+        (16, 0b000000000000000011000000000000000000000000000000000000),
+    ],
+    &[
+        (-22, 0b000000000000000000000000000000000000000000000000110000),
+        (-20, 0b000000000000000000000000000000000000000000000000001100),
+        (-18, 0b000000000000000000000000000000000000000000000000000011),
+        (-14, 0b000000000000000000000000000000110000000000000000000000),
+        (-12, 0b000000000000000000000000000000001100000000000000000000),
+        (-10, 0b000000000000000000000000000000000011000000110000000000),
+        (-8, 0b000000000000000000000000000000000000000000001100000000),
+        (-6, 0b000000000000110000000000000000000000000000000011000000),
+        (-4, 0b000000000000001100000000000000000000000000000000000000),
+        (-2, 0b000000000000000011000000110000000000000000000000000000),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b000000000000000000000000000011000000110000000000000000),
+        (4, 0b000000000000000000000000000000000000001100000000000000),
+        (6, 0b000000110000000000000000000000000000000011000000000000),
+        (8, 0b000000001100000000000000000000000000000000000000000000),
+        (10, 0b000000000011000000110000000000000000000000000000000000),
+        (12, 0b000000000000000000001100000000000000000000000000000000),
+        (14, 0b000000000000000000000011000000000000000000000000000000),
+        (18, 0b110000000000000000000000000000000000000000000000000000),
+        (20, 0b001100000000000000000000000000000000000000000000000000),
+        (22, 0b000011000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-18, 0b000000000000000000000000000000000000000011000000000000),
+        (-14, 0b000000000000000000000000000000000000001100000000000000),
+        (-12, 0b000000000000000000000000000000000000000000000011000000),
+        (-10, 0b000000000000000000000011000000000000110000000000000000),
+        (-8, 0b000000000000000000000000000000000000000000001100000000),
+        (-6, 0b000000000000000000001100000000000000000000000000000011),
+        (-4, 0b000000000000000000000000000011000000000000110000000000),
+        (-2, 0b000011000000000000110000000000000000000000000000001100),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b001100000000000000000000000000000011000000000000110000),
+        (4, 0b000000000011000000000000110000000000000000000000000000),
+        (6, 0b110000000000000000000000000000001100000000000000000000),
+        (8, 0b000000001100000000000000000000000000000000000000000000),
+        (10, 0b000000000000000011000000000000110000000000000000000000),
+        (12, 0b000000110000000000000000000000000000000000000000000000),
+        (14, 0b000000000000001100000000000000000000000000000000000000),
+        (18, 0b000000000000110000000000000000000000000000000000000000),
+    ],
+    &[
+        (-24, 0b000000000000000000000000000000000000000000000000000011),
+        (-20, 0b000000000000000000000000000000000000000000000000001100),
+        (-16, 0b000000000000000000000000000000000011000000000000110000),
+        (-12, 0b000000000000000000000000000000001100000000000011000000),
+        (-8, 0b000000000000000011000000000000110000000000001100000000),
+        (-4, 0b000000000000001100000000000011000000000000110000000000),
+        (0, 0b000000000000110000000000001100000000000011000000000000),
+        (4, 0b000000000011000000000000110000000000001100000000000000),
+        (8, 0b000000001100000000000011000000000000110000000000000000),
+        (12, 0b000000110000000000001100000000000000000000000000000000),
+        (16, 0b000011000000000000110000000000000000000000000000000000),
+        (20, 0b001100000000000000000000000000000000000000000000000000),
+        (24, 0b110000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-18, 0b000000000000000000000000000000000000110000000000000000),
+        (-16, 0b000000000000000000000000000000000000001100000000000000),
+        (-14, 0b000000000000000000000000000000000000000011000000000000),
+        (-12, 0b000000000000000000000000000000000000000000110000000000),
+        (-10, 0b000000000000000000000000000000000000000000001100000000),
+        (-8, 0b000000000000000000110000000000000000000000000011000000),
+        (-6, 0b000000000000000000001100000000000000000000000000110000),
+        (-4, 0b000000000000000000000011000000000000000000000000001100),
+        (-2, 0b000000000000000000000000110000000000000000000000000011),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b110000000000000000000000000011000000000000000000000000),
+        (4, 0b001100000000000000000000000000110000000000000000000000),
+        (6, 0b000011000000000000000000000000001100000000000000000000),
+        (8, 0b000000110000000000000000000000000011000000000000000000),
+        (10, 0b000000001100000000000000000000000000000000000000000000),
+        (12, 0b000000000011000000000000000000000000000000000000000000),
+        (14, 0b000000000000110000000000000000000000000000000000000000),
+        (16, 0b000000000000001100000000000000000000000000000000000000),
+        (18, 0b000000000000000011000000000000000000000000000000000000),
+    ],
+    &[
+        (-24, 0b000000000000000000000000000000000000000000000000110000),
+        (-22, 0b000000000000000000000000000000000000000000000000001100),
+        (-20, 0b000000000000000000000000000000000000000000000000000011),
+        (-14, 0b000000000000000000000000000000110000000000000000000000),
+        (-12, 0b000000000000000000000000000000001100000000110000000000),
+        (-10, 0b000000000000000000000000000000000011000000001100000000),
+        (-8, 0b000000000000000000000000000000000000000000000011000000),
+        (-4, 0b000000000000110000000000000000000000000000000000000000),
+        (-2, 0b000000000000001100000000110000000000000000000000000000),
+        (0, 0b000000000000000011000000001100000000110000000000000000),
+        (2, 0b000000000000000000000000000011000000001100000000000000),
+        (4, 0b000000000000000000000000000000000000000011000000000000),
+        (8, 0b000000110000000000000000000000000000000000000000000000),
+        (10, 0b000000001100000000110000000000000000000000000000000000),
+        (12, 0b000000000011000000001100000000000000000000000000000000),
+        (14, 0b000000000000000000000011000000000000000000000000000000),
+        (20, 0b110000000000000000000000000000000000000000000000000000),
+        (22, 0b001100000000000000000000000000000000000000000000000000),
+        (24, 0b000011000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-20, 0b000000000000000000000000000000000000000011000000000000),
+        (-16, 0b000000000000000000000000000000000000001100000000000000),
+        (-14, 0b000000000000000000000000000000000000000000000011000000),
+        (-12, 0b000000000000000000000000000000000000110000000000000000),
+        (-10, 0b000000000000000000000011000000000000000000001100000000),
+        (-8, 0b000000000000000000000000000000000000000000000000000011),
+        (-6, 0b000000000000000000001100000000000000000000110000000000),
+        (-4, 0b000000000000000000000000000011000000000000000000001100),
+        (-2, 0b000000000000000000110000000000000000000000000000000000),
+        (0, 0b000011000000000000000000001100000000000000000000110000),
+        (2, 0b000000000000000000000000000000000011000000000000000000),
+        (4, 0b001100000000000000000000110000000000000000000000000000),
+        (6, 0b000000000011000000000000000000001100000000000000000000),
+        (8, 0b110000000000000000000000000000000000000000000000000000),
+        (10, 0b000000001100000000000000000000110000000000000000000000),
+        (12, 0b000000000000000011000000000000000000000000000000000000),
+        (14, 0b000000110000000000000000000000000000000000000000000000),
+        (16, 0b000000000000001100000000000000000000000000000000000000),
+        (20, 0b000000000000110000000000000000000000000000000000000000),
+    ],
+    &[
+        (-26, 0b000000000000000000000000000000000000000000000000000011),
+        (-22, 0b000000000000000000000000000000000000000000000000001100),
+        (-18, 0b000000000000000000000000000000000000000000000000110000),
+        (-16, 0b000000000000000000000000000000000011000000000000000000),
+        (-14, 0b000000000000000000000000000000000000000000000011000000),
+        (-12, 0b000000000000000000000000000000001100000000000000000000),
+        (-10, 0b000000000000000000000000000000000000000000001100000000),
+        (-8, 0b000000000000000000000000000000110000000000000000000000),
+        (-6, 0b000000000000000011000000000000000000000000110000000000),
+        (-4, 0b000000000000000000000000000011000000000000000000000000),
+        (-2, 0b000000000000001100000000000000000000000011000000000000),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b000000000000110000000000000000000000001100000000000000),
+        (4, 0b000000000000000000000000110000000000000000000000000000),
+        (6, 0b000000000011000000000000000000000000110000000000000000),
+        (8, 0b000000000000000000000011000000000000000000000000000000),
+        (10, 0b000000001100000000000000000000000000000000000000000000),
+        (12, 0b000000000000000000001100000000000000000000000000000000),
+        (14, 0b000000110000000000000000000000000000000000000000000000),
+        (16, 0b000000000000000000110000000000000000000000000000000000),
+        (18, 0b000011000000000000000000000000000000000000000000000000),
+        (22, 0b001100000000000000000000000000000000000000000000000000),
+        (26, 0b110000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-16, 0b000000000000000000000000000000000000000000000000110000),
+        (-14, 0b000000000000000000000000000000000000000000110000000000),
+        (-12, 0b000000000000000000000000000000000000110000000000000000),
+        (-10, 0b000000000000000000000000000000110000000000000000000000),
+        (-8, 0b000000000000000000000000110000000000000000000000001100),
+        (-6, 0b000000000000000000110000000000000000000000001100000000),
+        (-4, 0b000000000000110000000000000000000000001100000000000000),
+        (-2, 0b000000110000000000000000000000001100000000000000000000),
+        (0, 0b110000000000000000000000001100000000000000000000000011),
+        (2, 0b000000000000000000001100000000000000000000000011000000),
+        (4, 0b000000000000001100000000000000000000000011000000000000),
+        (6, 0b000000001100000000000000000000000011000000000000000000),
+        (8, 0b001100000000000000000000000011000000000000000000000000),
+        (10, 0b000000000000000000000011000000000000000000000000000000),
+        (12, 0b000000000000000011000000000000000000000000000000000000),
+        (14, 0b000000000011000000000000000000000000000000000000000000),
+        (16, 0b000011000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-18, 0b000000000000000000000000000000000000000000000000000011),
+        (-16, 0b000000000000000000000000000000000000000000000011000000),
+        (-14, 0b000000000000000000000000000000000000000011000000000000),
+        (-12, 0b000000000000000000000000000000000011000000000000000000),
+        (-10, 0b000000000000000000000000000011000000000000000000000000),
+        (-8, 0b000000000000000000000011000000000000000000000000001100),
+        (-6, 0b000000000000000011000000000000000000000000001100000000),
+        (-4, 0b000000000011000000000000000000000000001100000000000000),
+        (-2, 0b000011000000000000000000000000001100000000000000000000),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b000000000000000000001100000000000000000000000000110000),
+        (4, 0b000000000000001100000000000000000000000000110000000000),
+        (6, 0b000000001100000000000000000000000000110000000000000000),
+        (8, 0b001100000000000000000000000000110000000000000000000000),
+        (10, 0b000000000000000000000000110000000000000000000000000000),
+        (12, 0b000000000000000000110000000000000000000000000000000000),
+        (14, 0b000000000000110000000000000000000000000000000000000000),
+        (16, 0b000000110000000000000000000000000000000000000000000000),
+        (18, 0b110000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-22, 0b000000000000000000000000000000000000000000000000110000),
+        (-20, 0b000000000000000000000000000000000000000000110000000000),
+        (-18, 0b000000000000000000000000000000000000110000000000000000),
+        (-14, 0b000000000000000000000000000000000000000000000000001100),
+        (-12, 0b000000000000000000000000000000000000000000001100000000),
+        (-10, 0b000000000000000000000000000000110000001100000000000000),
+        (-8, 0b000000000000000000000000110000000000000000000000000000),
+        (-6, 0b000000000000000000110000000000000000000000000000000011),
+        (-4, 0b000000000000000000000000000000000000000000000011000000),
+        (-2, 0b000000000000000000000000000000001100000011000000000000),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b000000000000110000001100000000000000000000000000000000),
+        (4, 0b000000110000000000000000000000000000000000000000000000),
+        (6, 0b110000000000000000000000000000000011000000000000000000),
+        (8, 0b000000000000000000000000000011000000000000000000000000),
+        (10, 0b000000000000001100000011000000000000000000000000000000),
+        (12, 0b000000001100000000000000000000000000000000000000000000),
+        (14, 0b001100000000000000000000000000000000000000000000000000),
+        (18, 0b000000000000000011000000000000000000000000000000000000),
+        (20, 0b000000000011000000000000000000000000000000000000000000),
+        (22, 0b000011000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-24, 0b000000000000000000000000000000000000000000000000000011),
+        (-22, 0b000000000000000000000000000000000000000000000011000000),
+        (-20, 0b000000000000000000000000000000000000000011000000000000),
+        (-14, 0b000000000000000000000000000000000000000000000000001100),
+        (-12, 0b000000000000000000000000000000000011000000001100000000),
+        (-10, 0b000000000000000000000000000011000000001100000000000000),
+        (-8, 0b000000000000000000000011000000000000000000000000000000),
+        (-4, 0b000000000000000000000000000000000000000000000000110000),
+        (-2, 0b000000000000000000000000000000001100000000110000000000),
+        (0, 0b000000000000000011000000001100000000110000000000000000),
+        (2, 0b000000000011000000001100000000000000000000000000000000),
+        (4, 0b000011000000000000000000000000000000000000000000000000),
+        (8, 0b000000000000000000000000000000110000000000000000000000),
+        (10, 0b000000000000001100000000110000000000000000000000000000),
+        (12, 0b000000001100000000110000000000000000000000000000000000),
+        (14, 0b001100000000000000000000000000000000000000000000000000),
+        (20, 0b000000000000110000000000000000000000000000000000000000),
+        (22, 0b000000110000000000000000000000000000000000000000000000),
+        (24, 0b110000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-18, 0b000000000000000000000000000000000000000000000000110000),
+        (-14, 0b000000000000000000000000000000000000000000110000000000),
+        (-12, 0b000000000000000000000000000000110000000000000000000000),
+        (-10, 0b000000000000000000000000000000000000110000000000001100),
+        (-8, 0b000000000000000000000000110000000000000000000000000000),
+        (-6, 0b000000000000110000000000000000000000000000001100000000),
+        (-4, 0b000000000000000000110000000000001100000000000000000000),
+        (-2, 0b000000110000000000000000000000000000001100000000000011),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b110000000000001100000000000000000000000000000011000000),
+        (4, 0b000000000000000000001100000000000011000000000000000000),
+        (6, 0b000000001100000000000000000000000000000011000000000000),
+        (8, 0b000000000000000000000000000011000000000000000000000000),
+        (10, 0b001100000000000011000000000000000000000000000000000000),
+        (12, 0b000000000000000000000011000000000000000000000000000000),
+        (14, 0b000000000011000000000000000000000000000000000000000000),
+        (18, 0b000011000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-20, 0b000000000000000000000000000000000000000000000000000011),
+        (-16, 0b000000000000000000000000000000000000000000000011000000),
+        (-14, 0b000000000000000000000000000000000011000000000000000000),
+        (-12, 0b000000000000000000000000000000000000000011000000000000),
+        (-10, 0b000000000000000000000000000011000000000000000000001100),
+        (-8, 0b000000000000000011000000000000000000000000000000000000),
+        (-6, 0b000000000000000000000011000000000000000000001100000000),
+        (-4, 0b000000000011000000000000000000001100000000000000000000),
+        (-2, 0b000000000000000000000000000000000000001100000000000000),
+        (0, 0b000011000000000000000000001100000000000000000000110000),
+        (2, 0b000000000000001100000000000000000000000000000000000000),
+        (4, 0b000000000000000000001100000000000000000000110000000000),
+        (6, 0b000000001100000000000000000000110000000000000000000000),
+        (8, 0b000000000000000000000000000000000000110000000000000000),
+        (10, 0b001100000000000000000000110000000000000000000000000000),
+        (12, 0b000000000000110000000000000000000000000000000000000000),
+        (14, 0b000000000000000000110000000000000000000000000000000000),
+        (16, 0b000000110000000000000000000000000000000000000000000000),
+        (20, 0b110000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-24, 0b000000000000000000000000000000000000000000000000110000),
+        (-20, 0b000000000000000000000000000000000000000000110000000000),
+        (-16, 0b000000000000000000000000000000000000110000000000001100),
+        (-12, 0b000000000000000000000000000000110000000000001100000000),
+        (-8, 0b000000000000000000000000110000000000001100000000000011),
+        (-4, 0b000000000000000000110000000000001100000000000011000000),
+        (0, 0b000000000000110000000000001100000000000011000000000000),
+        (4, 0b000000110000000000001100000000000011000000000000000000),
+        (8, 0b110000000000001100000000000011000000000000000000000000),
+        (12, 0b000000001100000000000011000000000000000000000000000000),
+        (16, 0b001100000000000011000000000000000000000000000000000000),
+        (20, 0b000000000011000000000000000000000000000000000000000000),
+        (24, 0b000011000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-26, 0b000000000000000000000000000000000000000000000000000011),
+        (-22, 0b000000000000000000000000000000000000000000000011000000),
+        (-18, 0b000000000000000000000000000000000000000011000000000000),
+        (-16, 0b000000000000000000000000000000000000000000000000001100),
+        (-14, 0b000000000000000000000000000000000011000000000000000000),
+        (-12, 0b000000000000000000000000000000000000000000001100000000),
+        (-10, 0b000000000000000000000000000011000000000000000000000000),
+        (-8, 0b000000000000000000000000000000000000001100000000000000),
+        (-6, 0b000000000000000000000011000000000000000000000000110000),
+        (-4, 0b000000000000000000000000000000001100000000000000000000),
+        (-2, 0b000000000000000011000000000000000000000000110000000000),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b000000000011000000000000000000000000110000000000000000),
+        (4, 0b000000000000000000001100000000000000000000000000000000),
+        (6, 0b000011000000000000000000000000110000000000000000000000),
+        (8, 0b000000000000001100000000000000000000000000000000000000),
+        (10, 0b000000000000000000000000110000000000000000000000000000),
+        (12, 0b000000001100000000000000000000000000000000000000000000),
+        (14, 0b000000000000000000110000000000000000000000000000000000),
+        (16, 0b001100000000000000000000000000000000000000000000000000),
+        (18, 0b000000000000110000000000000000000000000000000000000000),
+        (22, 0b000000110000000000000000000000000000000000000000000000),
+        (26, 0b110000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-16, 0b000000000000000000000000000000000000110000110000110000),
+        (-8, 0b000000000000000000110000110000110000001100001100001100),
+        (0, 0b110000110000110000001100001100001100000011000011000011),
+        (8, 0b001100001100001100000011000011000011000000000000000000),
+        (16, 0b000011000011000011000000000000000000000000000000000000),
+    ],
+    &[
+        (-18, 0b000000000000000000000000000000000000000011000011000011),
+        (-10, 0b000000000000000000000011000011000011000000000000000000),
+        (-8, 0b000000000000000000000000000000000000001100001100001100),
+        (-2, 0b000011000011000011000000000000000000000000000000000000),
+        (0, 0b000000000000000000001100001100001100000000000000000000),
+        (2, 0b000000000000000000000000000000000000110000110000110000),
+        (8, 0b001100001100001100000000000000000000000000000000000000),
+        (10, 0b000000000000000000110000110000110000000000000000000000),
+        (18, 0b110000110000110000000000000000000000000000000000000000),
+    ],
+    &[
+        (-22, 0b000000000000000000000000000000000000000000000000110000),
+        (-16, 0b000000000000000000000000000000000000000000110000000000),
+        (-14, 0b000000000000000000000000000000110000000000000000001100),
+        (-10, 0b000000000000000000000000000000000000110000000000000000),
+        (-8, 0b000000000000000000000000110000000000000000001100000000),
+        (-6, 0b000000000000110000000000000000001100000000000000000011),
+        (-2, 0b000000000000000000110000000000000000001100000000000000),
+        (0, 0b000000110000000000000000001100000000000000000011000000),
+        (2, 0b000000000000001100000000000000000011000000000000000000),
+        (6, 0b110000000000000000001100000000000000000011000000000000),
+        (8, 0b000000001100000000000000000011000000000000000000000000),
+        (10, 0b000000000000000011000000000000000000000000000000000000),
+        (14, 0b001100000000000000000011000000000000000000000000000000),
+        (16, 0b000000000011000000000000000000000000000000000000000000),
+        (22, 0b000011000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-24, 0b000000000000000000000000000000000000000000000000000011),
+        (-18, 0b000000000000000000000000000000000000000000000011000000),
+        (-16, 0b000000000000000000000000000000000011000000000000000000),
+        (-14, 0b000000000000000000000000000000000000000000000000001100),
+        (-12, 0b000000000000000000000000000000000000000011000000000000),
+        (-10, 0b000000000000000000000000000011000000000000000000000000),
+        (-8, 0b000000000000000011000000000000000000000000001100000000),
+        (-6, 0b000000000000000000000000000000001100000000000000000000),
+        (-4, 0b000000000000000000000011000000000000000000000000110000),
+        (-2, 0b000000000011000000000000000000000000001100000000000000),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b000000000000001100000000000000000000000000110000000000),
+        (4, 0b000011000000000000000000000000110000000000000000000000),
+        (6, 0b000000000000000000001100000000000000000000000000000000),
+        (8, 0b000000001100000000000000000000000000110000000000000000),
+        (10, 0b000000000000000000000000110000000000000000000000000000),
+        (12, 0b000000000000110000000000000000000000000000000000000000),
+        (14, 0b001100000000000000000000000000000000000000000000000000),
+        (16, 0b000000000000000000110000000000000000000000000000000000),
+        (18, 0b000000110000000000000000000000000000000000000000000000),
+        (24, 0b110000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-18, 0b000000000000000000000000000000000000110000110000110000),
+        (-10, 0b000000000000000000000000000000000000001100001100001100),
+        (-8, 0b000000000000000000110000110000110000000000000000000000),
+        (-2, 0b000000000000000000000000000000000000000011000011000011),
+        (0, 0b000000000000000000001100001100001100000000000000000000),
+        (2, 0b110000110000110000000000000000000000000000000000000000),
+        (8, 0b000000000000000000000011000011000011000000000000000000),
+        (10, 0b001100001100001100000000000000000000000000000000000000),
+        (18, 0b000011000011000011000000000000000000000000000000000000),
+    ],
+    &[
+        (-20, 0b000000000000000000000000000000000000000011000011000011),
+        (-10, 0b000000000000000000000011000011000011001100001100001100),
+        (0, 0b000011000011000011001100001100001100110000110000110000),
+        (10, 0b001100001100001100110000110000110000000000000000000000),
+        (20, 0b110000110000110000000000000000000000000000000000000000),
+    ],
+    &[
+        (-24, 0b000000000000000000000000000000000000000000000000110000),
+        (-18, 0b000000000000000000000000000000000000000000110000000000),
+        (-16, 0b000000000000000000000000000000000000000000000000001100),
+        (-14, 0b000000000000000000000000000000110000000000000000000000),
+        (-12, 0b000000000000000000000000000000000000110000000000000000),
+        (-10, 0b000000000000000000000000000000000000000000001100000000),
+        (-8, 0b000000000000000000000000110000000000000000000000000011),
+        (-6, 0b000000000000000000000000000000001100000000000000000000),
+        (-4, 0b000000000000110000000000000000000000001100000000000000),
+        (-2, 0b000000000000000000110000000000000000000000000011000000),
+        (0, 0b000000000000000000000000001100000000000000000000000000),
+        (2, 0b000000110000000000000000000000000011000000000000000000),
+        (4, 0b000000000000001100000000000000000000000011000000000000),
+        (6, 0b000000000000000000001100000000000000000000000000000000),
+        (8, 0b110000000000000000000000000011000000000000000000000000),
+        (10, 0b000000001100000000000000000000000000000000000000000000),
+        (12, 0b000000000000000011000000000000000000000000000000000000),
+        (14, 0b000000000000000000000011000000000000000000000000000000),
+        (16, 0b001100000000000000000000000000000000000000000000000000),
+        (18, 0b000000000011000000000000000000000000000000000000000000),
+        (24, 0b000011000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-26, 0b000000000000000000000000000000000000000000000000000011),
+        (-20, 0b000000000000000000000000000000000000000000000011000000),
+        (-16, 0b000000000000000000000000000000000011000000000000001100),
+        (-14, 0b000000000000000000000000000000000000000011000000000000),
+        (-10, 0b000000000000000000000000000011000000000000001100000000),
+        (-6, 0b000000000000000011000000000000001100000000000000110000),
+        (-4, 0b000000000000000000000011000000000000001100000000000000),
+        (0, 0b000000000011000000000000001100000000000000110000000000),
+        (4, 0b000000000000001100000000000000110000000000000000000000),
+        (6, 0b000011000000000000001100000000000000110000000000000000),
+        (10, 0b000000001100000000000000110000000000000000000000000000),
+        (14, 0b000000000000110000000000000000000000000000000000000000),
+        (16, 0b001100000000000000110000000000000000000000000000000000),
+        (20, 0b000000110000000000000000000000000000000000000000000000),
+        (26, 0b110000000000000000000000000000000000000000000000000000),
+    ],
+];
+pub const CUBE4_WIN_MASKS: [u128; 76] = [
+    0b01000000000000000000000000000000000000000001000000000000000000000000000000000000000001000000000000000000000000000000000000000001,
+    0b00000001000000000000000000000000000000000000000100000000000000000000000000000000000000010000000000000000000000000000000000000001,
+    0b00000100000000000000000000000000000000000000010000000000000000000000000000000000000001000000000000000000000000000000000000000100,
+    0b00010000000000000000000000000000000000000001000000000000000000000000000000000000000100000000000000000000000000000000000000010000,
+    0b01000000000000000000000000000000000000000100000000000000000000000000000000000000010000000000000000000000000000000000000001000000,
+    0b00000001000000000000000000000000000000000000010000000000000000000000000000000000000100000000000000000000000000000000000001000000,
+    0b00000000000000000000000001000000000000000000000000000000000100000000000000000000000000000000010000000000000000000000000000000001,
+    0b00000000000000000100000000000000000000000000000000010000000000000000000000000000000001000000000000000000000000000000000100000000,
+    0b00000000010000000000000000000000000000000001000000000000000000000000000000000100000000000000000000000000000000010000000000000000,
+    0b01000000000000000000000000000000000100000000000000000000000000000000010000000000000000000000000000000001000000000000000000000000,
+    0b00000000000000000000000000000001000000000000000000000000000000010000000000000000000000000000000100000000000000000000000000000001,
+    0b00000000000000000000000000000100000000000000000000000000000001000000000000000000000000000000010000000000000000000000000000000100,
+    0b00000000000000000000000000010000000000000000000000000000000100000000000000000000000000000001000000000000000000000000000000010000,
+    0b00000000000000000000000001000000000000000000000000000000010000000000000000000000000000000100000000000000000000000000000001000000,
+    0b00000000000000000000000100000000000000000000000000000001000000000000000000000000000000010000000000000000000000000000000100000000,
+    0b00000000000000000000010000000000000000000000000000000100000000000000000000000000000001000000000000000000000000000000010000000000,
+    0b00000000000000000001000000000000000000000000000000010000000000000000000000000000000100000000000000000000000000000001000000000000,
+    0b00000000000000000100000000000000000000000000000001000000000000000000000000000000010000000000000000000000000000000100000000000000,
+    0b00000000000000010000000000000000000000000000000100000000000000000000000000000001000000000000000000000000000000010000000000000000,
+    0b00000000000001000000000000000000000000000000010000000000000000000000000000000100000000000000000000000000000001000000000000000000,
+    0b00000000000100000000000000000000000000000001000000000000000000000000000000010000000000000000000000000000000100000000000000000000,
+    0b00000000010000000000000000000000000000000100000000000000000000000000000001000000000000000000000000000000010000000000000000000000,
+    0b00000001000000000000000000000000000000010000000000000000000000000000000100000000000000000000000000000001000000000000000000000000,
+    0b00000100000000000000000000000000000001000000000000000000000000000000010000000000000000000000000000000100000000000000000000000000,
+    0b00010000000000000000000000000000000100000000000000000000000000000001000000000000000000000000000000010000000000000000000000000000,
+    0b01000000000000000000000000000000010000000000000000000000000000000100000000000000000000000000000001000000000000000000000000000000,
+    0b00000000000000000000000000000001000000000000000000000000000001000000000000000000000000000001000000000000000000000000000001000000,
+    0b00000000000000000000000100000000000000000000000000000100000000000000000000000000000100000000000000000000000000000100000000000000,
+    0b00000000000000010000000000000000000000000000010000000000000000000000000000010000000000000000000000000000010000000000000000000000,
+    0b00000001000000000000000000000000000001000000000000000000000000000001000000000000000000000000000001000000000000000000000000000000,
+    0b00000000000000000000000001000000000000000000000000010000000000000000000000000100000000000000000000000001000000000000000000000000,
+    0b00000000000000000000000000000001000000000000000000000001000000000000000000000001000000000000000000000001000000000000000000000000,
+    0b00000000000000000000000000000100000000000000000000000100000000000000000000000100000000000000000000000100000000000000000000000000,
+    0b00000000000000000000000000010000000000000000000000010000000000000000000000010000000000000000000000010000000000000000000000000000,
+    0b00000000000000000000000001000000000000000000000001000000000000000000000001000000000000000000000001000000000000000000000000000000,
+    0b00000000000000000000000000000001000000000000000000000100000000000000000000010000000000000000000001000000000000000000000000000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000000100000000010000000001,
+    0b00000000000000000000000000000000000000000000000000000000000000000100000000010000000001000000000100000000000000000000000000000000,
+    0b00000000000000000000000000000000010000000001000000000100000000010000000000000000000000000000000000000000000000000000000000000000,
+    0b01000000000100000000010000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000010000000100000001,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000100000001000000010000000100,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000100000001000000010000,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000010000000100000001000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000100000001000000010000000100000000000000000000000000000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000000010000000100000001000000010000000000000000000000000000000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000001000000010000000100000001000000000000000000000000000000000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000100000001000000010000000100000000000000000000000000000000000000,
+    0b00000000000000000000000000000000000000010000000100000001000000010000000000000000000000000000000000000000000000000000000000000000,
+    0b00000000000000000000000000000000000001000000010000000100000001000000000000000000000000000000000000000000000000000000000000000000,
+    0b00000000000000000000000000000000000100000001000000010000000100000000000000000000000000000000000000000000000000000000000000000000,
+    0b00000000000000000000000000000000010000000100000001000000010000000000000000000000000000000000000000000000000000000000000000000000,
+    0b00000001000000010000000100000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,
+    0b00000100000001000000010000000100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,
+    0b00010000000100000001000000010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,
+    0b01000000010000000100000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000001000001000001000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000100000100000100000100000000000000000000000000000000000000,
+    0b00000000000000000000000000000000000000010000010000010000010000000000000000000000000000000000000000000000000000000000000000000000,
+    0b00000001000001000001000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001010101,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000101010100000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010101010000000000000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001010101000000000000000000000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000101010100000000000000000000000000000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000000000000010101010000000000000000000000000000000000000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000000000001010101000000000000000000000000000000000000000000000000,
+    0b00000000000000000000000000000000000000000000000000000000000000000101010100000000000000000000000000000000000000000000000000000000,
+    0b00000000000000000000000000000000000000000000000000000000010101010000000000000000000000000000000000000000000000000000000000000000,
+    0b00000000000000000000000000000000000000000000000001010101000000000000000000000000000000000000000000000000000000000000000000000000,
+    0b00000000000000000000000000000000000000000101010100000000000000000000000000000000000000000000000000000000000000000000000000000000,
+    0b00000000000000000000000000000000010101010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,
+    0b00000000000000000000000001010101000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,
+    0b00000000000000000101010100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,
+    0b00000000010101010000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,
+    0b01010101000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000,
+];
+pub const CUBE4_TRANSFORM_SHIFTS: [&[(i32, u128)]; 48] = [
+    &[
+        (0, 0b11111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111),
+    ],
+    &[
+        (-12, 0b00000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000),
+        (-9, 0b00000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000),
+        (-7, 0b00000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000),
+        (-6, 0b00000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100),
+        (-4, 0b00000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000),
+        (-3, 0b00000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011),
+        (-2, 0b00000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000),
+        (-1, 0b00000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000),
+        (1, 0b00000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000),
+        (2, 0b00000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000),
+        (3, 0b11000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000),
+        (4, 0b00000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000),
+        (6, 0b00110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000),
+        (7, 0b00000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000),
+        (9, 0b00001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000),
+        (12, 0b00000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000),
+    ],
+    &[
+        (-15, 0b00000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011),
+        (-13, 0b00000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100),
+        (-11, 0b00000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000),
+        (-9, 0b00000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000),
+        (-7, 0b00000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000),
+        (-5, 0b00000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000),
+        (-3, 0b00000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000),
+        (-1, 0b00000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000),
+        (1, 0b00000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000),
+        (3, 0b00000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000),
+        (5, 0b00000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000),
+        (7, 0b00000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000),
+        (9, 0b00000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000),
+        (11, 0b00001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000),
+        (13, 0b00110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000),
+        (15, 0b11000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000),
+    ],
+    &[
+        (-12, 0b00000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011),
+        (-9, 0b00000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000),
+        (-7, 0b00000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100),
+        (-6, 0b00000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000),
+        (-4, 0b00000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000),
+        (-3, 0b00000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000),
+        (-2, 0b00000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000),
+        (-1, 0b00000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000),
+        (1, 0b00000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000),
+        (2, 0b00001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000),
+        (3, 0b00000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000),
+        (4, 0b00000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000),
+        (6, 0b00000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000),
+        (7, 0b00110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000),
+        (9, 0b00000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000110000000000000000000000),
+        (12, 0b11000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000),
+    ],
+    &[
+        (-12, 0b00000000000000000000000011111111000000000000000000000000111111110000000000000000000000001111111100000000000000000000000011111111),
+        (-4, 0b00000000000000001111111100000000000000000000000011111111000000000000000000000000111111110000000000000000000000001111111100000000),
+        (4, 0b00000000111111110000000000000000000000001111111100000000000000000000000011111111000000000000000000000000111111110000000000000000),
+        (12, 0b11111111000000000000000000000000111111110000000000000000000000001111111100000000000000000000000011111111000000000000000000000000),
+    ],
+    &[
+        (-9, 0b00000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000),
+        (-6, 0b00000000000000001100000000110000000000000000000011000000001100000000000000000000110000000011000000000000000000001100000000110000),
+        (-3, 0b00000000110000000011000000001100000000001100000000110000000011000000000011000000001100000000110000000000110000000011000000001100),
+        (0, 0b11000000001100000000110000000011110000000011000000001100000000111100000000110000000011000000001111000000001100000000110000000011),
+        (3, 0b00110000000011000000001100000000001100000000110000000011000000000011000000001100000000110000000000110000000011000000001100000000),
+        (6, 0b00001100000000110000000000000000000011000000001100000000000000000000110000000011000000000000000000001100000000110000000000000000),
+        (9, 0b00000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000),
+    ],
+    &[
+        (-3, 0b00000011000000110000001100000011000000110000001100000011000000110000001100000011000000110000001100000011000000110000001100000011),
+        (-1, 0b00001100000011000000110000001100000011000000110000001100000011000000110000001100000011000000110000001100000011000000110000001100),
+        (1, 0b00110000001100000011000000110000001100000011000000110000001100000011000000110000001100000011000000110000001100000011000000110000),
+        (3, 0b11000000110000001100000011000000110000001100000011000000110000001100000011000000110000001100000011000000110000001100000011000000),
+    ],
+    &[
+        (-15, 0b00000000000000000000000000000011000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011),
+        (-10, 0b00000000000000000000001100001100000000000000000000000011000011000000000000000000000000110000110000000000000000000000001100001100),
+        (-5, 0b00000000000000110000110000110000000000000000001100001100001100000000000000000011000011000011000000000000000000110000110000110000),
+        (0, 0b00000011000011000011000011000000000000110000110000110000110000000000001100001100001100001100000000000011000011000011000011000000),
+        (5, 0b00001100001100001100000000000000000011000011000011000000000000000000110000110000110000000000000000001100001100001100000000000000),
+        (10, 0b00110000110000000000000000000000001100001100000000000000000000000011000011000000000000000000000000110000110000000000000000000000),
+        (15, 0b11000000000000000000000000000000110000000000000000000000000000001100000000000000000000000000000011000000000000000000000000000000),
+    ],
+    &[
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011111111111111111111111111111111),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000001111111111111111111111111111111100000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000111111111111111111111111111111110000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b11111111111111111111111111111111000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-60, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011111111),
+        (-52, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001111111100000000),
+        (-44, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000111111110000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011111111000000000000000000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001111111100000000000000000000000000000000),
+        (-20, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000111111110000000000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000000000000000000000000000000000000000000000000000011111111000000000000000000000000000000000000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000000000000000001111111100000000000000000000000000000000000000000000000000000000),
+        (4, 0b00000000000000000000000000000000000000000000000000000000111111110000000000000000000000000000000000000000000000000000000000000000),
+        (12, 0b00000000000000000000000000000000000000000000000011111111000000000000000000000000000000000000000000000000000000000000000000000000),
+        (20, 0b00000000000000000000000000000000000000001111111100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000000000000000000000000000111111110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000011111111000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (44, 0b00000000000000001111111100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (52, 0b00000000111111110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (60, 0b11111111000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000110000001100000011),
+        (-49, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000011000000110000001100),
+        (-47, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000001100000011000000110000),
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000110000001100000011000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000011000000110000001100000000000000000000000000000000),
+        (-17, 0b00000000000000000000000000000000000000000000000000000000000000000000110000001100000011000000110000000000000000000000000000000000),
+        (-15, 0b00000000000000000000000000000000000000000000000000000000000000000011000000110000001100000011000000000000000000000000000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000000001100000011000000110000001100000000000000000000000000000000000000),
+        (13, 0b00000000000000000000000000000000000000110000001100000011000000110000000000000000000000000000000000000000000000000000000000000000),
+        (15, 0b00000000000000000000000000000000000011000000110000001100000011000000000000000000000000000000000000000000000000000000000000000000),
+        (17, 0b00000000000000000000000000000000001100000011000000110000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000110000001100000011000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000011000000110000001100000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (47, 0b00001100000011000000110000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (49, 0b00110000001100000011000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b11000000110000001100000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-63, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-61, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-59, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-57, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-55, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-53, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-49, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-47, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-43, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-41, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-37, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-35, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (-29, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (-25, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (-23, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (-17, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (-15, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (-11, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (-9, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (-7, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (-5, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (-3, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (-1, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (3, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (5, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (7, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (9, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (11, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (13, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (15, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (17, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (23, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (25, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (29, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (35, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (37, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (41, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (43, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (47, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (49, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (53, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (55, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (57, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (59, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (61, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (63, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-57, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-54, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000110000),
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000011000000001100),
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000001100000000110000000011),
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000011000000001100000000),
+        (-42, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000110000000000000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-25, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (-22, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000011000000000000000000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000001100000000110000000000000000000000000000000000),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000001100000000110000000011000000001100000000000000000000000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000000000011000000001100000000110000000000000000000000000000000000000000),
+        (-10, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000011000000000000000000000000000000000000000000000000),
+        (-7, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (7, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (10, 0b00000000000000000000000000000000000000000000000011000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (13, 0b00000000000000000000000000000000000000001100000000110000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000110000000011000000001100000000110000000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000001100000000110000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (22, 0b00000000000000000000000000000000000011000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (25, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (42, 0b00000000000000001100000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000000110000000011000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b11000000001100000000110000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b00110000000011000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (54, 0b00001100000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (57, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-60, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-57, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-55, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-54, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-52, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-50, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-49, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-47, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-46, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-44, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-42, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-41, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (-25, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (-23, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (-22, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (-20, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (-17, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (-15, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (-14, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (-10, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (-9, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (-7, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (4, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (7, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (9, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (10, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (12, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (13, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (14, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (15, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (17, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (18, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (20, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (22, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (23, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (25, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (41, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (42, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (44, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (46, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (47, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (49, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (50, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (52, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (54, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (55, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (57, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (60, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-60, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-57, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-55, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-54, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-52, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-50, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-49, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-47, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-46, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (-44, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-42, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-41, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (-25, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (-23, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (-22, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (-20, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (-17, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (-15, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (-14, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (-10, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (-9, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (-7, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (4, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (7, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (9, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (10, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (12, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (13, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (14, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (15, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (17, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (18, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (20, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (22, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (23, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (25, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (41, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (42, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (44, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (46, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (47, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (49, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (50, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (52, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (54, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (55, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (57, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (60, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-63, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-58, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100001100),
+        (-53, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000110000110000),
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000011000011000011000000),
+        (-43, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100001100001100000000000000),
+        (-38, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000110000000000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (-26, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000110000000000000000000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000011000011000000000000000000000000000000000000),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000000000001100001100001100001100000000000000000000000000000000000000),
+        (-11, 0b00000000000000000000000000000000000000000000000000000000000000000000110000110000110000000000000000000000000000000000000000000000),
+        (-6, 0b00000000000000000000000000000000000000000000000000000000000000000011000011000000000000000000000000000000000000000000000000000000),
+        (-1, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (6, 0b00000000000000000000000000000000000000000000000000000011000011000000000000000000000000000000000000000000000000000000000000000000),
+        (11, 0b00000000000000000000000000000000000000000000001100001100001100000000000000000000000000000000000000000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000000000110000110000110000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000000000000000000000011000011000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (26, 0b00000000000000000000000000000000001100001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (38, 0b00000000000000000000001100001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (43, 0b00000000000000110000110000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b00000011000011000011000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (53, 0b00001100001100001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (58, 0b00110000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (63, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011111111000000000000000000000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000000000001111111100000000000000000000000000000000111111110000000000000000),
+        (-12, 0b00000000000000000000000000000000111111110000000000000000000000000000000011111111000000000000000000000000000000001111111100000000),
+        (0, 0b11111111000000000000000000000000000000001111111100000000000000000000000000000000111111110000000000000000000000000000000011111111),
+        (12, 0b00000000111111110000000000000000000000000000000011111111000000000000000000000000000000001111111100000000000000000000000000000000),
+        (24, 0b00000000000000001111111100000000000000000000000000000000111111110000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000011111111000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011111111),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001111111100000000000000000000000000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001111111100000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000111111110000000000000000000000000000000000000000000000000000000000000000),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000111111110000000000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000011111111000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-8, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000111111110000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000011111111000000000000000000000000000000000000000000000000000000000000000000000000),
+        (4, 0b00000000000000000000000000000000000000000000000000000000000000000000000011111111000000000000000000000000000000000000000000000000),
+        (8, 0b00000000000000001111111100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011111111000000000000000000000000),
+        (16, 0b00000000000000000000000000000000000000001111111100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000000000000000000000000000000000000000000000000000000000001111111100000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000111111110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000000000000111111110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b11111111000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011111111000000000000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000111111110000000000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000001111111100000000000000000000000000000000000000000000000000000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001111111100000000),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000000000000011111111000000000000000000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011111111),
+        (-8, 0b00000000000000000000000000000000111111110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000111111110000000000000000000000000000000000000000),
+        (4, 0b00000000000000000000000000000000000000001111111100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (8, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001111111100000000000000000000000000000000),
+        (12, 0b11111111000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000000000000000000011111111000000000000000000000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000111111110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000000000000000000000000000000000000000000000000000111111110000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000001111111100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b00000000000000000000000011111111000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-60, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011111111),
+        (-40, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001111111100000000000000001111111100000000),
+        (-20, 0b00000000000000000000000000000000000000000000000000000000111111110000000000000000111111110000000000000000111111110000000000000000),
+        (0, 0b00000000000000000000000011111111000000000000000011111111000000000000000011111111000000000000000011111111000000000000000000000000),
+        (20, 0b00000000000000001111111100000000000000001111111100000000000000001111111100000000000000000000000000000000000000000000000000000000),
+        (40, 0b00000000111111110000000000000000111111110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (60, 0b11111111000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-37, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-35, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000110000000000000000),
+        (-25, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000011000000000000000000),
+        (-23, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000001100000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000110000000000000000000000),
+        (-15, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000011000000000000000000000000000000000000001100000000),
+        (-13, 0b00000000000000000000000000000000000011000000000000000000000000000000000000001100000000000000000000000000000000000000110000000000),
+        (-11, 0b00000000000000000000000000000000001100000000000000000000000000000000000000110000000000000000000000000000000000000011000000000000),
+        (-9, 0b00000000000000000000000000000000110000000000000000000000000000000000000011000000000000000000000000000000000000001100000000000000),
+        (-3, 0b00000011000000000000000000000000000000000000001100000000000000000000000000000000000000110000000000000000000000000000000000000011),
+        (-1, 0b00001100000000000000000000000000000000000000110000000000000000000000000000000000000011000000000000000000000000000000000000001100),
+        (1, 0b00110000000000000000000000000000000000000011000000000000000000000000000000000000001100000000000000000000000000000000000000110000),
+        (3, 0b11000000000000000000000000000000000000001100000000000000000000000000000000000000110000000000000000000000000000000000000011000000),
+        (9, 0b00000000000000110000000000000000000000000000000000000011000000000000000000000000000000000000001100000000000000000000000000000000),
+        (11, 0b00000000000011000000000000000000000000000000000000001100000000000000000000000000000000000000110000000000000000000000000000000000),
+        (13, 0b00000000001100000000000000000000000000000000000000110000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (15, 0b00000000110000000000000000000000000000000000000011000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (21, 0b00000000000000000000001100000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (23, 0b00000000000000000000110000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (25, 0b00000000000000000011000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00000000000000001100000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (35, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (37, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-49, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-47, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (-37, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (-35, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-29, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000011000000000000),
+        (-25, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000001100000000000000),
+        (-23, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (-17, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (-15, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (-13, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (-11, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-9, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-7, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-5, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-3, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-1, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (5, 0b00000000000000000000001100000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (7, 0b00000000000000000000110000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (9, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (11, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (13, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (15, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (17, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (23, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (25, 0b00000000000000110000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00000000000011000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (29, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (35, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (37, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (47, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (49, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-49, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-47, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-37, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-35, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (-29, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000001100000000),
+        (-25, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000110000000000),
+        (-23, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (-17, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (-15, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000011),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000001100),
+        (-11, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-9, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-7, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (-5, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (-3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (-1, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (3, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (5, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (7, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (9, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (11, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (13, 0b00110000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (15, 0b11000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (17, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (23, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (25, 0b00000000001100000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00000000110000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (29, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (35, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (37, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (47, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (49, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-63, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-61, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-59, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-57, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-43, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000001100000000),
+        (-41, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000110000000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000011000000000000),
+        (-37, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000001100000000000000),
+        (-23, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000110000000000000000000000110000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000011000000000000000000000011000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000001100000000000000000000001100000000000000000000),
+        (-17, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000110000000000000000000000110000000000000000000000),
+        (-3, 0b00000000000000000000000000000011000000000000000000000011000000000000000000000011000000000000000000000011000000000000000000000000),
+        (-1, 0b00000000000000000000000000001100000000000000000000001100000000000000000000001100000000000000000000001100000000000000000000000000),
+        (1, 0b00000000000000000000000000110000000000000000000000110000000000000000000000110000000000000000000000110000000000000000000000000000),
+        (3, 0b00000000000000000000000011000000000000000000000011000000000000000000000011000000000000000000000011000000000000000000000000000000),
+        (17, 0b00000000000000000000001100000000000000000000001100000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000110000000000000000000000110000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000011000000000000000000000011000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (23, 0b00000000000000001100000000000000000000001100000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (37, 0b00000000000000110000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000011000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (41, 0b00000000001100000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (43, 0b00000000110000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (57, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (59, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (61, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (63, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (-42, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-30, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000001100000000000000000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000011000000000000000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000110000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000001100000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000011000000000000),
+        (-15, 0b00000000000000000000000000000000110000000000000000000000000000000000000000110000000000000000000000000000000000000000110000000000),
+        (-12, 0b00000000000000000000000000000000001100000000000000000000000000000000000000001100000000000000000000000000000000000000001100000000),
+        (-9, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000011000000000000000000000000000000000000000011000000),
+        (-6, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000110000000000000000000000000000000000000000110000),
+        (-3, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000001100000000000000000000000000000000000000001100),
+        (0, 0b11000000000000000000000000000000000000000011000000000000000000000000000000000000000011000000000000000000000000000000000000000011),
+        (3, 0b00110000000000000000000000000000000000000000110000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (6, 0b00001100000000000000000000000000000000000000001100000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (9, 0b00000011000000000000000000000000000000000000000011000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (12, 0b00000000110000000000000000000000000000000000000000110000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (15, 0b00000000001100000000000000000000000000000000000000001100000000000000000000000000000000000000001100000000000000000000000000000000),
+        (18, 0b00000000000011000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000110000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000000000001100000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00000000000000000011000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (30, 0b00000000000000000000110000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (42, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-57, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-54, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-42, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (-37, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (-34, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (-22, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (-17, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (-14, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-11, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-9, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-8, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-7, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-6, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-3, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-2, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (-1, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (2, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (4, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (6, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (7, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (8, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (9, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (11, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (13, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (14, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (17, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (18, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (22, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (34, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (37, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (42, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (54, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (57, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-43, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-38, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000011000000000000000000000000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (-26, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-23, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000110000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-18, 0b00000000000000000000000000000000000000110000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (-14, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-13, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-11, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (-9, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000001100000000000000),
+        (-8, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-7, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-6, 0b00000000000000000000000000000000000000000000001100000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (-3, 0b00000011000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-2, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-1, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (2, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000011000000),
+        (4, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (6, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000110000000000000000000000000000000000000000000000),
+        (7, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (8, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (9, 0b00000000000000110000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (11, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (12, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (13, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (14, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (18, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000001100000000000000000000000000000000000000),
+        (19, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000000001100000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (23, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (26, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000000000000011000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (38, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (43, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-60, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-55, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-50, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000011000000),
+        (-40, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000001100000000),
+        (-35, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000110000000000),
+        (-30, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000001100000000000000000000000011000000000000),
+        (-25, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000110000000000000000000000001100000000000000),
+        (-20, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000011000000000000000000000000110000000000000000),
+        (-15, 0b00000000000000000000000000000011000000000000000000000000110000000000000000000000001100000000000000000000000011000000000000000000),
+        (-10, 0b00000000000000000000000000001100000000000000000000000011000000000000000000000000110000000000000000000000001100000000000000000000),
+        (-5, 0b00000000000000000000000000110000000000000000000000001100000000000000000000000011000000000000000000000000110000000000000000000000),
+        (0, 0b00000000000000000000000011000000000000000000000000110000000000000000000000001100000000000000000000000011000000000000000000000000),
+        (5, 0b00000000000000000000001100000000000000000000000011000000000000000000000000110000000000000000000000001100000000000000000000000000),
+        (10, 0b00000000000000000000110000000000000000000000001100000000000000000000000011000000000000000000000000110000000000000000000000000000),
+        (15, 0b00000000000000000011000000000000000000000000110000000000000000000000001100000000000000000000000011000000000000000000000000000000),
+        (20, 0b00000000000000001100000000000000000000000011000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (25, 0b00000000000000110000000000000000000000001100000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (30, 0b00000000000011000000000000000000000000110000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (35, 0b00000000001100000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (40, 0b00000000110000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000011000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (50, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (55, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (60, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-42, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (-30, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-25, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-22, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (-15, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-14, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-11, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-10, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (-9, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-8, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-7, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (-6, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-5, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (-3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-2, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-1, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (2, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (3, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (4, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (5, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (6, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (7, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (8, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (9, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (10, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (11, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (12, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (13, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (14, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (15, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (18, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (22, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (25, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (30, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (42, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-60, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-57, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-54, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-43, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (-40, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000001100000000000000),
+        (-37, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000011000000000000),
+        (-34, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000110000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-26, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (-23, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000110000000000000000000000000000000000000000000000),
+        (-20, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000001100000000000000000000110000000000000000000000),
+        (-17, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000011000000000000000000001100000000000000000000),
+        (-14, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000011000000000000000000),
+        (-11, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-9, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-6, 0b00000000000000000000000000110000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-3, 0b00000000000000000000000000001100000000000000000000110000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (0, 0b00000000000000000000000000000011000000000000000000001100000000000000000000110000000000000000000011000000000000000000000000000000),
+        (3, 0b00000000000000000000000000000000000000000000000000000011000000000000000000001100000000000000000000110000000000000000000000000000),
+        (6, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000001100000000000000000000000000),
+        (9, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (11, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (14, 0b00000000000000000011000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (17, 0b00000000000000000000110000000000000000000011000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (20, 0b00000000000000000000001100000000000000000000110000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (23, 0b00000000000000000000000000000000000000000000001100000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (26, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (34, 0b00000000001100000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (37, 0b00000000000011000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (40, 0b00000000000000110000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (43, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (54, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (57, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (60, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-46, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-41, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (-34, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000011000000000000000000),
+        (-29, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000001100000000000000000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000110000000000000000000000),
+        (-22, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000110000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (-17, 0b00000000000000000000000000000000000000110000000000000000000000000000000000001100000000000000000000000000000000000011000000000000),
+        (-15, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-12, 0b00000000000000000000000000000000000011000000000000000000000000000000000000110000000000000000000000000000000000001100000000000000),
+        (-10, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000001100),
+        (-7, 0b00000000000000000000000000000000001100000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (-5, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000011000000000000000000000000000000000000110000),
+        (-2, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (0, 0b00000011000000000000000000000000000000000000110000000000000000000000000000000000001100000000000000000000000000000000000011000000),
+        (2, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (5, 0b00001100000000000000000000000000000000000011000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (7, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000110000000000000000000000000000000000),
+        (10, 0b00110000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (12, 0b00000000000000110000000000000000000000000000000000001100000000000000000000000000000000000011000000000000000000000000000000000000),
+        (15, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (17, 0b00000000000011000000000000000000000000000000000000110000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (22, 0b00000000001100000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000000000000000001100000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (29, 0b00000000000000000000110000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (34, 0b00000000000000000011000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (41, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (46, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-63, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-58, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-53, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-46, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (-43, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-41, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (-38, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (-29, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-26, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (-23, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (-14, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-12, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-11, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (-9, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-8, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-7, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-6, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-2, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-1, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (2, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (3, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (4, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (6, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (7, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (8, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (9, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (11, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (13, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (14, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (18, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (23, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (26, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (29, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (38, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (41, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (43, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (46, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (53, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (58, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (63, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-42, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (-30, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000110000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000011000000000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000001100000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000110000000000000000000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (-15, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000001100000000000000000000000000000000000000001100),
+        (-12, 0b00000000000000000000000000000000110000000000000000000000000000000000000000110000000000000000000000000000000000000000110000000000),
+        (-9, 0b00000000000000000000000011000000000000000000000000000000000000000011000000000000000000000000000000000000000011000000000000000000),
+        (-6, 0b00000000000000001100000000000000000000000000000000000000001100000000000000000000000000000000000000001100000000000000000000000000),
+        (-3, 0b00000000110000000000000000000000000000000000000000110000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (0, 0b11000000000000000000000000000000000000000011000000000000000000000000000000000000000011000000000000000000000000000000000000000011),
+        (3, 0b00000000000000000000000000000000001100000000000000000000000000000000000000001100000000000000000000000000000000000000001100000000),
+        (6, 0b00000000000000000000000000110000000000000000000000000000000000000000110000000000000000000000000000000000000000110000000000000000),
+        (9, 0b00000000000000000011000000000000000000000000000000000000000011000000000000000000000000000000000000000011000000000000000000000000),
+        (12, 0b00000000001100000000000000000000000000000000000000001100000000000000000000000000000000000000001100000000000000000000000000000000),
+        (15, 0b00110000000000000000000000000000000000000000110000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (18, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000000000000001100000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000000000000000110000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00000000000011000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (30, 0b00001100000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (42, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-42, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-30, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (-25, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (-22, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (-15, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-14, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-11, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-10, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (-9, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-8, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-7, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (-6, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-5, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-3, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-2, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (-1, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (2, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (4, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (5, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (6, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (7, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (8, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (9, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (10, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (11, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (13, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (14, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (15, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (18, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (22, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (25, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (30, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (42, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-57, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-54, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (-42, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-37, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-34, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-22, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-17, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (-14, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-11, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-9, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-8, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-7, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (-6, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (-3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-2, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (-1, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (2, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (3, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (4, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (6, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (7, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (8, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (9, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (11, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (12, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (13, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (14, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (17, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (18, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (22, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (34, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (37, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (42, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (54, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (57, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-60, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-57, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-54, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-43, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-40, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000110000000000),
+        (-37, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000011000000000000000000),
+        (-34, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000001100000000000000000000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (-26, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-23, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000011000000000000),
+        (-20, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000011000000000000000000001100000000000000000000),
+        (-17, 0b00000000000000000000000000000000000000000000000000000011000000000000000000001100000000000000000000110000000000000000000000000000),
+        (-14, 0b00000000000000000000000000000000000000000000001100000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (-11, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-9, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-6, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000001100000000000000),
+        (-3, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000001100000000000000000000110000000000000000000000),
+        (0, 0b00000000000000000000000000000011000000000000000000001100000000000000000000110000000000000000000011000000000000000000000000000000),
+        (3, 0b00000000000000000000001100000000000000000000110000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (6, 0b00000000000000110000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (9, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (11, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (14, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000110000000000000000000000000000000000000000000000),
+        (17, 0b00000000000000000000000000001100000000000000000000110000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (20, 0b00000000000000000000110000000000000000000011000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (23, 0b00000000000011000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (26, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (34, 0b00000000000000000000000000110000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (37, 0b00000000000000000011000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (40, 0b00000000001100000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (43, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (54, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (57, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (60, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-43, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-38, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000110000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-26, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (-23, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000011000000000000000000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000001100),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (-14, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-12, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-11, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (-9, 0b00000000000000000000000000000000110000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (-8, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-7, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-6, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000110000000000000000000000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000011),
+        (-2, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-1, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (2, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (3, 0b11000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (4, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (6, 0b00000000000000000000000000000000001100000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (7, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (8, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (9, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000001100000000000000000000000000000000),
+        (11, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (13, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (14, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (18, 0b00110000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000000000000000000000011000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (23, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (26, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00001100000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (38, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (43, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-46, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-41, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-34, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000001100),
+        (-29, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000110000000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000011000000000000000000),
+        (-22, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000110000000000000000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-17, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000011000000000000000000000000000000000000110000),
+        (-15, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000000000000000000110000000000000000000000000000000000001100000000000000000000000000000000000011000000000000),
+        (-10, 0b00000000000000000000001100000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (-7, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000001100000000000000000000),
+        (-5, 0b00000000000000110000000000000000000000000000000000001100000000000000000000000000000000000011000000000000000000000000000000000000),
+        (-2, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (0, 0b00000011000000000000000000000000000000000000110000000000000000000000000000000000001100000000000000000000000000000000000011000000),
+        (2, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (5, 0b00000000000000000000000000000000000011000000000000000000000000000000000000110000000000000000000000000000000000001100000000000000),
+        (7, 0b00000000000000000000110000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (10, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000110000000000000000000000),
+        (12, 0b00000000000011000000000000000000000000000000000000110000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (15, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (17, 0b00001100000000000000000000000000000000000011000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (22, 0b00000000000000000000000000000000001100000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000000000000011000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (29, 0b00000000001100000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (34, 0b00110000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (41, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (46, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-60, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-55, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-50, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000110000),
+        (-40, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000011000000000000),
+        (-35, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000001100000000000000000000),
+        (-30, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000110000000000000000000000001100),
+        (-25, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000011000000000000000000000000110000000000),
+        (-20, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000001100000000000000000000000011000000000000000000),
+        (-15, 0b00000000000000000000000000000000000000000000000011000000000000000000000000110000000000000000000000001100000000000000000000000011),
+        (-10, 0b00000000000000000000000000000000000000001100000000000000000000000011000000000000000000000000110000000000000000000000001100000000),
+        (-5, 0b00000000000000000000000000000000110000000000000000000000001100000000000000000000000011000000000000000000000000110000000000000000),
+        (0, 0b00000000000000000000000011000000000000000000000000110000000000000000000000001100000000000000000000000011000000000000000000000000),
+        (5, 0b00000000000000001100000000000000000000000011000000000000000000000000110000000000000000000000001100000000000000000000000000000000),
+        (10, 0b00000000110000000000000000000000001100000000000000000000000011000000000000000000000000110000000000000000000000000000000000000000),
+        (15, 0b11000000000000000000000000110000000000000000000000001100000000000000000000000011000000000000000000000000000000000000000000000000),
+        (20, 0b00000000000000000011000000000000000000000000110000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (25, 0b00000000001100000000000000000000000011000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (30, 0b00110000000000000000000000001100000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (35, 0b00000000000000000000110000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (40, 0b00000000000011000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00001100000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (50, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (55, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (60, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-63, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-58, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-53, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-46, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-43, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (-41, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-38, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-29, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (-26, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (-24, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-23, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (-14, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-11, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (-9, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (-8, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-7, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-6, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (-3, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-2, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-1, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (2, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (4, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (6, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (7, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (8, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (9, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (11, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (12, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (13, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (14, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (18, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (23, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (24, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (26, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (29, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (38, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (41, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (43, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (46, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (53, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (58, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (63, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000110000001100000011000000),
+        (-30, 0b00000000000000000000000000000000000000000000000000000000000000001100000011000000110000001100000000110000001100000011000000110000),
+        (-15, 0b00000000000000000000000000000000110000001100000011000000110000000011000000110000001100000011000000001100000011000000110000001100),
+        (0, 0b11000000110000001100000011000000001100000011000000110000001100000000110000001100000011000000110000000011000000110000001100000011),
+        (15, 0b00110000001100000011000000110000000011000000110000001100000011000000001100000011000000110000001100000000000000000000000000000000),
+        (30, 0b00001100000011000000110000001100000000110000001100000011000000110000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000011000000110000001100000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000110000001100000011),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000011000000110000001100000000000000000000000000000000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000011000000110000001100),
+        (-18, 0b00000000000000000000000000000000000000110000001100000011000000110000000000000000000000000000000000000000000000000000000000000000),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000000000110000001100000011000000110000000000000000000000000000000000),
+        (-14, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000001100000011000000110000),
+        (-3, 0b00000011000000110000001100000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-1, 0b00000000000000000000000000000000000011000000110000001100000011000000000000000000000000000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000000000000000000000000000011000000110000001100000011000000000000000000000000000000000000),
+        (3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000110000001100000011000000),
+        (14, 0b00001100000011000000110000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000001100000011000000110000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (18, 0b00000000000000000000000000000000000000000000000000000000000000001100000011000000110000001100000000000000000000000000000000000000),
+        (31, 0b00110000001100000011000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000000000000000110000001100000011000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b11000000110000001100000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-57, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-49, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-42, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000110000),
+        (-41, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-34, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000011000000000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000011000000000000000000000000000000001100),
+        (-26, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000001100000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000001100000000000000000000000000000000110000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000110000000000000000000000000000),
+        (-12, 0b00000000000000000000000011000000000000000000000000000000001100000000000000000000000000000000110000000000000000000000000000000011),
+        (-11, 0b00000000000000000000000000000000000000001100000000000000000000000000000000110000000000000000000000000000000011000000000000000000),
+        (-4, 0b00000000000000001100000000000000000000000000000000110000000000000000000000000000000011000000000000000000000000000000001100000000),
+        (-3, 0b00000000000000000000000000000000110000000000000000000000000000000011000000000000000000000000000000001100000000000000000000000000),
+        (3, 0b00000000000000000000000000110000000000000000000000000000000011000000000000000000000000000000001100000000000000000000000000000000),
+        (4, 0b00000000110000000000000000000000000000000011000000000000000000000000000000001100000000000000000000000000000000110000000000000000),
+        (11, 0b00000000000000000011000000000000000000000000000000001100000000000000000000000000000000110000000000000000000000000000000000000000),
+        (12, 0b11000000000000000000000000000000001100000000000000000000000000000000110000000000000000000000000000000011000000000000000000000000),
+        (18, 0b00000000000000000000000000001100000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000001100000000000000000000000000000000110000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (26, 0b00000000000000000000110000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00110000000000000000000000000000000011000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (33, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (34, 0b00000000000011000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (41, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (42, 0b00001100000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (49, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (57, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-60, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-52, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (-44, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-43, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-37, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-35, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-30, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (-29, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-26, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-22, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (-20, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-15, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-14, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (-11, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (-10, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-9, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-7, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-6, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-5, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (-3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (-2, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-1, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (1, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (2, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (3, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (4, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (5, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (6, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (7, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (9, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (10, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (11, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (12, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (13, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (14, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (15, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (18, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (20, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (22, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (26, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (27, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (29, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (30, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (35, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (37, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (43, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (44, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (52, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (60, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-48, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000110000001100000011000000),
+        (-33, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000001100000011000000110000),
+        (-31, 0b00000000000000000000000000000000000000000000000000000000000000001100000011000000110000001100000000000000000000000000000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000011000000110000001100),
+        (-16, 0b00000000000000000000000000000000000000000000000000000000000000000011000000110000001100000011000000000000000000000000000000000000),
+        (-14, 0b00000000000000000000000000000000110000001100000011000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (-3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000110000001100000011),
+        (-1, 0b00000000000000000000000000000000000000000000000000000000000000000000110000001100000011000000110000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000001100000011000000110000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (3, 0b11000000110000001100000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (14, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000011000000110000001100000000000000000000000000000000),
+        (16, 0b00000000000000000000000000000000000011000000110000001100000011000000000000000000000000000000000000000000000000000000000000000000),
+        (18, 0b00110000001100000011000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (31, 0b00000000000000000000000000000000000000110000001100000011000000110000000000000000000000000000000000000000000000000000000000000000),
+        (33, 0b00001100000011000000110000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (48, 0b00000011000000110000001100000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-51, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000110000001100000011),
+        (-34, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000011000000110000001100001100000011000000110000001100),
+        (-17, 0b00000000000000000000000000000000000000110000001100000011000000110000110000001100000011000000110000110000001100000011000000110000),
+        (0, 0b00000011000000110000001100000011000011000000110000001100000011000011000000110000001100000011000011000000110000001100000011000000),
+        (17, 0b00001100000011000000110000001100001100000011000000110000001100001100000011000000110000001100000000000000000000000000000000000000),
+        (34, 0b00110000001100000011000000110000110000001100000011000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (51, 0b11000000110000001100000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-60, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000),
+        (-52, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000),
+        (-45, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000),
+        (-44, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000),
+        (-43, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000),
+        (-37, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000),
+        (-36, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000),
+        (-35, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (-30, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100),
+        (-29, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000),
+        (-28, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000),
+        (-27, 0b00000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (-26, 0b00000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (-22, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000),
+        (-20, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000),
+        (-19, 0b00000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (-18, 0b00000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-15, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-14, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000),
+        (-12, 0b00000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000),
+        (-11, 0b00000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000),
+        (-10, 0b00000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-9, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-7, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-6, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000),
+        (-5, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000),
+        (-4, 0b00000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000),
+        (-3, 0b00000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-2, 0b00000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (-1, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (1, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (2, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000),
+        (3, 0b00000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000),
+        (4, 0b00000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000),
+        (5, 0b00000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (6, 0b00000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (7, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (9, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (10, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000),
+        (11, 0b00000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000),
+        (12, 0b00000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000),
+        (13, 0b00000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (14, 0b00000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (15, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (18, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000),
+        (19, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000),
+        (20, 0b00000000000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (21, 0b00000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (22, 0b00000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (26, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000),
+        (27, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000),
+        (28, 0b00000000000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (29, 0b00000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (30, 0b00110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (35, 0b00000000000000000000000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (36, 0b00000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (37, 0b00000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (43, 0b00000000000000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (44, 0b00000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (45, 0b00001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (52, 0b00000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (60, 0b00000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+    &[
+        (-63, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011),
+        (-55, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000),
+        (-47, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000),
+        (-46, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000001100),
+        (-39, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000),
+        (-38, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000110000000000),
+        (-30, 0b00000000000000000000000000000000000000000000000000000000000000000000000000000011000000000000000000000000000011000000000000000000),
+        (-29, 0b00000000000000000000000000000000000000000000000000000000000000110000000000000000000000000000110000000000000000000000000000110000),
+        (-22, 0b00000000000000000000000000000000000000000000000000000000000000000000001100000000000000000000000000001100000000000000000000000000),
+        (-21, 0b00000000000000000000000000000000000000000000000000000011000000000000000000000000000011000000000000000000000000000011000000000000),
+        (-13, 0b00000000000000000000000000000000000000000000001100000000000000000000000000001100000000000000000000000000001100000000000000000000),
+        (-12, 0b00000000000000000000000000000011000000000000000000000000000011000000000000000000000000000011000000000000000000000000000011000000),
+        (-5, 0b00000000000000000000000000000000000000110000000000000000000000000000110000000000000000000000000000110000000000000000000000000000),
+        (-4, 0b00000000000000000000001100000000000000000000000000001100000000000000000000000000001100000000000000000000000000001100000000000000),
+        (4, 0b00000000000000110000000000000000000000000000110000000000000000000000000000110000000000000000000000000000110000000000000000000000),
+        (5, 0b00000000000000000000000000001100000000000000000000000000001100000000000000000000000000001100000000000000000000000000000000000000),
+        (12, 0b00000011000000000000000000000000000011000000000000000000000000000011000000000000000000000000000011000000000000000000000000000000),
+        (13, 0b00000000000000000000110000000000000000000000000000110000000000000000000000000000110000000000000000000000000000000000000000000000),
+        (21, 0b00000000000011000000000000000000000000000011000000000000000000000000000011000000000000000000000000000000000000000000000000000000),
+        (22, 0b00000000000000000000000000110000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000),
+        (29, 0b00001100000000000000000000000000001100000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000),
+        (30, 0b00000000000000000011000000000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (38, 0b00000000001100000000000000000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (39, 0b00000000000000000000000011000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (46, 0b00110000000000000000000000000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (47, 0b00000000000000001100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (55, 0b00000000110000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+        (63, 0b11000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000),
+    ],
+];
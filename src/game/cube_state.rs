@@ -0,0 +1,423 @@
+use crate::game::board::Board;
+use crate::game::cube_consts::{
+    CUBE3_TRANSFORM_SHIFTS, CUBE3_WIN_MASKS, CUBE4_TRANSFORM_SHIFTS, CUBE4_WIN_MASKS,
+};
+use crate::game::errors::TicTacToeError;
+use crate::game::game_state::PlayerEnum;
+use crate::game::zobrist;
+use itertools::Itertools;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+/// How many symmetries a cube has: 6 ways to order the axes times 8 ways to mirror them.
+pub const CUBE_SYMMETRIES: usize = 48;
+
+/// An `N` x `N` x `N` cube won by `N` in a row along any row, column or diagonal, including
+/// the ones running through every layer. Cells are numbered layer by layer, then row by
+/// row, and packed two bits per cell into a `u128` in the same encoding as `GameState`.
+/// Only the 3x3x3 cube and the 4x4x4 cube (Qubic) have generated tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CubeState<const N: usize> {
+    raw: u128,
+    // The Zobrist key of the pieces, kept up to date by `set`
+    key: u64,
+}
+
+impl<const N: usize> CubeState<N> {
+    pub const SIZE: usize = N * N * N;
+    const VALID_SIZE: () = assert!(N == 3 || N == 4, "only 3x3x3 and 4x4x4 cubes are supported");
+    const X_BIT_MASK: u128 = (u128::MAX / 3) >> (128 - N * N * N * 2);
+
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID_SIZE;
+        CubeState { raw: 0, key: 0 }
+    }
+
+    fn from_raw(raw: u128) -> Self {
+        let mut board = CubeState { raw, key: 0 };
+        board.key = zobrist::board_key((0..Self::SIZE).map(|pos| board.cell(pos)));
+        board
+    }
+
+    // The two bit value at a position known to be in bounds
+    fn cell(&self, pos: usize) -> u32 {
+        (self.raw >> (pos * 2) & 3) as u32
+    }
+
+    pub fn win_masks() -> &'static [u128] {
+        match N {
+            3 => &CUBE3_WIN_MASKS,
+            _ => &CUBE4_WIN_MASKS,
+        }
+    }
+
+    fn transform_shifts() -> &'static [&'static [(i32, u128)]; CUBE_SYMMETRIES] {
+        match N {
+            3 => &CUBE3_TRANSFORM_SHIFTS,
+            _ => &CUBE4_TRANSFORM_SHIFTS,
+        }
+    }
+
+    pub fn set(&mut self, pos: usize, value: PlayerEnum) -> Result<(), TicTacToeError> {
+        if pos >= Self::SIZE {
+            return Err(TicTacToeError::OutOfBounds);
+        }
+        let old = self.cell(pos);
+        self.raw &= !(3 << (pos * 2));
+        self.raw |= (value as u128) << (pos * 2);
+        self.key ^= zobrist::piece_key(pos, old) ^ zobrist::piece_key(pos, value as u32);
+        Ok(())
+    }
+
+    pub fn get(&self, pos: usize) -> Result<PlayerEnum, TicTacToeError> {
+        if pos >= Self::SIZE {
+            return Err(TicTacToeError::OutOfBounds);
+        }
+        PlayerEnum::try_from(self.cell(pos))
+    }
+
+    pub fn get_raw(&self) -> u128 {
+        self.raw
+    }
+
+    /// A hash of the pieces on the board, updated incrementally as pieces are set.
+    pub fn zobrist_key(&self) -> u64 {
+        self.key
+    }
+
+    // The pieces of `player` as a board of X bits
+    fn pieces(&self, player: PlayerEnum) -> u128 {
+        match player {
+            PlayerEnum::X => self.raw & Self::X_BIT_MASK,
+            PlayerEnum::O => self.raw >> 1 & Self::X_BIT_MASK,
+            PlayerEnum::None => 0,
+        }
+    }
+
+    pub fn is_winner(&self, player: PlayerEnum) -> bool {
+        let pieces = self.pieces(player);
+        Self::win_masks().iter().any(|&mask| mask & !pieces == 0)
+    }
+
+    /// The player who has won and every line of N they completed, in position order.
+    pub fn winner(&self) -> Option<(PlayerEnum, Vec<Vec<usize>>)> {
+        [PlayerEnum::X, PlayerEnum::O].iter().find_map(|&player| {
+            let pieces = self.pieces(player);
+            let lines = Self::win_masks()
+                .iter()
+                .filter(|&&mask| mask & !pieces == 0)
+                .map(|&mask| {
+                    (0..Self::SIZE)
+                        .filter(|&pos| mask >> (pos * 2) & 1 == 1)
+                        .collect_vec()
+                })
+                .sorted()
+                .collect_vec();
+            if lines.is_empty() {
+                None
+            } else {
+                Some((player, lines))
+            }
+        })
+    }
+
+    pub fn is_tie(&self) -> bool {
+        let filled = (self.raw | self.raw >> 1) & Self::X_BIT_MASK;
+        filled == Self::X_BIT_MASK
+            && !(self.is_winner(PlayerEnum::X) || self.is_winner(PlayerEnum::O))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        // No cell with both bits set or past the end, a legal piece count and at most one
+        // winner
+        let cells = Self::X_BIT_MASK | Self::X_BIT_MASK << 1;
+        if self.raw & (self.raw >> 1) & Self::X_BIT_MASK != 0 || self.raw & !cells != 0 {
+            return false;
+        }
+        let x_count = self.pieces(PlayerEnum::X).count_ones();
+        let o_count = self.pieces(PlayerEnum::O).count_ones();
+        (x_count == o_count || x_count == o_count + 1)
+            && !(self.is_winner(PlayerEnum::X) && self.is_winner(PlayerEnum::O))
+    }
+
+    /// Moves every cell by the symmetry at `index`. The first 8 turn and flip each layer
+    /// like `GameState::transform`, the rest also move cells between layers.
+    pub fn apply(&self, index: usize) -> Self {
+        let mut new_raw = 0;
+        for &(translation, bit_mask) in Self::transform_shifts()[index].iter() {
+            if translation < 0 {
+                new_raw |= (self.raw & bit_mask) << (translation.abs() * 2);
+            } else {
+                new_raw |= (self.raw & bit_mask) >> (translation * 2);
+            }
+        }
+        CubeState::from_raw(new_raw)
+    }
+
+    /// Rotates every layer clockwise after an optional top to bottom flip.
+    pub fn transform(&self, rotations: i32, flip: bool) -> Self {
+        self.apply(rotations.rem_euclid(4) as usize + if flip { 4 } else { 0 })
+    }
+
+    pub fn as_vec(&self) -> Vec<String> {
+        (0..Self::SIZE)
+            .map(|i| self.get(i).unwrap().to_string())
+            .collect()
+    }
+
+    // Pieces on a winning line are shown in green, like `GameState`
+    fn display_cells(&self, numbered: bool, cell_width: usize) -> Vec<String> {
+        let winning_cells = self
+            .winner()
+            .map(|(_, lines)| lines.concat())
+            .unwrap_or_default();
+        self.as_vec()
+            .into_iter()
+            .enumerate()
+            .map(|(i, s)| {
+                let padding = " ".repeat(cell_width - 1);
+                if s == " " && numbered {
+                    format!("{:>width$}", i + 1, width = cell_width)
+                } else if winning_cells.contains(&i) {
+                    padding + &format!("\x1b[92m{}\x1b[0m", s)
+                } else if numbered {
+                    padding + &format!("\x1b[93m{}\x1b[0m", s)
+                } else {
+                    padding + &s
+                }
+            })
+            .collect_vec()
+    }
+
+    // Each layer is drawn like a `GameState`, top layer first
+    fn layers(cells: Vec<String>, cell_width: usize) -> String {
+        let separator = vec!["-".repeat(cell_width + 2); N].join("+");
+        cells
+            .chunks(N * N)
+            .map(|layer| {
+                layer
+                    .chunks(N)
+                    .map(|row| row.iter().map(|c| format!(" {} ", c)).join("|"))
+                    .join(format!("\n{}\n", separator).as_str())
+            })
+            .join("\n\n")
+    }
+
+    pub fn get_display(&self) -> String {
+        Self::layers(self.display_cells(false, 1), 1)
+    }
+
+    pub fn get_numbered_display(&self) -> String {
+        let cell_width = Self::SIZE.to_string().len();
+        Self::layers(self.display_cells(true, cell_width), cell_width)
+    }
+}
+
+impl<const N: usize> Default for CubeState<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Board for CubeState<N> {
+    type Raw = u128;
+    const SIZE: usize = N * N * N;
+
+    fn new() -> Self {
+        CubeState::new()
+    }
+
+    fn set(&mut self, pos: usize, value: PlayerEnum) -> Result<(), TicTacToeError> {
+        CubeState::set(self, pos, value)
+    }
+
+    fn get(&self, pos: usize) -> Result<PlayerEnum, TicTacToeError> {
+        CubeState::get(self, pos)
+    }
+
+    fn get_raw(&self) -> u128 {
+        self.raw
+    }
+
+    fn zobrist_key(&self) -> u64 {
+        self.key
+    }
+
+    fn is_winner(&self, player: PlayerEnum) -> bool {
+        CubeState::is_winner(self, player)
+    }
+
+    fn is_tie(&self) -> bool {
+        CubeState::is_tie(self)
+    }
+
+    fn transform(&self, rotations: i32, flip: bool) -> Self {
+        CubeState::transform(self, rotations, flip)
+    }
+
+    fn get_display(&self) -> String {
+        CubeState::get_display(self)
+    }
+
+    fn get_numbered_display(&self) -> String {
+        CubeState::get_numbered_display(self)
+    }
+
+    fn symmetries(&self) -> Vec<Self> {
+        (0..CUBE_SYMMETRIES).map(|i| self.apply(i)).collect()
+    }
+
+    fn get_turn(&self) -> PlayerEnum {
+        if self.pieces(PlayerEnum::X).count_ones() == self.pieces(PlayerEnum::O).count_ones() {
+            PlayerEnum::X
+        } else {
+            PlayerEnum::O
+        }
+    }
+}
+
+impl<const N: usize> fmt::Display for CubeState<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_vec().join(""))
+    }
+}
+
+impl<const N: usize> FromStr for CubeState<N> {
+    type Err = TicTacToeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.chars().count() != Self::SIZE {
+            return Err(TicTacToeError::InvalidBoard);
+        }
+        let mut board = CubeState::new();
+        for (i, ch) in value.chars().enumerate() {
+            board.set(i, PlayerEnum::from_str(ch.to_string().as_str())?)?;
+        }
+        if !board.is_valid() {
+            return Err(TicTacToeError::InvalidBoard);
+        }
+        Ok(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::game::Game;
+    use crate::game::game_state::GameState;
+    use crate::game::mcts::MctsPlayer;
+    use crate::game::player::{AIPlayer, Player, RandomPlayer};
+    use std::collections::HashSet;
+
+    fn board<const N: usize>(x: &[usize], o: &[usize]) -> CubeState<N> {
+        let mut board = CubeState::new();
+        for &pos in x {
+            board.set(pos, PlayerEnum::X).unwrap();
+        }
+        for &pos in o {
+            board.set(pos, PlayerEnum::O).unwrap();
+        }
+        board
+    }
+
+    #[test]
+    fn test_win_mask_counts() {
+        assert_eq!(CubeState::<3>::win_masks().len(), 49);
+        assert_eq!(CubeState::<4>::win_masks().len(), 76);
+        assert_eq!(CubeState::<4>::X_BIT_MASK, u128::MAX / 3);
+    }
+
+    #[test]
+    fn test_is_winner() {
+        // Through the center from one corner to the opposite one
+        let cube = board::<3>(&[0, 13, 26], &[1, 2]);
+        assert!(cube.is_winner(PlayerEnum::X));
+        assert!(!cube.is_winner(PlayerEnum::O));
+        assert_eq!(cube.winner(), Some((PlayerEnum::X, vec![vec![0, 13, 26]])));
+        // Straight down through all four layers
+        let qubic = board::<4>(&[1, 2, 3], &[5, 21, 37, 53]);
+        assert!(qubic.is_winner(PlayerEnum::O));
+        assert!(!qubic.is_winner(PlayerEnum::X));
+        assert!(!board::<4>(&[0, 1, 2], &[]).is_winner(PlayerEnum::X));
+    }
+
+    // Every symmetry maps winning lines onto winning lines
+    fn assert_maps_lines<const N: usize>() {
+        let masks: HashSet<u128> = CubeState::<N>::win_masks().iter().copied().collect();
+        for index in 0..CUBE_SYMMETRIES {
+            for &mask in masks.iter() {
+                let moved = CubeState::<N>::from_raw(mask).apply(index).raw;
+                assert!(masks.contains(&moved), "{} {}", N, index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_symmetries() {
+        let cube = board::<3>(&[0, 5, 19], &[1, 14]);
+        let symmetries = cube.symmetries();
+        assert_eq!(symmetries[0], cube);
+        assert_eq!(symmetries.iter().unique().count(), CUBE_SYMMETRIES);
+        assert_maps_lines::<3>();
+        assert_maps_lines::<4>();
+        // The first 8 move each layer like `GameState::transform`
+        for (rotations, flip) in (0..4).cartesian_product([false, true].iter().copied()) {
+            let transformed = cube.transform(rotations, flip);
+            for layer in 0..3 {
+                let mut expected = GameState::new();
+                for cell in 0..9 {
+                    expected
+                        .set(cell, cube.get(layer * 9 + cell).unwrap())
+                        .unwrap();
+                }
+                let expected = expected.transform(rotations, flip);
+                for cell in 0..9 {
+                    assert_eq!(
+                        transformed.get(layer * 9 + cell).unwrap(),
+                        expected.get(cell).unwrap()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_zobrist_key() {
+        let qubic = board::<4>(&[0, 21, 63], &[42, 7]);
+        assert_eq!(qubic.zobrist_key(), CubeState::<4>::from_raw(qubic.raw).key);
+        for symmetry in qubic.symmetries() {
+            let rebuilt = CubeState::<4>::from_str(&symmetry.to_string()).unwrap();
+            assert_eq!(rebuilt.zobrist_key(), symmetry.zobrist_key());
+        }
+    }
+
+    #[test]
+    fn test_from_str_and_display() {
+        let cube = CubeState::<3>::from_str(&format!("{:<27}", "X   O")).unwrap();
+        assert_eq!(cube, board(&[0], &[4]));
+        assert_eq!(cube.to_string(), format!("{:<27}", "X   O"));
+        assert!(CubeState::<3>::from_str(&format!("{:<27}", "XX")).is_err());
+        assert!(CubeState::<3>::from_str("X").is_err());
+        let display = cube.get_display();
+        assert_eq!(display.split("\n\n").count(), 3);
+        assert!(display.starts_with(" X |   |   \n---+---+---\n   | O |   "));
+        assert!(CubeState::<4>::new().get_numbered_display().contains("64"));
+    }
+
+    #[test]
+    fn test_ai_plays_cubes() {
+        // X threatens the center column and O has to block it
+        let cube = board::<3>(&[4, 13], &[0]);
+        let mut ai: AIPlayer<CubeState<3>> = AIPlayer::new().max_depth(Some(2));
+        ai.assign_piece(PlayerEnum::O);
+        assert_eq!(ai.play_turn(cube), 22);
+        let mut game = Game::<CubeState<3>>::new(
+            Box::new(MctsPlayer::seeded(4).iterations(200)) as Box<dyn Player<CubeState<3>>>,
+            Box::new(RandomPlayer::seeded(4)) as Box<dyn Player<CubeState<3>>>,
+        );
+        // 3x3x3 can't end in a tie, and X is far ahead of a random player
+        assert_eq!(game.play(), Some(PlayerEnum::X));
+    }
+}
@@ -0,0 +1,331 @@
+// A line-based protocol for playing against programs in other languages, in the spirit
+// of UCI and GTP. The controller sends:
+//
+//   ttt                  handshake, answered by optional `id name <name>` lines and `tttok`
+//   newgame              forget the previous game
+//   position <board>     the current board, one character per square with - for empty
+//   go                   pick a move for the side to move on the last position
+//   quit                 exit
+//
+// and the engine answers `go` with any number of `info <text>` lines followed by
+// `bestmove <n>`, where n counts squares from 1 like the human prompt, or `bestmove none`
+// if there is nothing to play. Unknown commands are answered with `info error ...`.
+
+use crate::game::board::Board;
+use crate::game::errors::EngineError;
+use crate::game::game_state::GameState;
+use crate::game::player::{Player, PlayerAction};
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::str::FromStr;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub const ENGINE_NAME: &str = concat!("ttt ", env!("CARGO_PKG_VERSION"));
+
+/// How long an engine has to answer the handshake or a `go`.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub fn encode_board<B: Board>(board: B) -> String {
+    board.to_string().replace(' ', "-")
+}
+
+pub fn decode_board<B: Board>(value: &str) -> Option<B> {
+    B::from_str(&value.replace('-', " ")).ok()
+}
+
+/// Answers protocol commands from `input` with moves chosen by `player` until `quit` or
+/// the end of the input.
+pub fn serve<B: Board, R: BufRead, W: Write>(
+    player: &mut dyn Player<B>,
+    input: R,
+    mut output: W,
+) -> Result<(), EngineError> {
+    let mut board = B::new();
+    for line in input.lines() {
+        let line = line?;
+        let (command, arg) = match line.trim().split_once(' ') {
+            Some((command, arg)) => (command, arg.trim()),
+            None => (line.trim(), ""),
+        };
+        match command {
+            "" => continue,
+            "ttt" => {
+                writeln!(output, "id name {}", ENGINE_NAME)?;
+                writeln!(output, "tttok")?;
+            }
+            "newgame" => board = B::new(),
+            "position" => match decode_board(arg) {
+                Some(b) => board = b,
+                None => writeln!(output, "info error invalid position {}", arg)?,
+            },
+            "go" if board.is_over() => writeln!(output, "bestmove none")?,
+            "go" => {
+                player.assign_piece(board.get_turn());
+                writeln!(output, "bestmove {}", player.play_turn(board) + 1)?;
+            }
+            "quit" => break,
+            _ => writeln!(output, "info error unknown command {}", command)?,
+        }
+        output.flush()?;
+    }
+    Ok(())
+}
+
+/// A player whose moves come from a child process speaking the engine protocol. An
+/// engine that fails or doesn't answer in time resigns.
+pub struct ExternalPlayer<B: Board = GameState> {
+    name: String,
+    child: Child,
+    stdin: ChildStdin,
+    // Lines read from the engine's output by a separate thread, so reads can time out
+    lines: Receiver<std::io::Result<String>>,
+    timeout: Duration,
+    last_board: Option<B>,
+    last_info: Vec<String>,
+    last_error: Option<EngineError>,
+}
+
+impl<B: Board> ExternalPlayer<B> {
+    pub fn spawn(program: &str, args: &[&str]) -> Result<ExternalPlayer<B>, EngineError> {
+        Self::spawn_with_timeout(program, args, DEFAULT_TIMEOUT)
+    }
+
+    /// Like `spawn`, but giving the engine `timeout` to answer each request instead of
+    /// `DEFAULT_TIMEOUT`.
+    pub fn spawn_with_timeout(
+        program: &str,
+        args: &[&str],
+        timeout: Duration,
+    ) -> Result<ExternalPlayer<B>, EngineError> {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let stdin = child.stdin.take().ok_or(EngineError::Closed)?;
+        let stdout = BufReader::new(child.stdout.take().ok_or(EngineError::Closed)?);
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in stdout.lines() {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        let mut player = ExternalPlayer {
+            name: program.to_string(),
+            child,
+            stdin,
+            lines,
+            timeout,
+            last_board: None,
+            last_info: vec![],
+            last_error: None,
+        };
+        player.send("ttt")?;
+        loop {
+            let line = player.read_line()?;
+            if line == "tttok" {
+                break;
+            } else if let Some(name) = line.strip_prefix("id name ") {
+                player.name = name.to_string();
+            }
+        }
+        player.send("newgame")?;
+        Ok(player)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The `info` lines the engine sent with its last move.
+    pub fn last_info(&self) -> &[String] {
+        &self.last_info
+    }
+
+    /// Why the engine last resigned, if it has.
+    pub fn last_error(&self) -> Option<&EngineError> {
+        self.last_error.as_ref()
+    }
+
+    fn send(&mut self, command: &str) -> Result<(), EngineError> {
+        writeln!(self.stdin, "{}", command)?;
+        self.stdin.flush()?;
+        Ok(())
+    }
+
+    fn read_line(&mut self) -> Result<String, EngineError> {
+        match self.lines.recv_timeout(self.timeout) {
+            Ok(line) => Ok(line?.trim().to_string()),
+            Err(RecvTimeoutError::Timeout) => Err(EngineError::Timeout),
+            Err(RecvTimeoutError::Disconnected) => Err(EngineError::Closed),
+        }
+    }
+
+    pub fn best_move(&mut self, board: B) -> Result<usize, EngineError> {
+        // A board with fewer pieces than last time means a new game or an undo
+        let pieces = |b: B| B::SIZE - b.empty_positions().len();
+        if self
+            .last_board
+            .is_some_and(|last| pieces(board) < pieces(last))
+        {
+            self.send("newgame")?;
+        }
+        self.last_board = Some(board);
+        self.last_info.clear();
+        self.send(&format!("position {}", encode_board(board)))?;
+        self.send("go")?;
+        loop {
+            let line = self.read_line()?;
+            if let Some(info) = line.strip_prefix("info ") {
+                self.last_info.push(info.to_string());
+            } else if let Some(pos) = line.strip_prefix("bestmove ") {
+                return match usize::from_str(pos) {
+                    Ok(pos) if pos > 0 => Ok(pos - 1),
+                    _ => Err(EngineError::UnexpectedResponse(line)),
+                };
+            } else {
+                return Err(EngineError::UnexpectedResponse(line));
+            }
+        }
+    }
+}
+
+impl<B: Board> Player<B> for ExternalPlayer<B> {
+    // A failed engine gives an illegal position, so the game's retries run out
+    fn play_turn(&mut self, board: B) -> usize {
+        match self.play_action(board) {
+            PlayerAction::Move(pos) => pos,
+            _ => B::SIZE,
+        }
+    }
+
+    fn play_action(&mut self, board: B) -> PlayerAction {
+        match self.best_move(board) {
+            Ok(pos) => PlayerAction::Move(pos),
+            Err(e) => {
+                println!("Engine {} failed: {}", self.name, e);
+                self.last_error = Some(e);
+                PlayerAction::Resign
+            }
+        }
+    }
+}
+
+// How long an engine has to exit after `quit` before it is killed
+const QUIT_TIMEOUT: Duration = Duration::from_secs(1);
+
+impl<B: Board> Drop for ExternalPlayer<B> {
+    fn drop(&mut self) {
+        let _ = self.send("quit");
+        let deadline = Instant::now() + QUIT_TIMEOUT;
+        while Instant::now() < deadline {
+            match self.child.try_wait() {
+                Ok(Some(_)) => return,
+                Ok(None) => thread::sleep(Duration::from_millis(10)),
+                Err(_) => break,
+            }
+        }
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::game::Game;
+    use crate::game::game_state::PlayerEnum;
+    use crate::game::player::AIPlayer;
+
+    const STUB_ENGINE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/scripts/stub_engine.sh");
+
+    fn serve_lines(input: &str) -> Vec<String> {
+        let mut output = vec![];
+        let mut ai = AIPlayer::<GameState>::new();
+        serve(&mut ai, input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_serve() {
+        let lines =
+            serve_lines("ttt\nnewgame\nposition XX-OO----\ngo\nposition XX-OO-X--\ngo\nquit\ngo\n");
+        assert_eq!(
+            lines,
+            vec![
+                format!("id name {}", ENGINE_NAME),
+                "tttok".to_string(),
+                "bestmove 3".to_string(),
+                "bestmove 6".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_serve_errors() {
+        let lines = serve_lines("position XXX\nfoo\nposition XXXOO----\ngo\n");
+        assert_eq!(
+            lines,
+            vec![
+                "info error invalid position XXX",
+                "info error unknown command foo",
+                "bestmove none",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_external_player() {
+        let mut stub = ExternalPlayer::<GameState>::spawn("sh", &[STUB_ENGINE]).unwrap();
+        assert_eq!(stub.name(), "stub");
+        let board = GameState::from_str("XO  X    ").unwrap();
+        assert_eq!(stub.play_turn(board), 2);
+        assert_eq!(stub.last_info(), ["string first empty square"]);
+    }
+
+    const TIMEOUT: Duration = Duration::from_millis(200);
+
+    #[test]
+    fn test_external_player_resigns() {
+        // Square 0 doesn't exist, and the second engine never answers `go`
+        let scripts = [
+            "echo tttok; while read c a; do [ $c = go ] && echo bestmove 0; done",
+            "echo tttok; exec sleep 5",
+        ];
+        for script in scripts.iter() {
+            let engine =
+                ExternalPlayer::<GameState>::spawn_with_timeout("sh", &["-c", script], TIMEOUT);
+            let mut game = Game::new(Box::new(engine.unwrap()), Box::new(AIPlayer::new()));
+            assert_eq!(game.play(), Some(PlayerEnum::O));
+            assert_eq!(game.forfeited(), Some(PlayerEnum::X));
+        }
+        let hung = ExternalPlayer::<GameState>::spawn_with_timeout("sleep", &["5"], TIMEOUT);
+        assert!(matches!(hung, Err(EngineError::Timeout)));
+    }
+
+    #[test]
+    fn test_ignored_quit() {
+        // The engine only stops at the end of its input, which stays open while it is dropped
+        let script = "echo tttok; while read c; do :; done";
+        let engine = ExternalPlayer::<GameState>::spawn("sh", &["-c", script]).unwrap();
+        let start = Instant::now();
+        drop(engine);
+        assert!(start.elapsed() < QUIT_TIMEOUT * 5);
+    }
+
+    #[test]
+    fn test_external_player_game() {
+        // The stub fills squares in order, so the AI as O wins down the middle column
+        let stub = ExternalPlayer::<GameState>::spawn("sh", &[STUB_ENGINE]).unwrap();
+        let mut game = Game::new(Box::new(stub), Box::new(AIPlayer::new()));
+        assert_eq!(game.play(), Some(PlayerEnum::O));
+    }
+}
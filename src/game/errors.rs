@@ -1,4 +1,3 @@
-use std::any::TypeId;
 use std::fmt;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
@@ -8,7 +7,9 @@ pub enum TicTacToeError {
     InvalidBoard,
     InvalidPlayerEnum,
     IllegalMove,
-    OutOfBounds
+    OutOfBounds,
+    NothingToUndo,
+    Resigned
     
 }
 
@@ -19,7 +20,9 @@ impl Display for TicTacToeError {
             Self::InvalidPlayerEnum => 
                 write!(f, "invalid PlayerEnum - convert from &str (\" \",  \"X\", \"O\") or u32 (0, 1, 2)"),
             Self::InvalidBoard => write!(f, "invalid Board"),
-            Self::OutOfBounds => write!(f, "attempted to access Board position not in range (0-8) inclusive")
+            Self::OutOfBounds => write!(f, "attempted to access Board position not in range (0-8) inclusive"),
+            Self::NothingToUndo => write!(f, "no moves to undo"),
+            Self::Resigned => write!(f, "player resigned")
         }
     }
 }
@@ -30,3 +33,121 @@ impl Error for TicTacToeError {
     }
 }
 
+
+#[derive(Debug)]
+pub enum TablebaseError {
+    Io(std::io::Error),
+    InvalidMagic,
+    UnsupportedVersion(u16),
+    MappingMismatch,
+    ChecksumMismatch,
+    Corrupt,
+    UnsupportedVariant(String),
+}
+
+impl Display for TablebaseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "could not access tablebase file - {}", e),
+            Self::InvalidMagic => write!(f, "not a tablebase file"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported tablebase version {}", v),
+            Self::MappingMismatch => {
+                write!(f, "tablebase was built with a different POSITION_MAP")
+            }
+            Self::ChecksumMismatch => write!(f, "tablebase checksum does not match"),
+            Self::Corrupt => write!(f, "tablebase contains invalid entries"),
+            Self::UnsupportedVariant(name) => {
+                write!(f, "only standard tablebases can be saved, not {}", name)
+            }
+        }
+    }
+}
+
+impl Error for TablebaseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for TablebaseError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+#[derive(Debug)]
+pub enum RecordError {
+    Io(std::io::Error),
+    InvalidTag(String),
+    InvalidToken(String),
+    InvalidStart(TicTacToeError),
+    UnsupportedVariant(String),
+    IllegalMove { ply: usize, error: TicTacToeError },
+}
+
+impl Display for RecordError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "could not access game record - {}", e),
+            Self::InvalidTag(line) => write!(f, "invalid tag line: {}", line),
+            Self::InvalidToken(token) => write!(f, "invalid move text: {}", token),
+            Self::InvalidStart(e) => write!(f, "invalid starting position - {}", e),
+            Self::UnsupportedVariant(v) => write!(f, "unsupported variant {}", v),
+            Self::IllegalMove { ply, error } => write!(f, "illegal move at ply {} - {}", ply, error),
+        }
+    }
+}
+
+impl Error for RecordError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::InvalidStart(e) => Some(e),
+            Self::IllegalMove { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for RecordError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+#[derive(Debug)]
+pub enum EngineError {
+    Io(std::io::Error),
+    Closed,
+    Timeout,
+    UnexpectedResponse(String),
+}
+
+impl Display for EngineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "could not talk to engine - {}", e),
+            Self::Closed => write!(f, "engine closed its output"),
+            Self::Timeout => write!(f, "engine did not answer in time"),
+            Self::UnexpectedResponse(line) => write!(f, "unexpected engine response: {}", line),
+        }
+    }
+}
+
+impl Error for EngineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for EngineError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
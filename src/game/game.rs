@@ -1,64 +1,406 @@
+use crate::game::board::{Board, Move, Variant};
 use crate::game::errors::TicTacToeError;
 use crate::game::game_state::{GameState, PlayerEnum};
-use crate::game::player;
-use crate::game::player::Player;
-use std::borrow::Borrow;
-use std::ops::Deref;
-
-pub struct Game {
-    game_state: GameState,
-    player1: Box<dyn Player>,
-    player2: Box<dyn Player>,
+use crate::game::player::{Player, PlayerAction};
+
+/// One move of a game and the board it produced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoveRecord<B: Board = GameState> {
+    pub player: PlayerEnum,
+    pub position: usize,
+    /// The piece placed, which is `player` unless the variant lets the mover choose.
+    pub piece: PlayerEnum,
+    pub state: B,
+}
+
+pub struct Game<B: Board = GameState> {
+    game_state: B,
+    player1: Box<dyn Player<B>>,
+    player2: Box<dyn Player<B>>,
     turn: PlayerEnum,
+    variant: Variant,
+    // Moves after `ply` have been undone and can be redone
+    history: Vec<MoveRecord<B>>,
+    ply: usize,
+    // The player who ran out of retries, losing the game
+    forfeited: Option<PlayerEnum>,
 }
 
-impl Game {
-    pub fn new<P: 'static + Player>(player1: Box<P>, player2: Box<P>) -> Game {
+impl<B: Board> Game<B> {
+    pub fn new(player1: Box<dyn Player<B>>, player2: Box<dyn Player<B>>) -> Game<B> {
         Game {
-            game_state: GameState::new(),
+            game_state: B::new(),
             player1,
             player2,
             turn: PlayerEnum::X,
+            variant: Variant::Standard,
+            history: vec![],
+            ply: 0,
+            forfeited: None,
+        }
+    }
+
+    /// Plays by the rules of `variant` instead of the standard ones.
+    pub fn with_variant(mut self, variant: Variant) -> Game<B> {
+        self.variant = variant;
+        self
+    }
+
+    pub fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    pub fn game_state(&self) -> B {
+        self.game_state
+    }
+
+    pub fn turn(&self) -> PlayerEnum {
+        self.turn
+    }
+
+    /// The player who lost by resigning or failing to make a legal move, if the game ended
+    /// that way.
+    pub fn forfeited(&self) -> Option<PlayerEnum> {
+        self.forfeited
+    }
+
+    /// The winner of the game so far, either by the rules or by the opponent forfeiting.
+    pub fn winner(&self) -> Option<PlayerEnum> {
+        match self.forfeited {
+            Some(player) => Some(player.opponent()),
+            None => self.variant.winner(&self.game_state),
+        }
+    }
+
+    /// The moves that led to the current board, in order.
+    pub fn history(&self) -> &[MoveRecord<B>] {
+        &self.history[..self.ply]
+    }
+
+    /// Takes back the last move, keeping it available to `redo`.
+    pub fn undo(&mut self) -> Option<MoveRecord<B>> {
+        if self.ply == 0 {
+            return None;
+        }
+        let record = self.history[self.ply - 1];
+        self.go_to(self.ply - 1);
+        Some(record)
+    }
+
+    pub fn redo(&mut self) -> Option<MoveRecord<B>> {
+        let record = *self.history.get(self.ply)?;
+        self.go_to(self.ply + 1);
+        Some(record)
+    }
+
+    /// Moves to the board after `ply` moves of the recorded game, which can be ahead of
+    /// the current board if moves were undone.
+    pub fn replay_to(&mut self, ply: usize) -> Result<(), TicTacToeError> {
+        if ply > self.history.len() {
+            return Err(TicTacToeError::OutOfBounds);
+        }
+        self.go_to(ply);
+        Ok(())
+    }
+
+    fn go_to(&mut self, ply: usize) {
+        self.ply = ply;
+        match ply {
+            0 => {
+                self.game_state = B::new();
+                self.turn = PlayerEnum::X;
+            }
+            _ => {
+                let record = self.history[ply - 1];
+                self.game_state = record.state;
+                self.turn = record.player.opponent();
+            }
         }
     }
 
-    fn play_turn(&mut self, turn: PlayerEnum) {
-        let mut player = match turn {
+    fn player(&mut self, turn: PlayerEnum) -> &mut dyn Player<B> {
+        match turn {
             PlayerEnum::X => self.player1.as_mut(),
             PlayerEnum::O => self.player2.as_mut(),
             _ => panic!(),
-        };
+        }
+    }
+
+    fn make_move(&mut self, m: Move) -> Result<(), TicTacToeError> {
+        self.game_state.set(m.position, m.piece)?;
+        // A new move replaces whatever could have been redone
+        self.history.truncate(self.ply);
+        self.history.push(MoveRecord {
+            player: self.turn,
+            position: m.position,
+            piece: m.piece,
+            state: self.game_state,
+        });
+        self.ply += 1;
+        self.turn = self.turn.opponent();
+        Ok(())
+    }
+
+    // Players place the piece the variant gives them unless it lets them choose
+    fn check_move(&self, m: Move) -> Result<(), TicTacToeError> {
+        if m.position >= B::SIZE {
+            return Err(TicTacToeError::OutOfBounds);
+        }
+        let piece_allowed = m.piece == self.variant.piece(self.turn)
+            || (self.variant.chooses_piece() && m.piece != PlayerEnum::None);
+        if !piece_allowed || !self.game_state.empty_positions().contains(&m.position) {
+            return Err(TicTacToeError::IllegalMove);
+        }
+        Ok(())
+    }
+
+    // Gives up with the last error once the player has used all their retries or resigns
+    fn play_turn(&mut self, turn: PlayerEnum) -> Result<(), TicTacToeError> {
         let retries = 5;
+        let mut error = TicTacToeError::IllegalMove;
         for i in 0..retries {
-            player.assign_piece(self.turn);
-            let index = player.play_turn(self.game_state.clone());
-            let valid_moves = self.game_state.empty_positions();
-            if !valid_moves.contains(&index) {
-                player.on_error(TicTacToeError::IllegalMove, i)
-            } else {
-                match self.game_state.set(index, self.turn) {
-                    Ok(_) => return,
-                    Err(e) => player.on_error(e, i),
+            self.player(turn).assign_piece(turn);
+            let game_state = self.game_state;
+            let m = match self.player(turn).play_action(game_state) {
+                // Undo back to this player's previous turn
+                PlayerAction::Undo if self.ply >= 2 => {
+                    self.undo();
+                    self.undo();
+                    return Ok(());
+                }
+                PlayerAction::Undo => Err(TicTacToeError::NothingToUndo),
+                PlayerAction::Resign => return Err(TicTacToeError::Resigned),
+                PlayerAction::Move(index) => Ok(Move::new(index, self.variant.piece(turn))),
+                PlayerAction::Place(m) => Ok(m),
+            };
+            let result = m.and_then(|m| {
+                self.check_move(m)?;
+                self.make_move(m)
+            });
+            match result {
+                Ok(_) => return Ok(()),
+                Err(e) => {
+                    error = e.clone();
+                    self.player(turn).on_error(e, i);
                 }
             }
         }
+        Err(error)
     }
 
+    /// Plays until the game is over or a player forfeits, by resigning or by not making a
+    /// legal move in their retries, returning the winner.
     pub fn play(&mut self) -> Option<PlayerEnum> {
-        loop {
-            self.play_turn(self.turn);
-            if self.game_state.is_winner(PlayerEnum::X) {
-                return Some(PlayerEnum::X);
-            } else if self.game_state.is_winner(PlayerEnum::O) {
-                return Some(PlayerEnum::O);
-            } else if self.game_state.is_tie() {
-                return None;
-            }
-            self.turn = match self.turn {
-                PlayerEnum::None => panic!(),
-                PlayerEnum::X => PlayerEnum::O,
-                PlayerEnum::O => PlayerEnum::X,
+        while !self.game_state.is_over() {
+            let turn = self.turn;
+            if self.play_turn(turn).is_err() {
+                self.forfeited = Some(turn);
+                break;
             }
         }
+        let game_state = self.game_state;
+        self.player1.on_game_end(game_state);
+        self.player2.on_game_end(game_state);
+        self.winner()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::mnk_state::MnkState;
+    use std::collections::VecDeque;
+    use std::str::FromStr;
+
+    struct ScriptedPlayer {
+        actions: VecDeque<PlayerAction>,
+    }
+
+    impl ScriptedPlayer {
+        fn new(actions: Vec<PlayerAction>) -> Box<ScriptedPlayer> {
+            Box::new(ScriptedPlayer {
+                actions: actions.into(),
+            })
+        }
+    }
+
+    impl Player for ScriptedPlayer {
+        fn play_action(&mut self, _board: GameState) -> PlayerAction {
+            self.actions.pop_front().unwrap()
+        }
+    }
+
+    fn moves(positions: &[usize]) -> Vec<PlayerAction> {
+        positions.iter().map(|&p| PlayerAction::Move(p)).collect()
+    }
+
+    #[test]
+    fn test_misere() {
+        // X completes the top row and loses
+        let x = ScriptedPlayer::new(moves(&[0, 1, 2]));
+        let o = ScriptedPlayer::new(moves(&[3, 4]));
+        let mut game = Game::new(x, o).with_variant(Variant::Misere);
+        assert_eq!(game.variant(), Variant::Misere);
+        assert_eq!(game.play(), Some(PlayerEnum::O));
+        let board = GameState::from_str("XXXOO    ").unwrap();
+        assert_eq!(Variant::Standard.winner(&board), Some(PlayerEnum::X));
+        assert_eq!(Variant::Misere.winner(&board), Some(PlayerEnum::O));
+        assert_eq!(Variant::Misere.winner(&GameState::new()), None);
+        assert_eq!(Variant::from_name("misère"), Some(Variant::Misere));
+    }
+
+    #[test]
+    fn test_forfeit_after_retries() {
+        // O only ever plays on X's square, so forfeits once the retries run out
+        let x = ScriptedPlayer::new(moves(&[4]));
+        let o = ScriptedPlayer::new(moves(&[4; 5]));
+        let mut game = Game::new(x, o);
+        assert_eq!(game.play(), Some(PlayerEnum::X));
+        assert_eq!(game.forfeited(), Some(PlayerEnum::O));
+        assert_eq!(game.history().len(), 1);
+        assert!(!game.game_state().is_over());
+    }
+
+    #[test]
+    fn test_resign() {
+        let x = ScriptedPlayer::new(moves(&[4]));
+        let o = ScriptedPlayer::new(vec![PlayerAction::Resign]);
+        let mut game = Game::new(x, o);
+        assert_eq!(game.play(), Some(PlayerEnum::X));
+        assert_eq!(game.forfeited(), Some(PlayerEnum::O));
+    }
+
+    fn place(position: usize, piece: PlayerEnum) -> PlayerAction {
+        PlayerAction::Place(Move::new(position, piece))
+    }
+
+    #[test]
+    fn test_wild() {
+        // X places the O that completes a line of Os, and wins
+        let x = ScriptedPlayer::new(vec![
+            place(0, PlayerEnum::O),
+            place(4, PlayerEnum::O),
+            place(8, PlayerEnum::O),
+        ]);
+        let o = ScriptedPlayer::new(vec![place(1, PlayerEnum::X), place(2, PlayerEnum::X)]);
+        let mut game = Game::new(x, o).with_variant(Variant::Wild);
+        assert_eq!(game.play(), Some(PlayerEnum::X));
+        let history = game.history();
+        assert_eq!(history[0].player, PlayerEnum::X);
+        assert_eq!(history[0].piece, PlayerEnum::O);
+        assert_eq!(game.game_state().to_string(), "OXX O   O");
+        assert!(game.game_state().is_valid_in(Variant::Wild));
+        assert_eq!(Variant::Wild.turn(&game.game_state()), PlayerEnum::O);
+        assert_eq!(Variant::Wild.moves(&GameState::new()).len(), 18);
+    }
+
+    #[test]
+    fn test_piece_must_be_own_unless_chosen() {
+        // The O is rejected and X retries with a normal move
+        let x = ScriptedPlayer::new(vec![
+            place(0, PlayerEnum::O),
+            place(0, PlayerEnum::X),
+            PlayerAction::Move(1),
+            PlayerAction::Move(2),
+        ]);
+        let o = ScriptedPlayer::new(moves(&[3, 4]));
+        let mut game = Game::new(x, o);
+        assert_eq!(game.play(), Some(PlayerEnum::X));
+        assert_eq!(game.history()[0].piece, PlayerEnum::X);
+        assert_eq!(game.game_state().to_string(), "XXXOO    ");
+    }
+
+    #[test]
+    fn test_order_and_chaos() {
+        type Board6 = MnkState<6, 6, 5>;
+        let variant = Variant::OrderAndChaos;
+        let mut board = Board6::new();
+        for pos in 0..5 {
+            board.set(pos * 7, PlayerEnum::O).unwrap();
+        }
+        // Five Os on the diagonal still win for Order
+        assert!(board.is_valid_in(variant));
+        assert!(!board.is_valid());
+        assert_eq!(variant.winner(&board), Some(PlayerEnum::X));
+        // A full board without five in a row wins for Chaos
+        let mut full = Board6::new();
+        for pos in 0..36 {
+            let (row, col) = (pos / 6, pos % 6);
+            let piece = if (col + 2 * row) % 4 < 2 {
+                PlayerEnum::X
+            } else {
+                PlayerEnum::O
+            };
+            full.set(pos, piece).unwrap();
+        }
+        assert!(full.is_over());
+        assert_eq!(variant.winner(&full), Some(PlayerEnum::O));
+        assert_eq!(variant.winner(&Board6::new()), None);
+    }
+
+    #[test]
+    fn test_history() {
+        let mut game = Game::new(
+            ScriptedPlayer::new(moves(&[0, 1, 2])),
+            ScriptedPlayer::new(moves(&[3, 4])),
+        );
+        assert_eq!(game.play(), Some(PlayerEnum::X));
+        let history = game.history();
+        assert_eq!(history.len(), 5);
+        assert_eq!(
+            history.iter().map(|r| r.position).collect::<Vec<_>>(),
+            vec![0, 3, 1, 4, 2]
+        );
+        assert_eq!(history[1].player, PlayerEnum::O);
+        assert_eq!(history[1].state.to_string(), "X  O     ");
+        assert_eq!(history[4].state, game.game_state());
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut game = Game::new(
+            ScriptedPlayer::new(moves(&[0, 1, 2])),
+            ScriptedPlayer::new(moves(&[3, 4])),
+        );
+        game.play();
+        assert_eq!(game.undo().map(|r| r.position), Some(2));
+        assert_eq!(game.turn(), PlayerEnum::X);
+        assert_eq!(game.game_state().to_string(), "XX OO    ");
+        assert_eq!(game.undo().map(|r| r.position), Some(4));
+        assert_eq!(game.turn(), PlayerEnum::O);
+        assert_eq!(game.history().len(), 3);
+        assert_eq!(game.redo().map(|r| r.position), Some(4));
+        assert_eq!(game.game_state().to_string(), "XX OO    ");
+
+        game.replay_to(0).unwrap();
+        assert_eq!(game.game_state(), GameState::new());
+        assert_eq!(game.undo(), None);
+        game.replay_to(5).unwrap();
+        assert!(game.game_state().is_winner(PlayerEnum::X));
+        assert_eq!(game.redo(), None);
+        assert!(game.replay_to(6).is_err());
+    }
+
+    #[test]
+    fn test_undo_action() {
+        // O takes back its move and X's reply, then both play differently
+        let mut game = Game::new(
+            ScriptedPlayer::new(moves(&[0, 1, 1, 2])),
+            ScriptedPlayer::new(vec![
+                PlayerAction::Undo,
+                PlayerAction::Move(4),
+                PlayerAction::Undo,
+                PlayerAction::Move(3),
+                PlayerAction::Move(4),
+            ]),
+        );
+        assert_eq!(game.play(), Some(PlayerEnum::X));
+        assert_eq!(
+            game.history()
+                .iter()
+                .map(|r| r.position)
+                .collect::<Vec<_>>(),
+            vec![0, 3, 1, 4, 2]
+        );
     }
 }
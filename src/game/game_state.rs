@@ -1,8 +1,10 @@
+use crate::game::board::{Board, Variant};
 use crate::game::consts::{
-    BOARD_SIZE, PLAYER_NONE, PLAYER_O, PLAYER_X, POSITION_MAP, TRANSFORM_SHIFTS, WIN_MASKS,
-    X_BIT_MASK,
+    BOARD_SIZE, O_BIT_MASK, PLAYER_NONE, PLAYER_O, PLAYER_X, POSITION_MAP, WIN_MASKS, X_BIT_MASK,
 };
 use crate::game::errors::TicTacToeError;
+use crate::game::symmetry::Symmetry;
+use crate::game::zobrist;
 use itertools::Itertools;
 use std::convert::TryFrom;
 use std::fmt;
@@ -16,6 +18,17 @@ pub enum PlayerEnum {
     O = PLAYER_O,
 }
 
+impl PlayerEnum {
+    /// The other player. Panics for `None`, which has no opponent.
+    pub fn opponent(self) -> PlayerEnum {
+        match self {
+            PlayerEnum::None => panic!("no opponent for an empty square"),
+            PlayerEnum::X => PlayerEnum::O,
+            PlayerEnum::O => PlayerEnum::X,
+        }
+    }
+}
+
 impl TryFrom<u32> for PlayerEnum {
     type Error = TicTacToeError;
 
@@ -55,6 +68,8 @@ impl fmt::Display for PlayerEnum {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct GameState {
     raw: u32,
+    // The Zobrist key of the pieces, kept up to date by `set`
+    key: u64,
 }
 
 // 8 7 4
@@ -63,7 +78,19 @@ pub struct GameState {
 
 impl GameState {
     pub fn new() -> GameState {
-        GameState { raw: 0 }
+        GameState { raw: 0, key: 0 }
+    }
+
+    fn from_raw(raw: u32) -> GameState {
+        GameState {
+            raw,
+            key: zobrist::raw_key(raw),
+        }
+    }
+
+    // The two bit value at a position known to be in bounds
+    fn cell(&self, pos: usize) -> u32 {
+        self.raw >> (POSITION_MAP[pos] * 2) & 3
     }
 
     pub fn set(&mut self, pos: usize, value: PlayerEnum) -> Result<(), TicTacToeError> {
@@ -71,7 +98,9 @@ impl GameState {
             return Err(TicTacToeError::OutOfBounds);
         }
         let actual_pos = POSITION_MAP[pos];
+        let old = self.cell(pos);
         self.raw |= (value as u32) << (actual_pos * 2);
+        self.key ^= zobrist::piece_key(pos, old) ^ zobrist::piece_key(pos, self.cell(pos));
         Ok(())
     }
 
@@ -81,7 +110,7 @@ impl GameState {
         }
         let actual_pos = POSITION_MAP[pos];
         let player_num = self.raw >> (actual_pos * 2) & 3;
-        return PlayerEnum::try_from(player_num);
+        PlayerEnum::try_from(player_num)
     }
 
     pub fn get_turn(&self) -> PlayerEnum {
@@ -94,14 +123,13 @@ impl GameState {
                 PlayerEnum::O => o_count += 1,
             }
         }
-        let player = if x_count == o_count {
+        if x_count == o_count {
             PlayerEnum::X
         } else {
             PlayerEnum::O
-        };
-        player
+        }
     }
-    
+
     pub fn auto_set(&mut self, pos: usize) -> Result<(), TicTacToeError> {
         self.set(pos, self.get_turn())
     }
@@ -116,24 +144,28 @@ impl GameState {
     pub fn inverted(&self) -> GameState {
         let mut piece_mask = ((self.raw & 0x000AAAAA) >> 1) | (self.raw & 0x00055555);
         piece_mask |= piece_mask << 1;
-        GameState {
-            raw: !self.raw & piece_mask,
-        }
+        GameState::from_raw(!self.raw & piece_mask)
     }
 
     pub fn transform(&self, rotations: i32, flip: bool) -> GameState {
-        let transform_index = if flip { 4 } else { 0 } + rotations % 4;
-        let mut new_raw = 0;
-        for (translation, bit_mask) in TRANSFORM_SHIFTS[transform_index as usize].iter() {
-            if *translation < 0 {
-                new_raw |= (self.raw & *bit_mask) << (translation.abs() * 2);
-            } else if *translation > 0 {
-                new_raw |= (self.raw & *bit_mask) >> (translation.abs() * 2);
-            } else {
-                new_raw |= self.raw & *bit_mask;
+        self.apply(Symmetry::new(rotations, flip))
+    }
+
+    pub fn apply(&self, symmetry: Symmetry) -> GameState {
+        GameState::from_raw(symmetry.apply(self.raw))
+    }
+
+    /// The symmetric board with the smallest raw value, and the symmetry that produces
+    /// it from this one.
+    pub fn canonical(&self) -> (GameState, Symmetry) {
+        let mut canonical = (*self, Symmetry::IDENTITY);
+        for &symmetry in Symmetry::ALL.iter() {
+            let transformed = self.apply(symmetry);
+            if transformed.raw < canonical.0.raw {
+                canonical = (transformed, symmetry);
             }
         }
-        GameState { raw: new_raw }
+        canonical
     }
 
     pub fn as_vec(&self) -> Vec<String> {
@@ -144,8 +176,31 @@ impl GameState {
         board_vec
     }
 
+    // Pieces on a winning line are shown in green
+    fn display_chars(&self, numbered: bool) -> Vec<String> {
+        let winning_cells = self
+            .winner()
+            .map(|(_, lines)| lines.concat())
+            .unwrap_or_default();
+        self.as_vec()
+            .into_iter()
+            .enumerate()
+            .map(|(i, s)| {
+                if s == " " && numbered {
+                    format!("{}", i + 1)
+                } else if winning_cells.contains(&i) {
+                    format!("\x1b[92m{}\x1b[0m", s)
+                } else if numbered {
+                    format!("\x1b[93m{}\x1b[0m", s)
+                } else {
+                    s
+                }
+            })
+            .collect_vec()
+    }
+
     pub fn get_display(&self) -> String {
-        let board_chars = self.as_vec();
+        let board_chars = self.display_chars(false);
         format!(
             " {} | {} | {} \n---+---+---\n {} | {} | {} \n---+---+---\n {} | {} | {} ",
             board_chars[0],
@@ -161,21 +216,7 @@ impl GameState {
     }
 
     pub fn get_numbered_display(&self) -> String {
-        let board_chars = self
-            .as_vec()
-            .iter()
-            .enumerate()
-            .map(|(i, s)| {
-                if s == " " {
-                    return format!("{}", i + 1);
-                } else if s == "X" {
-                    return "\x1b[93mX\x1b[0m".to_string();
-                } else if s == "O" {
-                    return "\x1b[93mO\x1b[0m".to_string();
-                }
-                return s.to_string();
-            })
-            .collect_vec();
+        let board_chars = self.display_chars(true);
         format!(
             " {} | {} | {} \n---+---+---\n {} | {} | {} \n---+---+---\n {} | {} | {} ",
             board_chars[0],
@@ -190,20 +231,169 @@ impl GameState {
         )
     }
 
+    /// The number of distinct ranks, one for every way of filling the cells with X, O or
+    /// nothing.
+    pub const RANK_COUNT: usize = 19683;
+
+    /// A dense index in `0..RANK_COUNT`, reading each position as a base 3 digit with the
+    /// first position least significant. Suitable for indexing flat arrays.
+    pub fn rank(&self) -> usize {
+        (0..BOARD_SIZE).rev().fold(0, |rank, pos| {
+            rank * 3 + (self.raw >> (POSITION_MAP[pos] * 2) & 3) as usize
+        })
+    }
+
+    /// The board with the given rank. Every rank is a board, but not every board is legal.
+    pub fn unrank(rank: usize) -> Result<GameState, TicTacToeError> {
+        if rank >= Self::RANK_COUNT {
+            return Err(TicTacToeError::OutOfBounds);
+        }
+        let mut raw = 0;
+        let mut rest = rank;
+        for &cell in POSITION_MAP.iter() {
+            raw |= ((rest % 3) as u32) << (cell * 2);
+            rest /= 3;
+        }
+        Ok(GameState::from_raw(raw))
+    }
+
     pub fn get_raw(&self) -> u32 {
         self.raw
     }
 
+    /// A hash of the pieces on the board, updated incrementally as pieces are set.
+    pub fn zobrist_key(&self) -> u64 {
+        self.key
+    }
+
     pub fn is_winner(&self, player: PlayerEnum) -> bool {
         let raw_board = if matches!(player, PlayerEnum::X) {
             self.raw
         } else {
             self.inverted().raw
         };
-        WIN_MASKS.iter().any(|&mask| (raw_board & mask) == mask)
+        WIN_MASKS.iter().any(|&mask| mask & !raw_board == 0)
+    }
+
+    // The low bit of every cell `player` holds and of every cell the opponent holds, in
+    // the same layout as WIN_MASKS
+    fn piece_bits(&self, player: PlayerEnum) -> Option<(u32, u32)> {
+        let x_bits = self.raw & X_BIT_MASK;
+        let o_bits = (self.raw & O_BIT_MASK) >> 1;
+        match player {
+            PlayerEnum::X => Some((x_bits, o_bits)),
+            PlayerEnum::O => Some((o_bits, x_bits)),
+            PlayerEnum::None => None,
+        }
+    }
+
+    fn empty_bits(&self) -> u32 {
+        let occupied = (self.raw | self.raw >> 1) & X_BIT_MASK;
+        !occupied & X_BIT_MASK
+    }
+
+    // User positions of the cells set in `bits`, in ascending order
+    fn bit_positions(bits: u32) -> Vec<usize> {
+        (0..BOARD_SIZE)
+            .filter(|&pos| bits >> (POSITION_MAP[pos] * 2) & 1 == 1)
+            .collect()
+    }
+
+    fn line_positions(mask: u32) -> [usize; 3] {
+        let positions = Self::bit_positions(mask);
+        [positions[0], positions[1], positions[2]]
+    }
+
+    // Cells that complete a line for `mine`, given the opponent's and the empty cells
+    fn completing_bits(mine: u32, theirs: u32, empty: u32) -> u32 {
+        WIN_MASKS
+            .iter()
+            .filter(|&&mask| mask & theirs == 0 && (mask & mine).count_ones() == 2)
+            .fold(0, |bits, &mask| bits | (mask & empty))
+    }
+
+    /// The empty positions where `player` would complete a line.
+    pub fn winning_moves(&self, player: PlayerEnum) -> Vec<usize> {
+        match self.piece_bits(player) {
+            Some((mine, theirs)) => {
+                Self::bit_positions(Self::completing_bits(mine, theirs, self.empty_bits()))
+            }
+            None => vec![],
+        }
+    }
+
+    /// The empty positions `player` must take to stop the opponent completing a line.
+    pub fn blocking_moves(&self, player: PlayerEnum) -> Vec<usize> {
+        match player {
+            PlayerEnum::X => self.winning_moves(PlayerEnum::O),
+            PlayerEnum::O => self.winning_moves(PlayerEnum::X),
+            PlayerEnum::None => vec![],
+        }
+    }
+
+    /// The empty positions where `player` would threaten to complete two lines at once.
+    pub fn fork_moves(&self, player: PlayerEnum) -> Vec<usize> {
+        let (mine, theirs) = match self.piece_bits(player) {
+            Some(bits) => bits,
+            None => return vec![],
+        };
+        let empty = self.empty_bits();
+        let forks = (0..BOARD_SIZE)
+            .map(|cell| 1 << (cell * 2))
+            .filter(|&bit| empty & bit != 0)
+            .filter(|&bit| {
+                Self::completing_bits(mine | bit, theirs, empty & !bit).count_ones() >= 2
+            })
+            .fold(0, |bits, bit| bits | bit);
+        Self::bit_positions(forks)
+    }
+
+    /// The lines `player` could still complete, with no opponent pieces on them.
+    pub fn open_lines(&self, player: PlayerEnum) -> Vec<[usize; 3]> {
+        match self.piece_bits(player) {
+            Some((_, theirs)) => WIN_MASKS
+                .iter()
+                .filter(|&&mask| mask & theirs == 0)
+                .map(|&mask| Self::line_positions(mask))
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    /// The player who has won and every line they completed, in position order.
+    pub fn winner(&self) -> Option<(PlayerEnum, Vec<[usize; 3]>)> {
+        [PlayerEnum::X, PlayerEnum::O].iter().find_map(|&player| {
+            let (mine, _) = self.piece_bits(player)?;
+            let lines = WIN_MASKS
+                .iter()
+                .filter(|&&mask| mask & mine == mask)
+                .map(|&mask| Self::line_positions(mask))
+                .sorted()
+                .collect_vec();
+            if lines.is_empty() {
+                None
+            } else {
+                Some((player, lines))
+            }
+        })
+    }
+
+    /// The first line `player` has completed.
+    pub fn winning_line(&self, player: PlayerEnum) -> Option<[usize; 3]> {
+        let (mine, _) = self.piece_bits(player)?;
+        WIN_MASKS
+            .iter()
+            .find(|&&mask| mask & mine == mask)
+            .map(|&mask| Self::line_positions(mask))
     }
 
     pub fn is_valid(&self) -> bool {
+        self.is_valid_in(Variant::Standard)
+    }
+
+    /// Like `is_valid`, but when `variant` lets the mover choose the piece any number of
+    /// each piece can be on the board, and in Notakto only X can be.
+    pub fn is_valid_in(&self, variant: Variant) -> bool {
         // Not an invalid number of pieces on the board or multiple winners
         let mut x_count = 0;
         let mut o_count = 0;
@@ -218,8 +408,12 @@ impl GameState {
                 Err(_) => return false,
             }
         }
-        (x_count == o_count || x_count == o_count + 1)
-            && !(self.is_winner(PlayerEnum::X) && self.is_winner(PlayerEnum::O))
+        let counts_valid = match variant {
+            Variant::Notakto => o_count == 0,
+            _ if variant.chooses_piece() => true,
+            _ => x_count == o_count || x_count == o_count + 1,
+        };
+        counts_valid && !(self.is_winner(PlayerEnum::X) && self.is_winner(PlayerEnum::O))
     }
 
     pub fn is_tie(&self) -> bool {
@@ -229,7 +423,7 @@ impl GameState {
                 return false;
             }
         }
-        true && !(self.is_winner(PlayerEnum::X) || self.is_winner(PlayerEnum::O))
+        !(self.is_winner(PlayerEnum::X) || self.is_winner(PlayerEnum::O))
     }
 
     pub fn empty_positions(&self) -> Vec<usize> {
@@ -243,6 +437,57 @@ impl GameState {
     }
 }
 
+impl Board for GameState {
+    type Raw = u32;
+    const SIZE: usize = BOARD_SIZE;
+
+    fn new() -> Self {
+        GameState::new()
+    }
+
+    fn set(&mut self, pos: usize, value: PlayerEnum) -> Result<(), TicTacToeError> {
+        GameState::set(self, pos, value)
+    }
+
+    fn get(&self, pos: usize) -> Result<PlayerEnum, TicTacToeError> {
+        GameState::get(self, pos)
+    }
+
+    fn get_raw(&self) -> u32 {
+        self.raw
+    }
+
+    fn zobrist_key(&self) -> u64 {
+        self.key
+    }
+
+    fn is_winner(&self, player: PlayerEnum) -> bool {
+        GameState::is_winner(self, player)
+    }
+
+    fn is_tie(&self) -> bool {
+        GameState::is_tie(self)
+    }
+
+    fn transform(&self, rotations: i32, flip: bool) -> Self {
+        GameState::transform(self, rotations, flip)
+    }
+
+    fn get_display(&self) -> String {
+        GameState::get_display(self)
+    }
+
+    fn get_numbered_display(&self) -> String {
+        GameState::get_numbered_display(self)
+    }
+}
+
+impl Default for GameState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl fmt::Display for GameState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let b_vec = self.as_vec();
@@ -277,7 +522,7 @@ impl TryFrom<u32> for GameState {
     type Error = TicTacToeError;
 
     fn try_from(value: u32) -> Result<Self, Self::Error> {
-        let board = GameState { raw: value };
+        let board = GameState::from_raw(value);
         if !board.is_valid() {
             return Err(TicTacToeError::InvalidBoard);
         }
@@ -288,6 +533,91 @@ impl TryFrom<u32> for GameState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::board_iterator::BoardIterator;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_tactics() {
+        let board = GameState::from_str("XX OO    ").unwrap();
+        assert_eq!(board.winning_moves(PlayerEnum::X), vec![2]);
+        assert_eq!(board.winning_moves(PlayerEnum::O), vec![5]);
+        assert_eq!(board.blocking_moves(PlayerEnum::X), vec![5]);
+        assert_eq!(board.open_lines(PlayerEnum::X).len(), 3);
+        assert_eq!(board.open_lines(PlayerEnum::X)[0], [0, 1, 2]);
+        assert!(board.winning_moves(PlayerEnum::None).is_empty());
+        let board = GameState::from_str("X   O   X").unwrap();
+        assert_eq!(board.fork_moves(PlayerEnum::X), vec![2, 6]);
+        assert!(board.fork_moves(PlayerEnum::O).is_empty());
+        assert!(board.winning_moves(PlayerEnum::X).is_empty());
+        let board = GameState::from_str("XO  X   O").unwrap();
+        assert_eq!(board.fork_moves(PlayerEnum::X), vec![3, 6]);
+    }
+    #[test]
+    fn test_winning_line() {
+        let test_cases = vec![
+            ("XXXOO    ", PlayerEnum::X, Some([0, 1, 2])),
+            ("XXOXO O  ", PlayerEnum::O, Some([2, 4, 6])),
+            ("OX OX  X ", PlayerEnum::X, Some([1, 4, 7])),
+            ("OX OX  X ", PlayerEnum::O, None),
+        ];
+        for (board_str, player, expected) in test_cases {
+            let board = GameState::from_str(board_str).unwrap();
+            assert_eq!(board.winning_line(player), expected, "{}", board_str);
+        }
+    }
+    #[test]
+    fn test_winner() {
+        let test_cases = vec![
+            ("XXXOO    ", Some((PlayerEnum::X, vec![[0, 1, 2]]))),
+            ("XXOXO O  ", Some((PlayerEnum::O, vec![[2, 4, 6]]))),
+            (
+                "XXXXOOXOO",
+                Some((PlayerEnum::X, vec![[0, 1, 2], [0, 3, 6]])),
+            ),
+            ("XOXXOOOXX", None),
+            ("         ", None),
+        ];
+        for (board_str, expected) in test_cases {
+            let board = GameState::from_str(board_str).unwrap();
+            assert_eq!(board.winner(), expected, "{}", board_str);
+        }
+    }
+    #[test]
+    fn test_display_highlights_winner() {
+        let board = GameState::from_str("XXXOO    ").unwrap();
+        let lines = board.get_display();
+        let lines = lines.lines().collect_vec();
+        assert_eq!(
+            lines[0],
+            " \x1b[92mX\x1b[0m | \x1b[92mX\x1b[0m | \x1b[92mX\x1b[0m "
+        );
+        assert_eq!(lines[2], " O | O |   ");
+        let lines = board.get_numbered_display();
+        let lines = lines.lines().collect_vec();
+        assert_eq!(lines[2], " \x1b[93mO\x1b[0m | \x1b[93mO\x1b[0m | 6 ");
+        assert!(!GameState::from_str("XX OO    ")
+            .unwrap()
+            .get_display()
+            .contains('\x1b'));
+    }
+    #[test]
+    fn test_is_valid_in() {
+        let mut board = GameState::new();
+        board.set(0, PlayerEnum::X).unwrap();
+        board.set(4, PlayerEnum::X).unwrap();
+        assert!(!board.is_valid());
+        assert!(board.is_valid_in(Variant::Wild));
+        assert!(!board.is_valid_in(Variant::Misere));
+        assert!(board.is_valid_in(Variant::Notakto));
+        for pos in [1, 2, 6, 7, 8].iter() {
+            board.set(*pos, PlayerEnum::O).unwrap();
+        }
+        // Wild games still end at the first line
+        board.set(3, PlayerEnum::X).unwrap();
+        board.set(5, PlayerEnum::X).unwrap();
+        assert!(!board.is_valid_in(Variant::Wild));
+        assert!(!board.is_valid_in(Variant::Notakto));
+    }
 
     #[test]
     fn test_inverted() {
@@ -335,6 +665,73 @@ mod tests {
         }
     }
     #[test]
+    fn test_rank() {
+        assert_eq!(GameState::new().rank(), 0);
+        assert_eq!(GameState::from_str("X        ").unwrap().rank(), 1);
+        assert_eq!(GameState::from_str("XO       ").unwrap().rank(), 7);
+        assert_eq!(
+            GameState::unrank(GameState::RANK_COUNT - 1)
+                .unwrap()
+                .to_string(),
+            "OOOOOOOOO"
+        );
+        assert!(GameState::unrank(GameState::RANK_COUNT).is_err());
+        for rank in 0..GameState::RANK_COUNT {
+            assert_eq!(GameState::unrank(rank).unwrap().rank(), rank);
+        }
+    }
+    #[test]
+    fn test_rank_is_bijective_on_legal_boards() {
+        let boards = BoardIterator::new().collect_vec();
+        let mut seen = vec![false; GameState::RANK_COUNT];
+        for board in boards {
+            let rank = board.rank();
+            assert!(!seen[rank], "{}", board);
+            seen[rank] = true;
+            assert_eq!(GameState::unrank(rank).unwrap(), board);
+        }
+    }
+    #[test]
+    fn test_zobrist_key() {
+        let mut a = GameState::new();
+        let mut b = GameState::new();
+        for &pos in [0, 4, 8, 2].iter() {
+            a.auto_set(pos).unwrap();
+        }
+        for &pos in [8, 2, 0, 4].iter() {
+            b.auto_set(pos).unwrap();
+        }
+        assert_eq!(a.zobrist_key(), b.zobrist_key());
+        assert_ne!(a.zobrist_key(), a.transform(1, false).zobrist_key());
+        assert_eq!(GameState::new().zobrist_key(), 0);
+        // Every filling of the board, keyed in one go and piece by piece
+        let mut keys = HashSet::new();
+        for rank in 0..GameState::RANK_COUNT {
+            let board = GameState::unrank(rank).unwrap();
+            let mut rebuilt = GameState::new();
+            for pos in 0..BOARD_SIZE {
+                rebuilt.set(pos, board.get(pos).unwrap()).unwrap();
+            }
+            assert_eq!(rebuilt.zobrist_key(), board.zobrist_key());
+            keys.insert(board.zobrist_key());
+        }
+        assert_eq!(keys.len(), GameState::RANK_COUNT);
+    }
+    #[test]
+    fn test_canonical() {
+        let board = GameState::from_str("X O XOXO ").unwrap();
+        let (canonical, symmetry) = board.canonical();
+        assert_eq!(board.apply(symmetry), canonical);
+        assert_eq!(canonical.apply(symmetry.inverse()), board);
+        for &other in Symmetry::ALL.iter() {
+            let transformed = board.apply(other);
+            assert_eq!(transformed.canonical().0, canonical);
+            assert!(canonical.raw <= transformed.raw);
+        }
+        let empty = GameState::new();
+        assert_eq!(empty.canonical(), (empty, Symmetry::IDENTITY));
+    }
+    #[test]
     fn test_from_string_to_str() {
         let test_cases = vec![
             ("", "", false),
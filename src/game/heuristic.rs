@@ -0,0 +1,198 @@
+// Newell and Simon's rules for perfect tic-tac-toe, tried in order until one applies.
+
+use crate::game::game_state::{GameState, PlayerEnum};
+use crate::game::player::Player;
+use std::fmt;
+
+const CENTER: usize = 4;
+const CORNERS: [usize; 4] = [0, 2, 6, 8];
+const SIDES: [usize; 4] = [1, 3, 5, 7];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    Win,
+    Block,
+    Fork,
+    BlockFork,
+    Center,
+    OppositeCorner,
+    EmptyCorner,
+    EmptySide,
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let explanation = match self {
+            Rule::Win => "completes a line",
+            Rule::Block => "blocks the opponent's line",
+            Rule::Fork => "makes two threats at once",
+            Rule::BlockFork => "stops the opponent from making two threats at once",
+            Rule::Center => "takes the center",
+            Rule::OppositeCorner => "takes the corner opposite the opponent",
+            Rule::EmptyCorner => "takes an empty corner",
+            Rule::EmptySide => "takes an empty side",
+        };
+        write!(f, "{}", explanation)
+    }
+}
+
+/// Plays by the first of Newell and Simon's rules that applies, explaining each move on
+/// stderr unless made quiet.
+pub struct HeuristicPlayer {
+    player_enum: PlayerEnum,
+    verbose: bool,
+    last_rule: Option<Rule>,
+}
+
+impl HeuristicPlayer {
+    pub fn new() -> HeuristicPlayer {
+        HeuristicPlayer {
+            player_enum: PlayerEnum::None,
+            verbose: true,
+            last_rule: None,
+        }
+    }
+
+    pub fn quiet(mut self) -> HeuristicPlayer {
+        self.verbose = false;
+        self
+    }
+
+    /// The rule behind the last move played.
+    pub fn last_rule(&self) -> Option<Rule> {
+        self.last_rule
+    }
+
+    /// The move for the side to move and the rule that picked it.
+    pub fn choose(board: GameState) -> (usize, Rule) {
+        let me = board.get_turn();
+        let them = me.opponent();
+        if let Some(&pos) = board.winning_moves(me).first() {
+            return (pos, Rule::Win);
+        }
+        if let Some(&pos) = board.blocking_moves(me).first() {
+            return (pos, Rule::Block);
+        }
+        if let Some(&pos) = board.fork_moves(me).first() {
+            return (pos, Rule::Fork);
+        }
+        if let Some(pos) = Self::block_fork(board, me) {
+            return (pos, Rule::BlockFork);
+        }
+        if board.is_empty(CENTER).unwrap() {
+            return (CENTER, Rule::Center);
+        }
+        let opposite = CORNERS
+            .iter()
+            .map(|&c| (c, 8 - c))
+            .find(|&(corner, opposite)| {
+                board.get(corner).unwrap() == them && board.is_empty(opposite).unwrap()
+            });
+        if let Some((_, pos)) = opposite {
+            return (pos, Rule::OppositeCorner);
+        }
+        if let Some(&pos) = CORNERS.iter().find(|&&c| board.is_empty(c).unwrap()) {
+            return (pos, Rule::EmptyCorner);
+        }
+        let pos = *SIDES.iter().find(|&&s| board.is_empty(s).unwrap()).unwrap();
+        (pos, Rule::EmptySide)
+    }
+
+    // With one fork square, take it. With more, make a threat whose forced answer does
+    // not give the opponent a fork, falling back to taking one of the fork squares.
+    fn block_fork(board: GameState, me: PlayerEnum) -> Option<usize> {
+        let them = me.opponent();
+        let fork_squares = board.fork_moves(them);
+        match fork_squares.len() {
+            0 => return None,
+            1 => return Some(fork_squares[0]),
+            _ => {}
+        }
+        let forcing = board.empty_positions().into_iter().find(|&pos| {
+            let mut next = board;
+            next.set(pos, me).unwrap();
+            let replies = next.winning_moves(me);
+            !replies.is_empty()
+                && replies.iter().all(|&reply| {
+                    let mut answered = next;
+                    answered.set(reply, them).unwrap();
+                    answered.winning_moves(them).len() < 2
+                })
+        });
+        forcing.or_else(|| fork_squares.first().copied())
+    }
+}
+
+impl Default for HeuristicPlayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Player for HeuristicPlayer {
+    fn assign_piece(&mut self, player_enum: PlayerEnum) {
+        self.player_enum = player_enum
+    }
+
+    fn play_turn(&mut self, board: GameState) -> usize {
+        let (pos, rule) = Self::choose(board);
+        self.last_rule = Some(rule);
+        if self.verbose {
+            eprintln!("{} plays {}: {}", self.player_enum, pos + 1, rule);
+        }
+        pos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board::Variant;
+    use crate::game::game::Game;
+    use crate::game::player::{assert_never_loses, AIPlayer};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_rules() {
+        let test_cases = vec![
+            ("XX OO    ", 2, Rule::Win),
+            ("XX  O    ", 2, Rule::Block),
+            ("XO  X   O", 3, Rule::Fork),
+            // X threatens forks on 2 and 6, so O forces X to answer on a side instead
+            ("X   O   X", 1, Rule::BlockFork),
+            ("X        ", 4, Rule::Center),
+            ("    X    ", 0, Rule::EmptyCorner),
+            ("O   X    ", 8, Rule::OppositeCorner),
+        ];
+        for (board_str, expected_pos, expected_rule) in test_cases {
+            let board = GameState::from_str(board_str).unwrap();
+            assert_eq!(
+                HeuristicPlayer::choose(board),
+                (expected_pos, expected_rule),
+                "{}",
+                board_str
+            );
+        }
+    }
+
+    #[test]
+    fn test_never_loses() {
+        for me in [PlayerEnum::X, PlayerEnum::O] {
+            let mut player = HeuristicPlayer::new().quiet();
+            player.assign_piece(me);
+            assert_never_loses(GameState::new(), Variant::Standard, &mut player, me);
+        }
+        let ai: Box<dyn Player> = Box::new(AIPlayer::new());
+        let mut game = Game::new(Box::new(HeuristicPlayer::new().quiet()), ai);
+        assert_eq!(game.play(), None);
+    }
+
+    #[test]
+    fn test_last_rule() {
+        let mut player = HeuristicPlayer::new().quiet();
+        player.assign_piece(PlayerEnum::X);
+        assert_eq!(player.last_rule(), None);
+        player.play_turn(GameState::new());
+        assert_eq!(player.last_rule(), Some(Rule::Center));
+    }
+}
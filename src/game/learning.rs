@@ -0,0 +1,459 @@
+// Temporal-difference learning of afterstate values. The table holds, for each board a
+// player can move into, how good that board is for the player who just moved: 1 for a
+// win, 0.5 for a tie and 0 for a loss. Boards are keyed by their simplified form so all
+// symmetric positions share one value, and since the side to move follows from the
+// board, X and O can learn from the same table.
+
+use crate::game::board::Board;
+use crate::game::game::Game;
+use crate::game::game_state::{GameState, PlayerEnum};
+use crate::game::player::{simplified_board, AIPlayer, Player, RandomPlayer};
+use crate::game::tournament::Record;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+const MAGIC: &str = "TTTV 1";
+const UNKNOWN_VALUE: f64 = 0.5;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValueTable {
+    values: HashMap<u32, f64>,
+}
+
+impl ValueTable {
+    pub fn new() -> ValueTable {
+        ValueTable {
+            values: HashMap::new(),
+        }
+    }
+
+    // Finished boards are worth their result rather than a learned value
+    fn reward(board: GameState, mover: PlayerEnum) -> Option<f64> {
+        if board.is_winner(mover) {
+            Some(1.0)
+        } else if board.is_tie() {
+            Some(0.5)
+        } else if board.is_over() {
+            Some(0.0)
+        } else {
+            None
+        }
+    }
+
+    /// The value of `board` for the player who made the last move on it.
+    pub fn get(&self, board: GameState) -> f64 {
+        let mover = board.get_turn().opponent();
+        Self::reward(board, mover).unwrap_or_else(|| {
+            let key = simplified_board(board).get_raw();
+            *self.values.get(&key).unwrap_or(&UNKNOWN_VALUE)
+        })
+    }
+
+    /// Moves the value of `board` a fraction `alpha` of the way towards `target`.
+    pub fn update(&mut self, board: GameState, target: f64, alpha: f64) {
+        if board.is_over() {
+            return;
+        }
+        let value = self.get(board);
+        let key = simplified_board(board).get_raw();
+        self.values.insert(key, value + alpha * (target - value));
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Writes the table as text, one `raw value` pair per line after a header line.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut entries = self.values.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|(&raw, _)| raw);
+        let mut text = format!("{}\n", MAGIC);
+        for (raw, value) in entries {
+            text += &format!("{} {}\n", raw, value);
+        }
+        fs::write(path, text)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<ValueTable> {
+        let invalid = |line: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid value table line: {}", line),
+            )
+        };
+        let text = fs::read_to_string(path)?;
+        let mut lines = text.lines();
+        match lines.next() {
+            Some(MAGIC) => {}
+            line => return Err(invalid(line.unwrap_or(""))),
+        }
+        let mut table = ValueTable::new();
+        for line in lines {
+            let (raw, value) = line.split_once(' ').ok_or_else(|| invalid(line))?;
+            let raw = raw.parse::<u32>().map_err(|_| invalid(line))?;
+            let value = value.parse::<f64>().map_err(|_| invalid(line))?;
+            table.values.insert(raw, value);
+        }
+        Ok(table)
+    }
+}
+
+/// How a training parameter changes over the episodes of a run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Schedule {
+    Constant(f64),
+    /// Moves evenly from `start` to `end` over `episodes`, then stays at `end`.
+    Linear {
+        start: f64,
+        end: f64,
+        episodes: usize,
+    },
+    /// Multiplies `start` by `decay` every episode, never going below `min`.
+    Exponential {
+        start: f64,
+        decay: f64,
+        min: f64,
+    },
+}
+
+impl Schedule {
+    pub fn value(&self, episode: usize) -> f64 {
+        match *self {
+            Schedule::Constant(value) => value,
+            Schedule::Linear {
+                start,
+                end,
+                episodes,
+            } => {
+                let progress = (episode as f64 / episodes.max(1) as f64).min(1.0);
+                start + (end - start) * progress
+            }
+            Schedule::Exponential { start, decay, min } => {
+                (start * decay.powi(episode.min(i32::MAX as usize) as i32)).max(min)
+            }
+        }
+    }
+}
+
+/// Plays the move with the highest learned value, exploring a random move with
+/// probability epsilon. While learning, each of its afterstates is moved towards the
+/// value of its best afterstate on the next turn, or towards the result at the end.
+pub struct LearnedPlayer<R: Rng = StdRng> {
+    table: Rc<RefCell<ValueTable>>,
+    rng: R,
+    epsilon: f64,
+    alpha: f64,
+    player_enum: PlayerEnum,
+    previous: Option<GameState>,
+}
+
+impl LearnedPlayer<StdRng> {
+    /// Always plays the best move it knows without changing the table.
+    pub fn greedy(table: Rc<RefCell<ValueTable>>) -> LearnedPlayer<StdRng> {
+        Self::learning(table, 0.0, 0.0, StdRng::from_entropy())
+    }
+}
+
+impl<R: Rng> LearnedPlayer<R> {
+    pub fn learning(
+        table: Rc<RefCell<ValueTable>>,
+        alpha: f64,
+        epsilon: f64,
+        rng: R,
+    ) -> LearnedPlayer<R> {
+        LearnedPlayer {
+            table,
+            rng,
+            epsilon,
+            alpha,
+            player_enum: PlayerEnum::None,
+            previous: None,
+        }
+    }
+
+    fn learn(&mut self, target: f64) {
+        if let Some(previous) = self.previous {
+            if self.alpha > 0.0 {
+                self.table.borrow_mut().update(previous, target, self.alpha);
+            }
+        }
+    }
+}
+
+impl<R: Rng> Player for LearnedPlayer<R> {
+    fn assign_piece(&mut self, player_enum: PlayerEnum) {
+        self.player_enum = player_enum
+    }
+
+    fn play_turn(&mut self, board: GameState) -> usize {
+        let positions = board.empty_positions();
+        let afterstate = |pos: usize| {
+            let mut next = board;
+            next.auto_set(pos).unwrap();
+            next
+        };
+        let (best_pos, best_value) = {
+            let table = self.table.borrow();
+            positions
+                .iter()
+                .map(|&pos| (pos, table.get(afterstate(pos))))
+                .fold((positions[0], f64::NEG_INFINITY), |best, next| {
+                    if next.1 > best.1 {
+                        next
+                    } else {
+                        best
+                    }
+                })
+        };
+        // Learning towards the best move rather than the one played keeps exploration
+        // from dragging values down
+        self.learn(best_value);
+        let pos = if self.rng.gen::<f64>() < self.epsilon {
+            *positions.choose(&mut self.rng).unwrap()
+        } else {
+            best_pos
+        };
+        self.previous = Some(afterstate(pos));
+        pos
+    }
+
+    fn on_game_end(&mut self, board: GameState) {
+        let reward = ValueTable::reward(board, self.player_enum).unwrap_or(UNKNOWN_VALUE);
+        self.learn(reward);
+        self.previous = None;
+    }
+}
+
+/// How a snapshot of the learned player did in evaluation games, as its own record.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrainingReport {
+    pub episode: usize,
+    pub vs_random: Record,
+    pub vs_perfect: Record,
+}
+
+/// Trains a value table by self-play between two learning players sharing it.
+pub struct Trainer {
+    table: Rc<RefCell<ValueTable>>,
+    rng: StdRng,
+    alpha: Schedule,
+    epsilon: Schedule,
+    episode: usize,
+    evaluation_games: usize,
+    report_every: Option<usize>,
+    checkpoint: Option<(PathBuf, usize)>,
+    perfect: Box<dyn Fn() -> Box<dyn Player>>,
+}
+
+impl Trainer {
+    pub fn new(table: ValueTable, seed: u64) -> Trainer {
+        Trainer {
+            table: Rc::new(RefCell::new(table)),
+            rng: StdRng::seed_from_u64(seed),
+            alpha: Schedule::Constant(0.2),
+            epsilon: Schedule::Linear {
+                start: 0.3,
+                end: 0.05,
+                episodes: 20000,
+            },
+            episode: 0,
+            evaluation_games: 100,
+            report_every: None,
+            checkpoint: None,
+            perfect: Box::new(|| Box::new(AIPlayer::new())),
+        }
+    }
+
+    pub fn alpha(mut self, schedule: Schedule) -> Trainer {
+        self.alpha = schedule;
+        self
+    }
+
+    pub fn epsilon(mut self, schedule: Schedule) -> Trainer {
+        self.epsilon = schedule;
+        self
+    }
+
+    /// Evaluates against random and perfect opponents every `episodes` episodes, playing
+    /// `games` games against each with colors alternating.
+    pub fn report_every(mut self, episodes: usize, games: usize) -> Trainer {
+        self.report_every = Some(episodes);
+        self.evaluation_games = games;
+        self
+    }
+
+    /// Saves the table to `path` every `episodes` episodes and at the end of training.
+    pub fn checkpoint<P: AsRef<Path>>(mut self, path: P, episodes: usize) -> Trainer {
+        self.checkpoint = Some((path.as_ref().to_path_buf(), episodes));
+        self
+    }
+
+    /// The opponent used as the perfect player in reports, the minimax AI by default.
+    pub fn perfect_opponent(mut self, factory: Box<dyn Fn() -> Box<dyn Player>>) -> Trainer {
+        self.perfect = factory;
+        self
+    }
+
+    pub fn table(&self) -> Rc<RefCell<ValueTable>> {
+        self.table.clone()
+    }
+
+    pub fn episode(&self) -> usize {
+        self.episode
+    }
+
+    fn learner(&mut self) -> Box<dyn Player> {
+        Box::new(LearnedPlayer::learning(
+            self.table.clone(),
+            self.alpha.value(self.episode),
+            self.epsilon.value(self.episode),
+            StdRng::seed_from_u64(self.rng.gen()),
+        ))
+    }
+
+    /// Plays `episodes` self-play games, returning the reports made along the way.
+    pub fn train(&mut self, episodes: usize) -> io::Result<Vec<TrainingReport>> {
+        let mut reports = vec![];
+        for _ in 0..episodes {
+            let mut game = Game::new(self.learner(), self.learner());
+            game.play();
+            self.episode += 1;
+            if self.report_every.is_some_and(|n| self.episode.is_multiple_of(n)) {
+                reports.push(self.evaluate());
+            }
+            if let Some((path, n)) = &self.checkpoint {
+                if self.episode.is_multiple_of(*n) {
+                    self.table.borrow().save(path)?;
+                }
+            }
+        }
+        if let Some((path, _)) = &self.checkpoint {
+            self.table.borrow().save(path)?;
+        }
+        Ok(reports)
+    }
+
+    // Plays the greedy player against a random or perfect opponent
+    fn play_evaluation(&mut self, perfect: bool) -> Record {
+        let mut record = Record::default();
+        for i in 0..self.evaluation_games {
+            let learned: Box<dyn Player> = Box::new(LearnedPlayer::learning(
+                self.table.clone(),
+                0.0,
+                0.0,
+                StdRng::seed_from_u64(self.rng.gen()),
+            ));
+            let opponent: Box<dyn Player> = if perfect {
+                (self.perfect)()
+            } else {
+                Box::new(RandomPlayer::seeded(self.rng.gen()))
+            };
+            let (mut game, side) = if i % 2 == 0 {
+                (Game::new(learned, opponent), PlayerEnum::X)
+            } else {
+                (Game::new(opponent, learned), PlayerEnum::O)
+            };
+            match game.play() {
+                None => record.draws += 1,
+                Some(winner) if winner == side => record.wins += 1,
+                Some(_) => record.losses += 1,
+            }
+        }
+        record
+    }
+
+    pub fn evaluate(&mut self) -> TrainingReport {
+        TrainingReport {
+            episode: self.episode,
+            vs_random: self.play_evaluation(false),
+            vs_perfect: self.play_evaluation(true),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::solver::Tablebase;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_value_table() {
+        let mut table = ValueTable::new();
+        let board = GameState::from_str("X        ").unwrap();
+        assert_eq!(table.get(board), UNKNOWN_VALUE);
+        table.update(board, 1.0, 0.5);
+        assert_eq!(table.get(board), 0.75);
+        // Symmetric boards share a value
+        assert_eq!(table.get(GameState::from_str("        X").unwrap()), 0.75);
+        assert_eq!(table.get(GameState::from_str("XXXOO    ").unwrap()), 1.0);
+        assert_eq!(table.get(GameState::from_str("XX OOOX  ").unwrap()), 1.0);
+        assert_eq!(table.get(GameState::from_str("XOXXOOOXX").unwrap()), 0.5);
+        assert_eq!(table.len(), 1);
+    }
+
+    #[test]
+    fn test_schedules() {
+        assert_eq!(Schedule::Constant(0.1).value(500), 0.1);
+        let linear = Schedule::Linear {
+            start: 1.0,
+            end: 0.0,
+            episodes: 10,
+        };
+        assert_eq!(linear.value(5), 0.5);
+        assert_eq!(linear.value(20), 0.0);
+        let exponential = Schedule::Exponential {
+            start: 1.0,
+            decay: 0.5,
+            min: 0.1,
+        };
+        assert_eq!(exponential.value(2), 0.25);
+        assert_eq!(exponential.value(10), 0.1);
+    }
+
+    #[test]
+    fn test_save_load() {
+        let path = std::env::temp_dir().join(format!("ttt-values-{}.txt", std::process::id()));
+        let mut table = ValueTable::new();
+        table.update(GameState::from_str("X        ").unwrap(), 0.9, 1.0);
+        table.update(GameState::from_str("X   O    ").unwrap(), 0.1, 1.0);
+        table.save(&path).unwrap();
+        assert_eq!(ValueTable::load(&path).unwrap(), table);
+        fs::write(&path, "TTTV 1\nnot a line\n").unwrap();
+        assert!(ValueTable::load(&path).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_training() {
+        let tablebase = Rc::new(Tablebase::solve());
+        let mut trainer = Trainer::new(ValueTable::new(), 42)
+            .epsilon(Schedule::Linear {
+                start: 0.3,
+                end: 0.0,
+                episodes: 15000,
+            })
+            .report_every(20000, 50)
+            .perfect_opponent(Box::new(move || {
+                Box::new(AIPlayer::with_tablebase(tablebase.clone()))
+            }));
+        let reports = trainer.train(20000).unwrap();
+        assert_eq!(reports.len(), 1);
+        let report = reports[0];
+        assert_eq!(report.episode, 20000);
+        assert_eq!(report.vs_perfect.losses, 0, "{:?}", report);
+        assert!(report.vs_random.wins >= 40, "{:?}", report);
+        assert_eq!(report.vs_random.losses, 0, "{:?}", report);
+    }
+}
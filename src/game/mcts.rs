@@ -0,0 +1,316 @@
+use crate::game::board::Board;
+use crate::game::game_state::{GameState, PlayerEnum};
+use crate::game::player::Player;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::time::{Duration, Instant};
+
+const DEFAULT_ITERATIONS: usize = 1000;
+
+struct Node<B: Board> {
+    board: B,
+    // The move that led here and the side that made it
+    pos: usize,
+    mover: PlayerEnum,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    untried: Vec<usize>,
+    visits: u32,
+    // Points for `mover`: 1 per win and 0.5 per tie
+    score: f64,
+}
+
+impl<B: Board> Node<B> {
+    fn new(board: B, pos: usize, mover: PlayerEnum, parent: Option<usize>) -> Node<B> {
+        let untried = if board.is_over() {
+            vec![]
+        } else {
+            board.empty_positions()
+        };
+        Node {
+            board,
+            pos,
+            mover,
+            parent,
+            children: vec![],
+            untried,
+            visits: 0,
+            score: 0.0,
+        }
+    }
+}
+
+/// Picks moves with UCT tree search and random playouts. The tree is kept between turns
+/// and reused when the next board is one already explored.
+pub struct MctsPlayer<B: Board = GameState, R: Rng = StdRng> {
+    rng: R,
+    iterations: Option<usize>,
+    time_budget: Option<Duration>,
+    exploration: f64,
+    // The root is always nodes[0]
+    nodes: Vec<Node<B>>,
+}
+
+impl<B: Board> MctsPlayer<B, StdRng> {
+    pub fn new() -> MctsPlayer<B, StdRng> {
+        Self::with_rng(StdRng::from_entropy())
+    }
+
+    pub fn seeded(seed: u64) -> MctsPlayer<B, StdRng> {
+        Self::with_rng(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl<B: Board> Default for MctsPlayer<B, StdRng> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Board, R: Rng> MctsPlayer<B, R> {
+    pub fn with_rng(rng: R) -> MctsPlayer<B, R> {
+        MctsPlayer {
+            rng,
+            iterations: None,
+            time_budget: None,
+            exploration: std::f64::consts::SQRT_2,
+            nodes: vec![],
+        }
+    }
+
+    /// The number of playouts per move. If neither this nor a time budget is set,
+    /// 1000 playouts are used.
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = Some(iterations);
+        self
+    }
+
+    /// Stops searching after this long, or at the iteration budget if that comes first.
+    pub fn time_budget(mut self, budget: Duration) -> Self {
+        self.time_budget = Some(budget);
+        self
+    }
+
+    /// The UCT exploration constant; higher values try less promising moves more often.
+    pub fn exploration(mut self, exploration: f64) -> Self {
+        self.exploration = exploration;
+        self
+    }
+
+    /// How many playouts have gone through the current root.
+    pub fn root_visits(&self) -> u32 {
+        self.nodes.first().map_or(0, |n| n.visits)
+    }
+
+    // Makes `board` the root, keeping its subtree if it was our last root, one of its
+    // children or one of its grandchildren
+    fn set_root(&mut self, board: B) {
+        let mut found = None;
+        if let Some(root) = self.nodes.first() {
+            let mut candidates = vec![0];
+            candidates.extend(root.children.iter().copied());
+            for &child in root.children.iter() {
+                candidates.extend(self.nodes[child].children.iter().copied());
+            }
+            found = candidates
+                .into_iter()
+                .find(|&i| self.nodes[i].board == board);
+        }
+        match found {
+            Some(index) => self.keep_subtree(index),
+            None => {
+                let mover = board.get_turn().opponent();
+                self.nodes = vec![Node::new(board, 0, mover, None)];
+            }
+        }
+    }
+
+    fn keep_subtree(&mut self, index: usize) {
+        let mut old = std::mem::take(&mut self.nodes)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+        let mut queue = vec![(index, None)];
+        while let Some((old_index, parent)) = queue.pop() {
+            let mut node = old[old_index].take().unwrap();
+            let new_index = self.nodes.len();
+            node.parent = parent;
+            let children = std::mem::take(&mut node.children);
+            if let Some(p) = parent {
+                self.nodes[p].children.push(new_index);
+            }
+            self.nodes.push(node);
+            queue.extend(children.into_iter().map(|c| (c, Some(new_index))));
+        }
+    }
+
+    fn uct(&self, parent: &Node<B>, child: &Node<B>) -> f64 {
+        let visits = child.visits as f64;
+        child.score / visits + self.exploration * ((parent.visits as f64).ln() / visits).sqrt()
+    }
+
+    fn select(&self) -> usize {
+        let mut index = 0;
+        loop {
+            let node = &self.nodes[index];
+            if !node.untried.is_empty() || node.children.is_empty() {
+                return index;
+            }
+            index = *node
+                .children
+                .iter()
+                .max_by(|&&a, &&b| {
+                    let (a, b) = (&self.nodes[a], &self.nodes[b]);
+                    self.uct(node, a).total_cmp(&self.uct(node, b))
+                })
+                .unwrap();
+        }
+    }
+
+    fn expand(&mut self, index: usize) -> usize {
+        let node = &mut self.nodes[index];
+        if node.untried.is_empty() {
+            return index;
+        }
+        let i = self.rng.gen_range(0..node.untried.len());
+        let pos = node.untried.swap_remove(i);
+        let mover = node.board.get_turn();
+        let mut board = node.board;
+        board.auto_set(pos).unwrap();
+        let child = self.nodes.len();
+        self.nodes.push(Node::new(board, pos, mover, Some(index)));
+        self.nodes[index].children.push(child);
+        child
+    }
+
+    fn playout(&mut self, mut board: B) -> Option<PlayerEnum> {
+        while !board.is_over() {
+            let pos = *board.empty_positions().choose(&mut self.rng).unwrap();
+            board.auto_set(pos).unwrap();
+        }
+        [PlayerEnum::X, PlayerEnum::O]
+            .iter()
+            .copied()
+            .find(|&side| board.is_winner(side))
+    }
+
+    fn backpropagate(&mut self, mut index: usize, winner: Option<PlayerEnum>) {
+        loop {
+            let node = &mut self.nodes[index];
+            node.visits += 1;
+            node.score += match winner {
+                None => 0.5,
+                Some(side) if side == node.mover => 1.0,
+                Some(_) => 0.0,
+            };
+            match node.parent {
+                Some(parent) => index = parent,
+                None => return,
+            }
+        }
+    }
+
+    fn search(&mut self) {
+        let start = Instant::now();
+        let iterations = match (self.iterations, self.time_budget) {
+            (None, None) => Some(DEFAULT_ITERATIONS),
+            (iterations, _) => iterations,
+        };
+        let mut i = 0;
+        while iterations.is_none_or(|n| i < n)
+            && self.time_budget.is_none_or(|t| start.elapsed() < t)
+        {
+            let leaf = self.select();
+            let child = self.expand(leaf);
+            let winner = self.playout(self.nodes[child].board);
+            self.backpropagate(child, winner);
+            i += 1;
+        }
+    }
+}
+
+impl<B: Board, R: Rng> Player<B> for MctsPlayer<B, R> {
+    fn play_turn(&mut self, board: B) -> usize {
+        self.set_root(board);
+        self.search();
+        let root = &self.nodes[0];
+        let best = root.children.iter().max_by_key(|&&c| self.nodes[c].visits);
+        match best {
+            Some(&child) => self.nodes[child].pos,
+            None => board.empty_positions()[0],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::game::Game;
+    use crate::game::mnk_state::MnkState;
+    use crate::game::player::AIPlayer;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_mcts_play_turn() {
+        let test_cases = vec![
+            ("XX OO    ", PlayerEnum::X, 2),
+            ("XX OO X  ", PlayerEnum::O, 5),
+            ("XX  O    ", PlayerEnum::O, 2),
+        ];
+        for (board_str, player_enum, expected_pos) in test_cases {
+            let mut mcts = MctsPlayer::seeded(1).iterations(2000);
+            mcts.assign_piece(player_enum);
+            let board = GameState::from_str(board_str).unwrap();
+            assert_eq!(mcts.play_turn(board), expected_pos, "{}", board_str);
+        }
+    }
+
+    #[test]
+    fn test_mcts_is_reproducible() {
+        let board = GameState::from_str("X        ").unwrap();
+        let moves = (0..2)
+            .map(|_| MctsPlayer::seeded(7).iterations(50).play_turn(board))
+            .collect::<Vec<_>>();
+        assert_eq!(moves[0], moves[1]);
+    }
+
+    #[test]
+    fn test_mcts_reuses_tree() {
+        let mut mcts = MctsPlayer::seeded(3).iterations(500);
+        let mut board = GameState::new();
+        let pos = mcts.play_turn(board);
+        board.auto_set(pos).unwrap();
+        board.auto_set(board.empty_positions()[0]).unwrap();
+        mcts.set_root(board);
+        assert!(mcts.root_visits() > 0);
+        assert_eq!(mcts.nodes[0].board, board);
+        assert_eq!(mcts.nodes[0].parent, None);
+        // Unrelated boards start a fresh tree
+        mcts.set_root(GameState::from_str("XOXOXO   ").unwrap());
+        assert_eq!(mcts.root_visits(), 0);
+    }
+
+    #[test]
+    fn test_mcts_draws_perfect_play() {
+        for mcts_first in [true, false] {
+            let mcts = Box::new(MctsPlayer::seeded(11).iterations(3000));
+            let ai = Box::new(AIPlayer::new());
+            let mut game = if mcts_first {
+                Game::new(mcts as Box<dyn Player>, ai as Box<dyn Player>)
+            } else {
+                Game::new(ai as Box<dyn Player>, mcts as Box<dyn Player>)
+            };
+            assert_eq!(game.play(), None);
+        }
+    }
+
+    #[test]
+    fn test_mcts_mnk() {
+        let board = MnkState::<5, 5, 4>::from_str(&format!("{:<25}", "XXX  OOO")).unwrap();
+        let mut mcts = MctsPlayer::seeded(5)
+            .time_budget(Duration::from_secs(5))
+            .iterations(3000);
+        assert_eq!(mcts.play_turn(board), 3);
+    }
+}
@@ -0,0 +1,353 @@
+// Donald Michie's MENACE (Machine Educable Noughts And Crosses Engine): a matchbox for
+// every position it can face, each holding beads for the moves it may play there. A move
+// is chosen by drawing a bead, and once the game is over the beads that were drawn are
+// rewarded or punished according to the result. Symmetric positions share one box,
+// stored in the simplified frame, so moves are drawn in that frame and then mapped back
+// to the real board.
+
+use crate::game::board::{Board, Variant};
+use crate::game::consts::BOARD_SIZE;
+use crate::game::game_state::{GameState, PlayerEnum};
+use crate::game::player::Player;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
+use std::convert::TryFrom;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::rc::Rc;
+use std::str::FromStr;
+
+const MAGIC: &str = "MENACE 1";
+
+/// Beads for every move of a box, indexed by position in the simplified frame.
+pub type Matchbox = [u32; BOARD_SIZE];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reinforcement {
+    pub win: i32,
+    pub draw: i32,
+    pub loss: i32,
+}
+
+// Michie's original rewards
+impl Default for Reinforcement {
+    fn default() -> Self {
+        Reinforcement {
+            win: 3,
+            draw: 1,
+            loss: -1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Matchboxes {
+    boxes: BTreeMap<u32, Matchbox>,
+}
+
+impl Matchboxes {
+    pub fn new() -> Matchboxes {
+        Matchboxes {
+            boxes: BTreeMap::new(),
+        }
+    }
+
+    // Fewer beads for later moves, as in the original: 4 for the first move down to 1
+    fn initial_beads(board: GameState) -> u32 {
+        let moves_made = BOARD_SIZE - board.empty_positions().len();
+        4u32.saturating_sub(moves_made as u32 / 2).max(1)
+    }
+
+    // Only one move out of each set that leads to symmetric positions gets beads
+    fn new_box(board: GameState) -> Matchbox {
+        let mut matchbox = [0; BOARD_SIZE];
+        let mut seen = HashSet::new();
+        for pos in board.empty_positions() {
+            let mut child = board;
+            child.auto_set(pos).unwrap();
+            if seen.insert(child.canonical().0) {
+                matchbox[pos] = Self::initial_beads(board);
+            }
+        }
+        matchbox
+    }
+
+    // The box for a simplified board, filling a new one if it has not been seen. Only the
+    // player calls this, always with the simplified board its beads are drawn for.
+    fn get_or_insert(&mut self, board: GameState) -> &mut Matchbox {
+        self.boxes
+            .entry(board.get_raw())
+            .or_insert_with(|| Self::new_box(board))
+    }
+
+    pub fn get(&self, board: GameState) -> Option<&Matchbox> {
+        self.boxes.get(&board.canonical().0.get_raw())
+    }
+
+    pub fn len(&self) -> usize {
+        self.boxes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    /// Writes one line per box: the raw simplified board and its bead counts.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let mut text = format!("{}\n", MAGIC);
+        for (raw, beads) in self.boxes.iter() {
+            let beads = beads.iter().map(|b| b.to_string()).collect::<Vec<_>>();
+            text += &format!("{} {}\n", raw, beads.join(" "));
+        }
+        fs::write(path, text)
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Matchboxes> {
+        let invalid = |line: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid matchbox line: {}", line),
+            )
+        };
+        let text = fs::read_to_string(path)?;
+        let mut lines = text.lines();
+        match lines.next() {
+            Some(MAGIC) => {}
+            line => return Err(invalid(line.unwrap_or(""))),
+        }
+        let mut matchboxes = Matchboxes::new();
+        for line in lines {
+            let numbers = line
+                .split_whitespace()
+                .map(u32::from_str)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| invalid(line))?;
+            if numbers.len() != BOARD_SIZE + 1 || GameState::try_from(numbers[0]).is_err() {
+                return Err(invalid(line));
+            }
+            let mut beads = [0; BOARD_SIZE];
+            beads.copy_from_slice(&numbers[1..]);
+            matchboxes.boxes.insert(numbers[0], beads);
+        }
+        Ok(matchboxes)
+    }
+}
+
+// Each box as its board with the bead count in every square that has beads
+impl fmt::Display for Matchboxes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (&raw, beads) in self.boxes.iter() {
+            let board = GameState::try_from(raw).unwrap();
+            let cells = (0..BOARD_SIZE)
+                .map(|pos| match board.get(pos).unwrap() {
+                    PlayerEnum::None if beads[pos] > 0 => beads[pos].to_string(),
+                    PlayerEnum::None => ".".to_string(),
+                    piece => piece.to_string(),
+                })
+                .collect::<Vec<_>>();
+            let width = cells.iter().map(|c| c.len()).max().unwrap_or(1);
+            writeln!(f, "{} ({} beads)", board, beads.iter().sum::<u32>())?;
+            for row in cells.chunks(3) {
+                let row = row
+                    .iter()
+                    .map(|c| format!("{:>width$}", c, width = width))
+                    .collect::<Vec<_>>();
+                writeln!(f, "  {}", row.join(" "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub struct MenacePlayer<R: Rng = StdRng> {
+    boxes: Rc<RefCell<Matchboxes>>,
+    reinforcement: Reinforcement,
+    rng: R,
+    player_enum: PlayerEnum,
+    // The simplified board and simplified position of every bead drawn this game
+    drawn: Vec<(GameState, usize)>,
+}
+
+impl MenacePlayer<StdRng> {
+    pub fn new(boxes: Rc<RefCell<Matchboxes>>) -> MenacePlayer<StdRng> {
+        Self::with_rng(boxes, StdRng::from_entropy())
+    }
+}
+
+impl<R: Rng> MenacePlayer<R> {
+    pub fn with_rng(boxes: Rc<RefCell<Matchboxes>>, rng: R) -> MenacePlayer<R> {
+        MenacePlayer {
+            boxes,
+            reinforcement: Reinforcement::default(),
+            rng,
+            player_enum: PlayerEnum::None,
+            drawn: vec![],
+        }
+    }
+
+    pub fn reinforcement(mut self, reinforcement: Reinforcement) -> Self {
+        self.reinforcement = reinforcement;
+        self
+    }
+}
+
+impl<R: Rng> Player for MenacePlayer<R> {
+    fn assign_piece(&mut self, player_enum: PlayerEnum) {
+        self.player_enum = player_enum
+    }
+
+    fn play_turn(&mut self, board: GameState) -> usize {
+        let (simplified, symmetry) = board.canonical();
+        let mut boxes = self.boxes.borrow_mut();
+        let matchbox = boxes.get_or_insert(simplified);
+        let total = matchbox.iter().sum::<u32>();
+        // An empty box means MENACE has given up on this position; any move will do
+        if total == 0 {
+            return board.empty_positions()[0];
+        }
+        let mut bead = self.rng.gen_range(0..total);
+        let mut pos = 0;
+        while bead >= matchbox[pos] {
+            bead -= matchbox[pos];
+            pos += 1;
+        }
+        self.drawn.push((simplified, pos));
+        // The bead is for the simplified board, so undo the transform to find the real square
+        symmetry.inverse().map_position(pos).unwrap()
+    }
+
+    fn on_game_end(&mut self, board: GameState) {
+        // A game that stopped before it was over was resigned or forfeited by the side
+        // whose turn it was
+        let winner = if board.is_over() {
+            Variant::Standard.winner(&board)
+        } else {
+            Some(board.get_turn().opponent())
+        };
+        let change = match winner {
+            Some(winner) if winner == self.player_enum => self.reinforcement.win,
+            Some(_) => self.reinforcement.loss,
+            None => self.reinforcement.draw,
+        };
+        let mut boxes = self.boxes.borrow_mut();
+        for (simplified, pos) in self.drawn.drain(..) {
+            let beads = &mut boxes.get_or_insert(simplified)[pos];
+            *beads = (*beads as i64 + change as i64).max(0) as u32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::game::Game;
+    use crate::game::player::RandomPlayer;
+
+    #[test]
+    fn test_new_box() {
+        let mut boxes = Matchboxes::new();
+        // Corner, edge and center are the only distinct first moves
+        let empty = *boxes.get_or_insert(GameState::new());
+        assert_eq!(empty.iter().filter(|&&b| b > 0).count(), 3);
+        assert!(empty.iter().all(|&b| b == 0 || b == 4));
+        let board = GameState::from_str("X   O    ").unwrap().canonical().0;
+        let matchbox = *boxes.get_or_insert(board);
+        assert_eq!(matchbox.iter().filter(|&&b| b > 0).count(), 4);
+        assert!(matchbox.iter().all(|&b| b == 0 || b == 3));
+    }
+
+    #[test]
+    fn test_moves_map_to_real_board() {
+        let boxes = Rc::new(RefCell::new(Matchboxes::new()));
+        let mut menace = MenacePlayer::with_rng(boxes.clone(), StdRng::seed_from_u64(1));
+        menace.assign_piece(PlayerEnum::O);
+        for board_str in [
+            "X        ",
+            "  X      ",
+            "      X  ",
+            "        X",
+            " X       ",
+        ] {
+            let board = GameState::from_str(board_str).unwrap();
+            for _ in 0..20 {
+                let pos = menace.play_turn(board);
+                assert!(board.is_empty(pos).unwrap(), "{} {}", board_str, pos);
+            }
+        }
+        // Every corner shares one box, and the edge has its own
+        assert_eq!(boxes.borrow().len(), 2);
+    }
+
+    #[test]
+    fn test_reinforcement() {
+        let boxes = Rc::new(RefCell::new(Matchboxes::new()));
+        let mut menace = MenacePlayer::with_rng(boxes.clone(), StdRng::seed_from_u64(2));
+        menace.assign_piece(PlayerEnum::X);
+        let pos = menace.play_turn(GameState::new());
+        let (simplified, drawn) = menace.drawn[0];
+        let before = boxes.borrow().get(simplified).unwrap()[drawn];
+        assert!(GameState::new().is_empty(pos).unwrap());
+        menace.on_game_end(GameState::from_str("XXXOO    ").unwrap());
+        assert_eq!(boxes.borrow().get(simplified).unwrap()[drawn], before + 3);
+        assert!(menace.drawn.is_empty());
+    }
+
+    #[test]
+    fn test_reinforces_resignations() {
+        let boxes = Rc::new(RefCell::new(Matchboxes::new()));
+        let mut menace = MenacePlayer::with_rng(boxes.clone(), StdRng::seed_from_u64(2));
+        menace.assign_piece(PlayerEnum::X);
+        // O resigned on its turn, so MENACE won
+        menace.play_turn(GameState::new());
+        let (simplified, drawn) = menace.drawn[0];
+        let before = boxes.borrow().get(simplified).unwrap()[drawn];
+        menace.on_game_end(GameState::from_str("X        ").unwrap());
+        assert_eq!(boxes.borrow().get(simplified).unwrap()[drawn], before + 3);
+        // MENACE forfeited on its own turn, so it lost
+        menace.play_turn(GameState::new());
+        let (simplified, drawn) = menace.drawn[0];
+        let before = boxes.borrow().get(simplified).unwrap()[drawn];
+        menace.on_game_end(GameState::from_str("X   O    ").unwrap());
+        assert_eq!(boxes.borrow().get(simplified).unwrap()[drawn], before - 1);
+    }
+
+    #[test]
+    fn test_learns_against_random() {
+        let boxes = Rc::new(RefCell::new(Matchboxes::new()));
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut losses = vec![];
+        for _ in 0..4 {
+            let mut lost = 0;
+            for _ in 0..500 {
+                let menace =
+                    MenacePlayer::with_rng(boxes.clone(), StdRng::seed_from_u64(rng.gen()));
+                let random = RandomPlayer::seeded(rng.gen());
+                let mut game = Game::new(Box::new(menace), Box::new(random));
+                if game.play() == Some(PlayerEnum::O) {
+                    lost += 1;
+                }
+            }
+            losses.push(lost);
+        }
+        assert!(losses[3] < losses[0], "{:?}", losses);
+    }
+
+    #[test]
+    fn test_save_load_display() {
+        let path = std::env::temp_dir().join(format!("ttt-menace-{}.txt", std::process::id()));
+        let mut boxes = Matchboxes::new();
+        boxes.get_or_insert(GameState::new());
+        boxes.get_or_insert(GameState::from_str("X   O    ").unwrap().canonical().0);
+        boxes.save(&path).unwrap();
+        assert_eq!(Matchboxes::load(&path).unwrap(), boxes);
+        fs::write(&path, "MENACE 1\n0 1 2\n").unwrap();
+        assert!(Matchboxes::load(&path).is_err());
+        fs::remove_file(&path).unwrap();
+        let table = boxes.to_string();
+        assert!(table.starts_with("          (12 beads)\n  4 4 .\n  . 4 .\n  . . .\n"));
+    }
+}
@@ -0,0 +1,489 @@
+use crate::game::board::{Board, Variant};
+use crate::game::consts::PLAYER_X;
+use crate::game::errors::TicTacToeError;
+use crate::game::game_state::PlayerEnum;
+use crate::game::zobrist;
+use itertools::Itertools;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+// Two bits for each of 64 cells fill a u128
+const MAX_CELLS: usize = 64;
+
+// Every cell can start at most one line in each of the four directions
+const MAX_WIN_MASKS: usize = 4 * MAX_CELLS;
+
+const fn x_bit_mask(cells: usize) -> u128 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < cells {
+        mask |= (PLAYER_X as u128) << (i * 2);
+        i += 1;
+    }
+    mask
+}
+
+const fn win_masks(width: usize, height: usize, k: usize) -> ([u128; MAX_WIN_MASKS], usize) {
+    let directions: [(isize, isize); 4] = [(0, 1), (1, 0), (1, 1), (1, -1)];
+    let mut masks = [0; MAX_WIN_MASKS];
+    let mut count = 0;
+    let mut d = 0;
+    while d < directions.len() {
+        let (d_row, d_col) = directions[d];
+        let mut row = 0;
+        while row < height {
+            let mut col = 0;
+            while col < width {
+                let end_row = row as isize + d_row * (k as isize - 1);
+                let end_col = col as isize + d_col * (k as isize - 1);
+                if end_row >= 0
+                    && end_row < height as isize
+                    && end_col >= 0
+                    && end_col < width as isize
+                {
+                    let mut mask = 0;
+                    let mut i = 0;
+                    while i < k {
+                        let cell_row = (row as isize + d_row * i as isize) as usize;
+                        let cell_col = (col as isize + d_col * i as isize) as usize;
+                        mask |= (PLAYER_X as u128) << ((cell_row * width + cell_col) * 2);
+                        i += 1;
+                    }
+                    masks[count] = mask;
+                    count += 1;
+                }
+                col += 1;
+            }
+            row += 1;
+        }
+        d += 1;
+    }
+    (masks, count)
+}
+
+/// A `width` x `height` board won by `k` in a row, packing two bits per cell into a `u128`
+/// in the same encoding as `GameState`. Boards can have up to 64 cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MnkState<const W: usize, const H: usize, const K: usize> {
+    raw: u128,
+    // The Zobrist key of the pieces, kept up to date by `set`
+    key: u64,
+}
+
+impl<const W: usize, const H: usize, const K: usize> MnkState<W, H, K> {
+    pub const SIZE: usize = W * H;
+    const VALID_DIMENSIONS: () = assert!(
+        W * H > 0 && W * H <= MAX_CELLS && K > 0 && K <= W && K <= H,
+        "MnkState needs at most 64 cells and a win length that fits the board"
+    );
+    const X_BIT_MASK: u128 = x_bit_mask(W * H);
+    const WIN_MASKS: ([u128; MAX_WIN_MASKS], usize) = win_masks(W, H, K);
+
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID_DIMENSIONS;
+        MnkState { raw: 0, key: 0 }
+    }
+
+    fn from_raw(raw: u128) -> Self {
+        let mut board = MnkState { raw, key: 0 };
+        board.key = zobrist::board_key((0..Self::SIZE).map(|pos| board.cell(pos)));
+        board
+    }
+
+    // The two bit value at a position known to be in bounds
+    fn cell(&self, pos: usize) -> u32 {
+        (self.raw >> (pos * 2) & 3) as u32
+    }
+
+    pub fn set(&mut self, pos: usize, value: PlayerEnum) -> Result<(), TicTacToeError> {
+        if pos >= Self::SIZE {
+            return Err(TicTacToeError::OutOfBounds);
+        }
+        let old = self.cell(pos);
+        self.raw &= !(3 << (pos * 2));
+        self.raw |= (value as u128) << (pos * 2);
+        self.key ^= zobrist::piece_key(pos, old) ^ zobrist::piece_key(pos, value as u32);
+        Ok(())
+    }
+
+    pub fn get(&self, pos: usize) -> Result<PlayerEnum, TicTacToeError> {
+        if pos >= Self::SIZE {
+            return Err(TicTacToeError::OutOfBounds);
+        }
+        PlayerEnum::try_from((self.raw >> (pos * 2) & 3) as u32)
+    }
+
+    pub fn get_raw(&self) -> u128 {
+        self.raw
+    }
+
+    /// A hash of the pieces on the board, updated incrementally as pieces are set.
+    pub fn zobrist_key(&self) -> u64 {
+        self.key
+    }
+
+    pub fn inverted(&self) -> Self {
+        let mut piece_mask =
+            ((self.raw & (Self::X_BIT_MASK << 1)) >> 1) | (self.raw & Self::X_BIT_MASK);
+        piece_mask |= piece_mask << 1;
+        MnkState::from_raw(!self.raw & piece_mask)
+    }
+
+    pub fn win_masks() -> &'static [u128] {
+        &Self::WIN_MASKS.0[..Self::WIN_MASKS.1]
+    }
+
+    pub fn is_winner(&self, player: PlayerEnum) -> bool {
+        let raw_board = match player {
+            PlayerEnum::X => self.raw,
+            PlayerEnum::O => self.inverted().raw,
+            PlayerEnum::None => return false,
+        };
+        Self::win_masks().iter().any(|&mask| mask & !raw_board == 0)
+    }
+
+    /// The player who has won and every line of K they completed, in position order.
+    pub fn winner(&self) -> Option<(PlayerEnum, Vec<Vec<usize>>)> {
+        [PlayerEnum::X, PlayerEnum::O].iter().find_map(|&player| {
+            let raw_board = match player {
+                PlayerEnum::X => self.raw,
+                _ => self.inverted().raw,
+            };
+            let lines = Self::win_masks()
+                .iter()
+                .filter(|&&mask| mask & !raw_board == 0)
+                .map(|&mask| {
+                    (0..Self::SIZE)
+                        .filter(|&pos| mask >> (pos * 2) & 1 == 1)
+                        .collect_vec()
+                })
+                .sorted()
+                .collect_vec();
+            if lines.is_empty() {
+                None
+            } else {
+                Some((player, lines))
+            }
+        })
+    }
+
+    pub fn is_tie(&self) -> bool {
+        let filled = (self.raw | self.raw >> 1) & Self::X_BIT_MASK;
+        filled == Self::X_BIT_MASK
+            && !(self.is_winner(PlayerEnum::X) || self.is_winner(PlayerEnum::O))
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.is_valid_in(Variant::Standard)
+    }
+
+    /// Like `is_valid`, but when `variant` lets the mover choose the piece any number of
+    /// each piece can be on the board, and in Notakto only X can be.
+    pub fn is_valid_in(&self, variant: Variant) -> bool {
+        // No cell with both bits set, a legal piece count and at most one winner
+        if self.raw & (self.raw >> 1) & Self::X_BIT_MASK != 0 || self.raw >> (Self::SIZE * 2) != 0 {
+            return false;
+        }
+        let x_count = (self.raw & Self::X_BIT_MASK).count_ones();
+        let o_count = (self.raw & (Self::X_BIT_MASK << 1)).count_ones();
+        let counts_valid = match variant {
+            Variant::Notakto => o_count == 0,
+            _ if variant.chooses_piece() => true,
+            _ => x_count == o_count || x_count == o_count + 1,
+        };
+        counts_valid && !(self.is_winner(PlayerEnum::X) && self.is_winner(PlayerEnum::O))
+    }
+
+    /// Rotates clockwise after an optional top to bottom flip, like `GameState::transform`.
+    /// Odd rotations are only possible on square boards and panic otherwise.
+    pub fn transform(&self, rotations: i32, flip: bool) -> Self {
+        let rotations = rotations.rem_euclid(4);
+        assert!(
+            W == H || rotations % 2 == 0,
+            "only square boards can be rotated by 90 degrees"
+        );
+        let mut transformed = 0;
+        for pos in 0..Self::SIZE {
+            let (row, col) = if flip {
+                (H - 1 - pos / W, pos % W)
+            } else {
+                (pos / W, pos % W)
+            };
+            let (row, col) = match rotations {
+                0 => (row, col),
+                1 => (col, H - 1 - row),
+                2 => (H - 1 - row, W - 1 - col),
+                _ => (W - 1 - col, row),
+            };
+            transformed |= (self.raw >> (pos * 2) & 3) << ((row * W + col) * 2);
+        }
+        MnkState::from_raw(transformed)
+    }
+
+    pub fn as_vec(&self) -> Vec<String> {
+        (0..Self::SIZE)
+            .map(|i| self.get(i).unwrap().to_string())
+            .collect()
+    }
+
+    fn grid(cells: Vec<String>, cell_width: usize) -> String {
+        let separator = vec!["-".repeat(cell_width + 2); W].join("+");
+        cells
+            .chunks(W)
+            .map(|row| row.iter().map(|c| format!(" {} ", c)).join("|"))
+            .join(format!("\n{}\n", separator).as_str())
+    }
+
+    // Pieces on a winning line are shown in green, like `GameState`
+    fn display_cells(&self, numbered: bool, cell_width: usize) -> Vec<String> {
+        let winning_cells = self
+            .winner()
+            .map(|(_, lines)| lines.concat())
+            .unwrap_or_default();
+        self.as_vec()
+            .into_iter()
+            .enumerate()
+            .map(|(i, s)| {
+                let padding = format!("{:>width$}", "", width = cell_width - 1);
+                if s == " " && numbered {
+                    format!("{:>width$}", i + 1, width = cell_width)
+                } else if winning_cells.contains(&i) {
+                    padding + &format!("\x1b[92m{}\x1b[0m", s)
+                } else if numbered {
+                    padding + &format!("\x1b[93m{}\x1b[0m", s)
+                } else {
+                    padding + &s
+                }
+            })
+            .collect_vec()
+    }
+
+    pub fn get_display(&self) -> String {
+        Self::grid(self.display_cells(false, 1), 1)
+    }
+
+    pub fn get_numbered_display(&self) -> String {
+        let cell_width = Self::SIZE.to_string().len();
+        Self::grid(self.display_cells(true, cell_width), cell_width)
+    }
+}
+
+impl<const W: usize, const H: usize, const K: usize> Default for MnkState<W, H, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const W: usize, const H: usize, const K: usize> Board for MnkState<W, H, K> {
+    type Raw = u128;
+    const SIZE: usize = W * H;
+
+    fn new() -> Self {
+        MnkState::new()
+    }
+
+    fn set(&mut self, pos: usize, value: PlayerEnum) -> Result<(), TicTacToeError> {
+        MnkState::set(self, pos, value)
+    }
+
+    fn get(&self, pos: usize) -> Result<PlayerEnum, TicTacToeError> {
+        MnkState::get(self, pos)
+    }
+
+    fn get_raw(&self) -> u128 {
+        self.raw
+    }
+
+    fn zobrist_key(&self) -> u64 {
+        self.key
+    }
+
+    fn is_winner(&self, player: PlayerEnum) -> bool {
+        MnkState::is_winner(self, player)
+    }
+
+    fn is_tie(&self) -> bool {
+        MnkState::is_tie(self)
+    }
+
+    fn transform(&self, rotations: i32, flip: bool) -> Self {
+        MnkState::transform(self, rotations, flip)
+    }
+
+    fn get_display(&self) -> String {
+        MnkState::get_display(self)
+    }
+
+    fn get_numbered_display(&self) -> String {
+        MnkState::get_numbered_display(self)
+    }
+
+    fn symmetries(&self) -> Vec<Self> {
+        if W == H {
+            return (0..8).map(|i| self.transform(i % 4, i > 3)).collect();
+        }
+        // Rectangles lose the 90 degree rotations
+        (0..4).map(|i| self.transform(i % 2 * 2, i > 1)).collect()
+    }
+
+    fn get_turn(&self) -> PlayerEnum {
+        let x_count = (self.raw & Self::X_BIT_MASK).count_ones();
+        let o_count = (self.raw & (Self::X_BIT_MASK << 1)).count_ones();
+        if x_count == o_count {
+            PlayerEnum::X
+        } else {
+            PlayerEnum::O
+        }
+    }
+}
+
+impl<const W: usize, const H: usize, const K: usize> fmt::Display for MnkState<W, H, K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_vec().join(""))
+    }
+}
+
+impl<const W: usize, const H: usize, const K: usize> FromStr for MnkState<W, H, K> {
+    type Err = TicTacToeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.chars().count() != Self::SIZE {
+            return Err(TicTacToeError::InvalidBoard);
+        }
+        let mut board = MnkState::new();
+        for (i, ch) in value.chars().enumerate() {
+            board.set(i, PlayerEnum::from_str(ch.to_string().as_str())?)?;
+        }
+        if !board.is_valid() {
+            return Err(TicTacToeError::InvalidBoard);
+        }
+        Ok(board)
+    }
+}
+
+impl<const W: usize, const H: usize, const K: usize> TryFrom<u128> for MnkState<W, H, K> {
+    type Error = TicTacToeError;
+
+    fn try_from(value: u128) -> Result<Self, Self::Error> {
+        let board = MnkState::from_raw(value);
+        if !board.is_valid() {
+            return Err(TicTacToeError::InvalidBoard);
+        }
+        Ok(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::game_state::GameState;
+
+    #[test]
+    fn test_win_mask_counts() {
+        assert_eq!(MnkState::<3, 3, 3>::win_masks().len(), 8);
+        assert_eq!(MnkState::<4, 4, 3>::win_masks().len(), 24);
+        assert_eq!(MnkState::<4, 4, 4>::win_masks().len(), 10);
+        assert_eq!(MnkState::<5, 5, 4>::win_masks().len(), 28);
+        assert_eq!(MnkState::<7, 7, 5>::win_masks().len(), 60);
+        assert_eq!(MnkState::<4, 3, 3>::win_masks().len(), 14);
+    }
+
+    #[test]
+    fn test_matches_game_state() {
+        let boards = vec!["X O XOXO ", "XXXOO O  ", "XO    O X", "XXOOO XOX"];
+        for board_str in boards {
+            let board = MnkState::<3, 3, 3>::from_str(board_str).unwrap();
+            let game_state = GameState::from_str(board_str).unwrap();
+            assert_eq!(board.to_string(), game_state.to_string());
+            assert_eq!(board.get_display(), game_state.get_display());
+            assert_eq!(
+                board.get_numbered_display(),
+                game_state.get_numbered_display()
+            );
+            assert_eq!(board.empty_positions(), game_state.empty_positions());
+            assert_eq!(board.get_turn(), game_state.get_turn());
+            assert_eq!(
+                board.inverted().to_string(),
+                game_state.inverted().to_string()
+            );
+            assert_eq!(
+                board.winner().map(|(p, lines)| (p, lines.concat())),
+                game_state.winner().map(|(p, lines)| (p, lines.concat()))
+            );
+            for player in [PlayerEnum::X, PlayerEnum::O] {
+                assert_eq!(board.is_winner(player), game_state.is_winner(player));
+            }
+            assert_eq!(board.is_tie(), game_state.is_tie());
+            for i in 0..8 {
+                assert_eq!(
+                    board.transform(i % 4, i > 3).to_string(),
+                    game_state.transform(i % 4, i > 3).to_string()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_winner() {
+        let test_cases = vec![
+            ("XXX OO O        ", PlayerEnum::X, true),
+            ("XXX OO O        ", PlayerEnum::O, false),
+            ("XO  OX  X  O   X", PlayerEnum::X, false),
+            ("O  O X    X O  X", PlayerEnum::X, true),
+            ("X  OXO OX       ", PlayerEnum::O, false),
+            ("   X  XO XOO    ", PlayerEnum::X, true),
+        ];
+        for (board_str, player, is_winner) in test_cases {
+            let board = MnkState::<4, 4, 3>::from_str(board_str).unwrap();
+            assert_eq!(board.is_winner(player), is_winner, "{}", board_str);
+        }
+        let board = MnkState::<4, 4, 4>::from_str("XXX OOO         ").unwrap();
+        assert!(!board.is_winner(PlayerEnum::X));
+    }
+
+    #[test]
+    fn test_transform() {
+        let board = MnkState::<4, 4, 4>::from_str("XO              ").unwrap();
+        assert_eq!(board.transform(1, false).to_string(), "   X   O        ");
+        assert_eq!(board.transform(0, true).to_string(), "            XO  ");
+        assert_eq!(board.symmetries().len(), 8);
+        let wide = MnkState::<4, 3, 3>::from_str("XO          ").unwrap();
+        assert_eq!(wide.transform(2, false).to_string(), "          OX");
+        assert_eq!(wide.symmetries().len(), 4);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert!(MnkState::<4, 4, 3>::from_str("XO              ").is_ok());
+        assert!(MnkState::<4, 4, 3>::from_str("XO             ").is_err());
+        assert!(MnkState::<4, 4, 3>::from_str("XX              ").is_err());
+        assert!(MnkState::<4, 4, 3>::from_str("XXX OOO         ").is_err());
+    }
+
+    #[test]
+    fn test_zobrist_key() {
+        let mut board = MnkState::<5, 5, 4>::new();
+        board.set(24, PlayerEnum::X).unwrap();
+        board.set(3, PlayerEnum::O).unwrap();
+        let rebuilt = MnkState::<5, 5, 4>::try_from(board.get_raw()).unwrap();
+        assert_eq!(board.zobrist_key(), rebuilt.zobrist_key());
+        board.set(3, PlayerEnum::None).unwrap();
+        board.set(24, PlayerEnum::None).unwrap();
+        assert_eq!(
+            board.zobrist_key(),
+            MnkState::<5, 5, 4>::new().zobrist_key()
+        );
+    }
+
+    #[test]
+    fn test_numbered_display() {
+        let board = MnkState::<4, 4, 3>::from_str("X               ").unwrap();
+        let lines = board.get_numbered_display();
+        let lines = lines.lines().collect_vec();
+        assert_eq!(lines[0], "  \x1b[93mX\x1b[0m |  2 |  3 |  4 ");
+        assert_eq!(lines[1], "----+----+----+----");
+        assert_eq!(lines[6], " 13 | 14 | 15 | 16 ");
+    }
+}
@@ -1,6 +1,25 @@
+pub mod board;
 pub mod board_iterator;
 pub mod consts;
+pub mod cube_consts;
+pub mod cube_state;
+pub mod engine;
 pub mod errors;
+#[allow(clippy::module_inception)]
 pub mod game;
 pub mod game_state;
+pub mod heuristic;
+pub mod learning;
+pub mod mcts;
+pub mod menace;
+pub mod mnk_state;
+pub mod notakto;
 pub mod player;
+pub mod record;
+pub mod solver;
+pub mod symmetry;
+pub mod tablebase_file;
+pub mod tournament;
+pub mod transposition;
+pub mod ultimate_state;
+pub mod zobrist;
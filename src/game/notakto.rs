@@ -0,0 +1,470 @@
+use crate::game::board::Board;
+use crate::game::consts::BOARD_SIZE;
+use crate::game::errors::TicTacToeError;
+use crate::game::game_state::{GameState, PlayerEnum};
+use crate::game::solver::MoveLookup;
+use crate::game::symmetry::Symmetry;
+use crate::game::zobrist;
+use itertools::Itertools;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Notakto: both players place X on `N` 3x3 boards, and a board is dead once it has a
+/// line. Only live boards can be played on, and whoever kills the last board loses, so
+/// it is played with `Variant::Notakto`.
+///
+/// Positions are numbered board by board, row by row within each board, and
+/// `is_winner(PlayerEnum::X)` is true once every board is dead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NotaktoState<const N: usize> {
+    boards: [GameState; N],
+    // The Zobrist key of the pieces, kept up to date by `set`
+    key: u64,
+}
+
+impl<const N: usize> NotaktoState<N> {
+    pub const SIZE: usize = N * BOARD_SIZE;
+    const VALID_SIZE: () = assert!(N >= 1 && N <= 9, "Notakto is played on 1 to 9 boards");
+
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID_SIZE;
+        NotaktoState {
+            boards: [GameState::new(); N],
+            key: 0,
+        }
+    }
+
+    fn from_boards(boards: [GameState; N]) -> Self {
+        let mut state = NotaktoState { boards, key: 0 };
+        state.key = zobrist::board_key((0..Self::SIZE).map(|pos| state.get(pos).unwrap() as u32));
+        state
+    }
+
+    /// The board and the cell within it of a position.
+    pub fn split(pos: usize) -> (usize, usize) {
+        (pos / BOARD_SIZE, pos % BOARD_SIZE)
+    }
+
+    /// The position of a cell of a board.
+    pub fn join(board: usize, cell: usize) -> usize {
+        board * BOARD_SIZE + cell
+    }
+
+    pub fn is_dead(board: &GameState) -> bool {
+        board.is_winner(PlayerEnum::X)
+    }
+
+    pub fn board(&self, index: usize) -> Result<GameState, TicTacToeError> {
+        self.boards
+            .get(index)
+            .copied()
+            .ok_or(TicTacToeError::OutOfBounds)
+    }
+
+    /// The boards that can still be played on.
+    pub fn live_boards(&self) -> Vec<usize> {
+        (0..N)
+            .filter(|&i| !Self::is_dead(&self.boards[i]))
+            .collect()
+    }
+
+    pub fn legal_moves(&self) -> Vec<usize> {
+        self.live_boards()
+            .into_iter()
+            .flat_map(|board| {
+                self.boards[board]
+                    .empty_positions()
+                    .into_iter()
+                    .map(move |cell| Self::join(board, cell))
+            })
+            .collect()
+    }
+
+    /// Places an X on an empty cell of a live board.
+    pub fn set(&mut self, pos: usize, value: PlayerEnum) -> Result<(), TicTacToeError> {
+        if pos >= Self::SIZE {
+            return Err(TicTacToeError::OutOfBounds);
+        }
+        let (board, cell) = Self::split(pos);
+        if value != PlayerEnum::X
+            || !self.boards[board].is_empty(cell)?
+            || Self::is_dead(&self.boards[board])
+        {
+            return Err(TicTacToeError::IllegalMove);
+        }
+        self.boards[board].set(cell, value)?;
+        self.key ^= zobrist::piece_key(pos, value as u32);
+        Ok(())
+    }
+
+    pub fn get(&self, pos: usize) -> Result<PlayerEnum, TicTacToeError> {
+        if pos >= Self::SIZE {
+            return Err(TicTacToeError::OutOfBounds);
+        }
+        let (board, cell) = Self::split(pos);
+        self.boards[board].get(cell)
+    }
+
+    pub fn get_raw(&self) -> [u32; N] {
+        let mut raw = [0; N];
+        for (r, board) in raw.iter_mut().zip(self.boards.iter()) {
+            *r = board.get_raw();
+        }
+        raw
+    }
+
+    pub fn zobrist_key(&self) -> u64 {
+        self.key
+    }
+
+    /// Whether every board is dead. Nobody places O, so O never has a line.
+    pub fn is_winner(&self, player: PlayerEnum) -> bool {
+        player == PlayerEnum::X && self.boards.iter().all(Self::is_dead)
+    }
+
+    /// A full 3x3 board of X always has a line, so Notakto can't be tied.
+    pub fn is_tie(&self) -> bool {
+        false
+    }
+
+    /// Applies the same symmetry to every board.
+    pub fn transform(&self, rotations: i32, flip: bool) -> Self {
+        let symmetry = Symmetry::new(rotations, flip);
+        let mut boards = self.boards;
+        for board in boards.iter_mut() {
+            *board = board.apply(symmetry);
+        }
+        Self::from_boards(boards)
+    }
+
+    // Pieces on a dead board's lines are shown in red, and empty cells of dead boards are
+    // left blank when numbered
+    fn display_cells(&self, numbered: bool, cell_width: usize) -> Vec<String> {
+        (0..Self::SIZE)
+            .map(|pos| {
+                let (board, cell) = Self::split(pos);
+                let piece = self.boards[board].get(cell).unwrap();
+                let padding = " ".repeat(cell_width - 1);
+                let dead = self.boards[board]
+                    .winner()
+                    .is_some_and(|(_, lines)| lines.concat().contains(&cell));
+                let live = !Self::is_dead(&self.boards[board]);
+                if piece == PlayerEnum::None && numbered && live {
+                    format!("{:>width$}", pos + 1, width = cell_width)
+                } else if dead {
+                    padding + &format!("\x1b[91m{}\x1b[0m", piece)
+                } else {
+                    padding + &piece.to_string()
+                }
+            })
+            .collect()
+    }
+
+    // Boards are shown side by side
+    fn side_by_side(cells: Vec<String>, cell_width: usize) -> String {
+        let boards = cells.chunks(BOARD_SIZE).collect_vec();
+        let divider = vec!["-".repeat(cell_width + 2); 3].join("+");
+        (0..3)
+            .map(|row| {
+                let line = boards
+                    .iter()
+                    .map(|cells| {
+                        cells[row * 3..row * 3 + 3]
+                            .iter()
+                            .map(|c| format!(" {} ", c))
+                            .join("|")
+                    })
+                    .join("    ");
+                match row {
+                    0 => line,
+                    _ => format!("{}\n{}", vec![divider.as_str(); N].join("    "), line),
+                }
+            })
+            .join("\n")
+    }
+
+    pub fn get_display(&self) -> String {
+        Self::side_by_side(self.display_cells(false, 1), 1)
+    }
+
+    pub fn get_numbered_display(&self) -> String {
+        Self::side_by_side(self.display_cells(true, 2), 2)
+    }
+}
+
+impl<const N: usize> Default for NotaktoState<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Board for NotaktoState<N> {
+    type Raw = [u32; N];
+    const SIZE: usize = NotaktoState::<N>::SIZE;
+
+    fn new() -> Self {
+        NotaktoState::new()
+    }
+
+    fn set(&mut self, pos: usize, value: PlayerEnum) -> Result<(), TicTacToeError> {
+        NotaktoState::set(self, pos, value)
+    }
+
+    fn get(&self, pos: usize) -> Result<PlayerEnum, TicTacToeError> {
+        NotaktoState::get(self, pos)
+    }
+
+    fn get_raw(&self) -> Self::Raw {
+        NotaktoState::get_raw(self)
+    }
+
+    fn zobrist_key(&self) -> u64 {
+        self.key
+    }
+
+    fn is_winner(&self, player: PlayerEnum) -> bool {
+        NotaktoState::is_winner(self, player)
+    }
+
+    fn is_tie(&self) -> bool {
+        NotaktoState::is_tie(self)
+    }
+
+    fn transform(&self, rotations: i32, flip: bool) -> Self {
+        NotaktoState::transform(self, rotations, flip)
+    }
+
+    fn get_display(&self) -> String {
+        NotaktoState::get_display(self)
+    }
+
+    fn get_numbered_display(&self) -> String {
+        NotaktoState::get_numbered_display(self)
+    }
+
+    // Both players place X
+    fn get_turn(&self) -> PlayerEnum {
+        PlayerEnum::X
+    }
+
+    fn empty_positions(&self) -> Vec<usize> {
+        self.legal_moves()
+    }
+}
+
+/// The cells board by board.
+impl<const N: usize> fmt::Display for NotaktoState<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for pos in 0..Self::SIZE {
+            write!(f, "{}", self.get(pos).unwrap())?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> FromStr for NotaktoState<N> {
+    type Err = TicTacToeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.chars().count() != Self::SIZE {
+            return Err(TicTacToeError::InvalidBoard);
+        }
+        let mut boards = [GameState::new(); N];
+        for (pos, ch) in value.chars().enumerate() {
+            let (board, cell) = Self::split(pos);
+            match PlayerEnum::from_str(ch.to_string().as_str())? {
+                PlayerEnum::O => return Err(TicTacToeError::InvalidBoard),
+                piece => boards[board].set(cell, piece)?,
+            }
+        }
+        Ok(Self::from_boards(boards))
+    }
+}
+
+/// Perfect Notakto play by exhaustive search. Dead boards no longer matter and live boards
+/// only matter up to symmetry and order, so positions are searched as their sorted
+/// canonical live boards, which keeps a few boards within reach. Results are kept between
+/// moves.
+#[derive(Debug, Default)]
+pub struct NotaktoSolver {
+    // Whether the player to move wins, by position
+    wins: RefCell<HashMap<Vec<GameState>, bool>>,
+}
+
+impl NotaktoSolver {
+    pub fn new() -> NotaktoSolver {
+        NotaktoSolver::default()
+    }
+
+    fn position(boards: impl Iterator<Item = GameState>) -> Vec<GameState> {
+        boards
+            .filter(|board| !NotaktoState::<1>::is_dead(board))
+            .map(|board| board.canonical().0)
+            .sorted_by_key(GameState::get_raw)
+            .collect()
+    }
+
+    fn solve(&self, position: Vec<GameState>) -> bool {
+        // The previous move killed the last board
+        if position.is_empty() {
+            return true;
+        }
+        if let Some(&wins) = self.wins.borrow().get(&position) {
+            return wins;
+        }
+        // Equal boards give the same children
+        let wins = (0..position.len())
+            .filter(|&i| i == 0 || position[i] != position[i - 1])
+            .any(|i| {
+                position[i].empty_positions().into_iter().any(|cell| {
+                    let mut children = position.clone();
+                    children[i].set(cell, PlayerEnum::X).unwrap();
+                    !self.solve(Self::position(children.into_iter()))
+                })
+            });
+        self.wins.borrow_mut().insert(position, wins);
+        wins
+    }
+
+    /// Whether the player to move in `state` wins with perfect play.
+    pub fn is_winning<const N: usize>(&self, state: &NotaktoState<N>) -> bool {
+        self.solve(Self::position(state.boards.iter().copied()))
+    }
+
+    /// A move that leaves the opponent lost. When there is none every move loses, so one
+    /// that keeps the game going is picked to give the opponent a chance to go wrong.
+    pub fn best_move<const N: usize>(&self, state: NotaktoState<N>) -> Option<usize> {
+        let moves = state.legal_moves();
+        let child = |pos: usize| {
+            let mut child = state;
+            child.set(pos, PlayerEnum::X).unwrap();
+            child
+        };
+        moves
+            .iter()
+            .copied()
+            .find(|&pos| !self.is_winning(&child(pos)))
+            .or_else(|| moves.iter().copied().find(|&pos| !child(pos).is_over()))
+            .or_else(|| moves.first().copied())
+    }
+
+    /// The number of positions solved so far.
+    pub fn len(&self) -> usize {
+        self.wins.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.wins.borrow().is_empty()
+    }
+}
+
+impl<const N: usize> MoveLookup<NotaktoState<N>> for NotaktoSolver {
+    fn best_move(&self, board: NotaktoState<N>) -> Option<usize> {
+        NotaktoSolver::best_move(self, board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::board::Variant;
+    use crate::game::game::Game;
+    use crate::game::player::{AIPlayer, RandomPlayer};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_dead_boards() {
+        let mut state = NotaktoState::<2>::new();
+        assert_eq!(state.legal_moves().len(), 18);
+        for cell in [0, 1, 2].iter() {
+            state
+                .set(NotaktoState::<2>::join(0, *cell), PlayerEnum::X)
+                .unwrap();
+        }
+        assert_eq!(state.live_boards(), vec![1]);
+        assert!(state.legal_moves().iter().all(|&pos| pos >= 9));
+        assert!(matches!(
+            state.set(4, PlayerEnum::X),
+            Err(TicTacToeError::IllegalMove)
+        ));
+        assert!(matches!(
+            state.set(13, PlayerEnum::O),
+            Err(TicTacToeError::IllegalMove)
+        ));
+        assert!(!state.is_over());
+        for cell in [2, 4, 6].iter() {
+            state
+                .set(NotaktoState::<2>::join(1, *cell), PlayerEnum::X)
+                .unwrap();
+        }
+        assert!(state.is_winner(PlayerEnum::X));
+        assert!(!state.is_winner(PlayerEnum::O));
+        // Six pieces have been placed, so O killed the last board and X wins
+        assert_eq!(Variant::Notakto.turn(&state), PlayerEnum::X);
+        assert_eq!(Variant::Notakto.winner(&state), Some(PlayerEnum::X));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let state = NotaktoState::<2>::from_str("X   X    X X      ").unwrap();
+        assert_eq!(state.to_string(), "X   X    X X      ");
+        assert_eq!(state.board(1).unwrap().to_string(), "X X      ");
+        assert_eq!(
+            state.zobrist_key(),
+            NotaktoState::<2>::from_str(&state.to_string())
+                .unwrap()
+                .zobrist_key()
+        );
+        assert!(NotaktoState::<2>::from_str("X   O    X X      ").is_err());
+        assert!(NotaktoState::<1>::from_str("X   X    X X      ").is_err());
+        let transformed = state.transform(1, false);
+        assert_eq!(
+            transformed.board(1).unwrap(),
+            state.board(1).unwrap().transform(1, false)
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let solver = NotaktoSolver::new();
+        // The first player wins on one board by taking the center, and loses on two
+        assert!(solver.is_winning(&NotaktoState::<1>::new()));
+        assert_eq!(solver.best_move(NotaktoState::<1>::new()), Some(4));
+        assert!(!solver.is_winning(&NotaktoState::<2>::new()));
+        assert!(!solver.is_empty());
+        // Boards only matter up to symmetry and order
+        let state = NotaktoState::<2>::from_str("        X X       ").unwrap();
+        let wins = solver.is_winning(&state);
+        let solved = solver.len();
+        let equivalent = NotaktoState::<2>::from_str("X          X      ").unwrap();
+        assert_eq!(solver.is_winning(&equivalent), wins);
+        assert_eq!(solver.len(), solved);
+        // Every move loses, but the solver still plays one that doesn't end the game
+        let lost = NotaktoState::<1>::from_str("X X X    ").unwrap();
+        assert!(!solver.is_winning(&lost));
+        assert!(solver
+            .best_move(lost)
+            .is_some_and(|pos| [3, 5, 7].contains(&pos)));
+    }
+
+    #[test]
+    fn test_perfect_play() {
+        let solver = Rc::new(NotaktoSolver::new());
+        for seed in 0..5 {
+            let ai = AIPlayer::with_tablebase(solver.clone()).variant(Variant::Notakto);
+            let random = RandomPlayer::seeded(seed).variant(Variant::Notakto);
+            let mut game = Game::<NotaktoState<1>>::new(Box::new(ai), Box::new(random))
+                .with_variant(Variant::Notakto);
+            assert_eq!(game.play(), Some(PlayerEnum::X));
+
+            let ai = AIPlayer::with_tablebase(solver.clone()).variant(Variant::Notakto);
+            let random = RandomPlayer::seeded(seed).variant(Variant::Notakto);
+            let mut game = Game::<NotaktoState<2>>::new(Box::new(random), Box::new(ai))
+                .with_variant(Variant::Notakto);
+            assert_eq!(game.play(), Some(PlayerEnum::O));
+        }
+    }
+}
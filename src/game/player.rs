@@ -1,158 +1,892 @@
+use crate::game::board::{Board, Move, Variant};
 use crate::game::errors::TicTacToeError;
 use crate::game::game_state::{GameState, PlayerEnum};
-use crate::{play_game, Game};
+use crate::game::solver::MoveLookup;
+use crate::game::transposition::{Bound, Entry, TranspositionTable};
 use itertools::Itertools;
-use std::collections::{HashMap, HashSet};
-use std::convert::TryFrom;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
 use std::io::Write;
+use std::rc::Rc;
+use std::str::FromStr;
 
-pub trait Player {
-    fn assign_piece(&mut self, player_enum: PlayerEnum) {}
+/// What a player wants to do on their turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerAction {
+    /// Places the player's own piece.
+    Move(usize),
+    /// Places the given piece, in variants where the mover chooses it.
+    Place(Move),
+    /// Take back this player's last move and the opponent's reply.
+    Undo,
+    /// Give up, losing the game.
+    Resign,
+}
+
+pub trait Player<B: Board = GameState> {
+    fn assign_piece(&mut self, _player_enum: PlayerEnum) {}
 
-    fn play_turn(&mut self, board: GameState) -> usize {
+    fn play_turn(&mut self, _board: B) -> usize {
         0
     }
-    fn on_error(&mut self, error: TicTacToeError, retries: i32) {}
+
+    fn play_action(&mut self, board: B) -> PlayerAction {
+        PlayerAction::Move(self.play_turn(board))
+    }
+
+    fn on_error(&mut self, _error: TicTacToeError, _retries: i32) {}
+
+    /// Called once the game is over with the final board.
+    fn on_game_end(&mut self, _board: B) {}
 }
 
 pub struct HumanPlayer {
     player_enum: PlayerEnum,
+    variant: Variant,
 }
 
 impl HumanPlayer {
     pub fn new() -> HumanPlayer {
         HumanPlayer {
             player_enum: PlayerEnum::None,
+            variant: Variant::Standard,
+        }
+    }
+
+    /// Asks for the piece as well as the position in variants where the mover chooses it.
+    pub fn variant(mut self, variant: Variant) -> HumanPlayer {
+        self.variant = variant;
+        self
+    }
+}
+
+impl HumanPlayer {
+    // A position from 1, followed by X or O when the piece is chosen
+    fn parse_action(&self, input: &str) -> Option<PlayerAction> {
+        let mut words = input.split_whitespace();
+        let pos = words.next()?.parse::<usize>().ok().filter(|&pos| pos > 0)?;
+        let action = if self.variant.chooses_piece() {
+            let piece = PlayerEnum::from_str(&words.next()?.to_uppercase()).ok()?;
+            PlayerAction::Place(Move::new(pos - 1, piece))
+        } else {
+            PlayerAction::Move(pos - 1)
+        };
+        match words.next() {
+            Some(_) => None,
+            None => Some(action),
+        }
+    }
+
+    fn read_action<B: Board>(&self, board: B) -> PlayerAction {
+        println!("{}", board.get_numbered_display());
+        let piece = if self.variant.chooses_piece() {
+            " and piece (X or O)"
+        } else {
+            ""
+        };
+        let mut line = String::new();
+        loop {
+            print!("Enter move (1-{}){} or undo: ", B::SIZE, piece);
+            std::io::stdout().flush().unwrap();
+            line.clear();
+            // Nobody is left to play once stdin is closed or can't be read
+            if std::io::stdin()
+                .read_line(&mut line)
+                .map_or(true, |read| read == 0)
+            {
+                println!();
+                return PlayerAction::Resign;
+            }
+            let input = line.trim();
+            if input == "undo" {
+                return PlayerAction::Undo;
+            }
+            match self.parse_action(input) {
+                Some(action) => return action,
+                None => println!("Enter a number between 1 and {}{} or undo", B::SIZE, piece),
+            }
         }
     }
 }
 
-impl Player for HumanPlayer {
+impl Default for HumanPlayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Board> Player<B> for HumanPlayer {
     fn assign_piece(&mut self, player_enum: PlayerEnum) {
         self.player_enum = player_enum
     }
 
-    fn play_turn(&mut self, board: GameState) -> usize {
-        let d = board.get_numbered_display();
-        println!("{}", d);
-        let mut line = String::new();
-        print!("Enter move (1-9): ");
-        std::io::stdout().flush().unwrap();
-        std::io::stdin().read_line(&mut line).unwrap();
-        let pos = line.trim().parse::<usize>().unwrap();
-        line.clear();
-        pos - 1
+    fn play_turn(&mut self, board: B) -> usize {
+        loop {
+            match self.read_action(board) {
+                PlayerAction::Move(pos) => return pos,
+                PlayerAction::Place(m) => return m.position,
+                PlayerAction::Undo => println!("Undo is not available here"),
+                // Never a legal position, so the caller's retries run out
+                PlayerAction::Resign => return B::SIZE,
+            }
+        }
+    }
+
+    fn play_action(&mut self, board: B) -> PlayerAction {
+        self.read_action(board)
     }
 
     fn on_error(&mut self, error: TicTacToeError, retries: i32) {
         match error {
             TicTacToeError::InvalidBoard => {}
             TicTacToeError::InvalidPlayerEnum => {}
-            TicTacToeError::IllegalMove => println!("That space is taken!"),
+            TicTacToeError::IllegalMove => println!("That move is not allowed!"),
+            TicTacToeError::NothingToUndo => println!("There is nothing to undo!"),
+            TicTacToeError::Resigned => {}
             TicTacToeError::OutOfBounds => {
-                println!("You must enter a value in between 1 and 9 for input")
+                println!(
+                    "You must enter a value in between 1 and {} for input",
+                    B::SIZE
+                )
             }
         }
         println!("Number of retries left: {}", retries)
     }
 }
 
-pub fn simplified_board(board: GameState) -> GameState {
-    let num_transformations = 8;
-    let mut min_board = board.clone();
-    for i in 0..num_transformations {
-        let transformed_board = board.transform(i % 4, i > 3);
+/// Plays a uniformly random empty square, with a random piece in variants where the
+/// mover chooses it.
+pub struct RandomPlayer<R: Rng = StdRng> {
+    rng: R,
+    variant: Variant,
+}
+
+impl RandomPlayer<StdRng> {
+    pub fn new() -> RandomPlayer<StdRng> {
+        Self::with_rng(StdRng::from_entropy())
+    }
+
+    pub fn seeded(seed: u64) -> RandomPlayer<StdRng> {
+        Self::with_rng(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl<R: Rng> RandomPlayer<R> {
+    pub fn with_rng(rng: R) -> RandomPlayer<R> {
+        RandomPlayer {
+            rng,
+            variant: Variant::Standard,
+        }
+    }
+
+    pub fn variant(mut self, variant: Variant) -> RandomPlayer<R> {
+        self.variant = variant;
+        self
+    }
+}
+
+impl Default for RandomPlayer<StdRng> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Board, R: Rng> Player<B> for RandomPlayer<R> {
+    fn play_turn(&mut self, board: B) -> usize {
+        *board.empty_positions().choose(&mut self.rng).unwrap()
+    }
+
+    fn play_action(&mut self, board: B) -> PlayerAction {
+        if !self.variant.chooses_piece() {
+            return PlayerAction::Move(self.play_turn(board));
+        }
+        let moves = self.variant.moves(&board);
+        PlayerAction::Place(*moves.choose(&mut self.rng).unwrap())
+    }
+}
+
+/// The symmetric board with the smallest raw value. For a `GameState`,
+/// `GameState::canonical` also gives the symmetry that produced it.
+pub fn simplified_board<B: Board>(board: B) -> B {
+    let mut min_board = board;
+    for transformed_board in board.symmetries() {
         if transformed_board.get_raw() < min_board.get_raw() {
             min_board = transformed_board
         }
     }
-    return min_board;
+    min_board
 }
 
-pub struct BoardNode {
-    game_state: GameState,
-    parent: Option<u32>,
+pub struct BoardNode<B: Board = GameState> {
+    game_state: B,
+    parent: Option<B>,
 }
 
-impl BoardNode {
-    pub fn new(parent: Option<u32>, pos: usize) -> BoardNode {
+impl<B: Board> BoardNode<B> {
+    pub fn new(parent: Option<B>, pos: usize) -> BoardNode<B> {
         if let Some(p) = parent {
-            let mut parent_gs = GameState::try_from(p).unwrap();
+            let mut parent_gs = p;
             parent_gs.auto_set(pos).unwrap();
             return BoardNode {
                 game_state: parent_gs,
-                parent: parent,
+                parent,
             };
         }
         BoardNode {
-            game_state: GameState::new(),
+            game_state: B::new(),
             parent: None,
         }
     }
 
-    pub fn children(&self) -> Vec<GameState> {
+    /// The node for `parent` after `m` is played, whichever piece it places.
+    pub fn after_move(parent: B, m: Move) -> BoardNode<B> {
+        let mut game_state = parent;
+        game_state.set(m.position, m.piece).unwrap();
+        BoardNode {
+            game_state,
+            parent: Some(parent),
+        }
+    }
+
+    pub fn children(&self) -> Vec<B> {
         let mut children = vec![];
         for pos in self.game_state.empty_positions() {
-            let mut child = self.game_state.clone();
+            let mut child = self.game_state;
             child.auto_set(pos).unwrap();
             children.push(child);
         }
         children
     }
 
-    pub fn unique_children(&self) -> Vec<GameState> {
+    pub fn unique_children(&self) -> Vec<B> {
         let mut children = HashSet::new();
         for child in self.children() {
             let simplified_child = simplified_board(child);
             children.insert(simplified_child);
         }
-        children.iter().map(|c| *c).collect_vec()
+        children.iter().copied().collect_vec()
+    }
+
+    pub fn parent_board(&self) -> Option<B> {
+        self.parent
+    }
+}
+
+/// How strong an `AIPlayer` plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+    Impossible,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Medium,
+        Difficulty::Hard,
+        Difficulty::Impossible,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Difficulty> {
+        match name {
+            "easy" => Some(Difficulty::Easy),
+            "medium" => Some(Difficulty::Medium),
+            "hard" => Some(Difficulty::Hard),
+            "impossible" => Some(Difficulty::Impossible),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+            Difficulty::Impossible => "impossible",
+        }
     }
 
-    pub fn parent_board(&self) -> Option<GameState> {
-        if let Some(p) = self.parent {
-            return Some(GameState::try_from(p).unwrap());
+    // (search depth, blunder rate, always take a win in one)
+    fn settings(&self) -> (Option<i32>, f64, bool) {
+        match self {
+            Difficulty::Easy => (Some(1), 0.5, false),
+            Difficulty::Medium => (Some(3), 0.15, true),
+            Difficulty::Hard => (Some(6), 0.03, true),
+            Difficulty::Impossible => (None, 0.0, true),
         }
-        return None;
     }
 }
 
-struct AIPlayer {
+pub struct AIPlayer<B: Board = GameState> {
     player_enum: PlayerEnum,
-    graph: HashMap<u32, BoardNode>,
+    tablebase: Option<Rc<dyn MoveLookup<B>>>,
+    max_depth: Option<i32>,
+    blunder_rate: f64,
+    take_wins: bool,
+    variant: Variant,
+    table: Option<TranspositionTable<B>>,
+    rng: StdRng,
 }
 
-impl AIPlayer {
-    fn score_board(board: GameState, player_enum: PlayerEnum) -> f32 {
-        let opponent_enum = if player_enum == PlayerEnum::X {
-            PlayerEnum::O
-        } else {
-            PlayerEnum::X
-        };
-        if board.is_winner(player_enum) {
-            return 9.0;
-        } else if board.is_winner(opponent_enum) {
-            return -9.0;
-        } else if board.is_tie() {
-            return 0.5;
+impl<B: Board> AIPlayer<B> {
+    pub fn new() -> AIPlayer<B> {
+        AIPlayer {
+            player_enum: PlayerEnum::None,
+            tablebase: None,
+            max_depth: None,
+            blunder_rate: 0.0,
+            take_wins: true,
+            variant: Variant::Standard,
+            table: None,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Answers from a solved tablebase instead of searching.
+    pub fn with_tablebase(tablebase: Rc<dyn MoveLookup<B>>) -> AIPlayer<B> {
+        AIPlayer {
+            tablebase: Some(tablebase),
+            ..Self::new()
+        }
+    }
+
+    pub fn difficulty(self, difficulty: Difficulty) -> AIPlayer<B> {
+        let (max_depth, blunder_rate, take_wins) = difficulty.settings();
+        AIPlayer {
+            max_depth,
+            blunder_rate,
+            take_wins,
+            ..self
+        }
+    }
+
+    /// Only looks this many plies ahead, treating unfinished boards as even. A limited
+    /// search never uses the tablebase.
+    pub fn max_depth(mut self, max_depth: Option<i32>) -> AIPlayer<B> {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// The chance of playing a random empty square instead of searching.
+    pub fn blunder_rate(mut self, blunder_rate: f64) -> AIPlayer<B> {
+        self.blunder_rate = blunder_rate;
+        self
+    }
+
+    /// Whether a win in one is always played, even on a turn that would blunder.
+    pub fn take_wins(mut self, take_wins: bool) -> AIPlayer<B> {
+        self.take_wins = take_wins;
+        self
+    }
+
+    /// Plays to win under the rules of `variant`. A tablebase, if any, should be solved
+    /// for the same variant.
+    pub fn variant(mut self, variant: Variant) -> AIPlayer<B> {
+        self.variant = variant;
+        self
+    }
+
+    /// Remembers positions already searched, which pays off on boards bigger than 3x3.
+    pub fn transposition_table(mut self, table: TranspositionTable<B>) -> AIPlayer<B> {
+        self.table = Some(table);
+        self
+    }
+
+    pub fn seeded(mut self, seed: u64) -> AIPlayer<B> {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    fn winning_move(&self, board: B) -> Option<Move> {
+        self.variant.moves(&board).into_iter().find(|m| {
+            let mut next = board;
+            next.set(m.position, m.piece).unwrap();
+            self.variant.winner(&next) == Some(self.player_enum)
+        })
+    }
+
+    fn best_move(&mut self, board: B) -> Move {
+        if self.take_wins {
+            if let Some(m) = self.winning_move(board) {
+                return m;
+            }
+        }
+        let moves = self.variant.moves(&board);
+        if self.blunder_rate > 0.0 && self.rng.gen_bool(self.blunder_rate.min(1.0)) {
+            return *moves.choose(&mut self.rng).unwrap();
+        }
+        if self.max_depth.is_none() {
+            if let Some(pos) = self.tablebase.as_ref().and_then(|t| t.best_move(board)) {
+                return Move::new(pos, self.variant.piece(self.player_enum));
+            }
+        }
+        let max_depth = self.max_depth.unwrap_or(i32::MAX);
+        if let Some(table) = self.table.as_mut() {
+            table.clear();
+        }
+        let mut best = moves[0];
+        let mut best_score = f32::NEG_INFINITY;
+        for m in moves {
+            let node = BoardNode::after_move(board, m);
+            let objective = Objective {
+                max_depth,
+                player_enum: self.player_enum,
+                variant: self.variant,
+            };
+            let score = search(
+                &node,
+                1,
+                best_score,
+                f32::INFINITY,
+                objective,
+                self.table.as_mut(),
+            );
+            if score > best_score {
+                best_score = score;
+                best = m;
+            }
+        }
+        best
+    }
+
+    fn score_board(board: B, player_enum: PlayerEnum, variant: Variant) -> f32 {
+        match variant.winner(&board) {
+            Some(winner) if winner == player_enum => 9.0,
+            Some(_) => -9.0,
+            None if board.is_tie() => 0.5,
+            None => 0.0,
         }
-        0.0
     }
 }
 
-impl Player for AIPlayer {
+impl<B: Board> Default for AIPlayer<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Board> Player<B> for AIPlayer<B> {
     fn assign_piece(&mut self, player_enum: PlayerEnum) {
         self.player_enum = player_enum
     }
 
-    fn play_turn(&mut self, board: GameState) -> usize {
-        0
+    fn play_turn(&mut self, board: B) -> usize {
+        self.best_move(board).position
     }
 
-    fn on_error(&mut self, error: TicTacToeError, retries: i32) {}
+    fn play_action(&mut self, board: B) -> PlayerAction {
+        let m = self.best_move(board);
+        if self.variant.chooses_piece() {
+            PlayerAction::Place(m)
+        } else {
+            PlayerAction::Move(m.position)
+        }
+    }
+}
+
+// Scores are divided by the number of plies it took to reach them so that
+// quicker wins and slower losses are preferred.
+pub fn minmax<B: Board>(
+    node: &BoardNode<B>,
+    depth: i32,
+    alpha: f32,
+    beta: f32,
+    player_enum: PlayerEnum,
+) -> f32 {
+    minmax_to_depth(node, depth, i32::MAX, alpha, beta, player_enum)
 }
 
-pub fn minmax(node: BoardNode, depth: i32, player_enum: PlayerEnum) -> usize {
-    if depth == 0 || node.game_state.
-    0
-}
\ No newline at end of file
+/// Like `minmax`, but boards still unfinished at `max_depth` score 0.
+pub fn minmax_to_depth<B: Board>(
+    node: &BoardNode<B>,
+    depth: i32,
+    max_depth: i32,
+    alpha: f32,
+    beta: f32,
+    player_enum: PlayerEnum,
+) -> f32 {
+    let objective = Objective {
+        max_depth,
+        player_enum,
+        variant: Variant::Standard,
+    };
+    search(node, depth, alpha, beta, objective, None)
+}
+
+/// Like `minmax_to_depth`, but looks positions up in `table` before searching them and
+/// stores what it finds. Values depend on the depth of the root, so the table should be
+/// cleared before searching from a new root.
+pub fn minmax_with_table<B: Board>(
+    node: &BoardNode<B>,
+    depth: i32,
+    max_depth: i32,
+    alpha: f32,
+    beta: f32,
+    player_enum: PlayerEnum,
+    table: &mut TranspositionTable<B>,
+) -> f32 {
+    let objective = Objective {
+        max_depth,
+        player_enum,
+        variant: Variant::Standard,
+    };
+    search(node, depth, alpha, beta, objective, Some(table))
+}
+
+// Who a search is scoring for, under which rules and how deep it may look
+#[derive(Clone, Copy)]
+struct Objective {
+    max_depth: i32,
+    player_enum: PlayerEnum,
+    variant: Variant,
+}
+
+fn search<B: Board>(
+    node: &BoardNode<B>,
+    depth: i32,
+    mut alpha: f32,
+    mut beta: f32,
+    objective: Objective,
+    mut table: Option<&mut TranspositionTable<B>>,
+) -> f32 {
+    let board = node.game_state;
+    let Objective {
+        max_depth,
+        player_enum,
+        variant,
+    } = objective;
+    if board.is_over() || depth >= max_depth {
+        return AIPlayer::score_board(board, player_enum, variant) / (depth + 1) as f32;
+    }
+    let remaining = max_depth - depth;
+    let (alpha_start, beta_start) = (alpha, beta);
+    let mut moves = variant.moves(&board);
+    if let Some(entry) = table.as_ref().and_then(|t| t.get(board)) {
+        if entry.depth >= remaining {
+            match entry.bound {
+                Bound::Exact => return entry.value,
+                Bound::Lower => alpha = alpha.max(entry.value),
+                Bound::Upper => beta = beta.min(entry.value),
+            }
+            if alpha >= beta {
+                return entry.value;
+            }
+        }
+        // Try the best move from last time first, whichever piece it placed
+        if let Some(pos) = entry.best_move {
+            moves.sort_by_key(|m| m.position != pos);
+        }
+    }
+    let maximizing = variant.turn(&board) == player_enum;
+    let mut best = if maximizing {
+        f32::NEG_INFINITY
+    } else {
+        f32::INFINITY
+    };
+    let mut best_move = None;
+    for m in moves {
+        let child_node = BoardNode::after_move(board, m);
+        let score = search(
+            &child_node,
+            depth + 1,
+            alpha,
+            beta,
+            objective,
+            table.as_deref_mut(),
+        );
+        if maximizing {
+            if score > best {
+                best = score;
+                best_move = Some(m.position);
+            }
+            alpha = alpha.max(best);
+        } else {
+            if score < best {
+                best = score;
+                best_move = Some(m.position);
+            }
+            beta = beta.min(best);
+        }
+        if alpha >= beta {
+            break;
+        }
+    }
+    if let Some(table) = table {
+        let bound = if best <= alpha_start {
+            Bound::Upper
+        } else if best >= beta_start {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        let entry = Entry {
+            depth: remaining,
+            bound,
+            value: best,
+            best_move,
+        };
+        table.store(board, entry);
+    }
+    best
+}
+
+/// Plays every possible opponent reply against `player`, playing as `me`, and checks it
+/// never loses.
+#[cfg(test)]
+pub(crate) fn assert_never_loses<B: Board>(
+    board: B,
+    variant: Variant,
+    player: &mut dyn Player<B>,
+    me: PlayerEnum,
+) {
+    match variant.winner(&board) {
+        Some(winner) if winner == me.opponent() => {
+            panic!("{} lost:\n{}", me, board.get_display())
+        }
+        Some(_) => return,
+        None if board.is_tie() => return,
+        None => {}
+    }
+    if board.get_turn() == me {
+        let mut next = board;
+        let pos = player.play_turn(board);
+        assert!(board.empty_positions().contains(&pos));
+        next.auto_set(pos).unwrap();
+        assert_never_loses(next, variant, player, me);
+    } else {
+        for pos in board.empty_positions() {
+            let mut next = board;
+            next.auto_set(pos).unwrap();
+            assert_never_loses(next, variant, player, me);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::game::Game;
+    use crate::game::mnk_state::MnkState;
+    use crate::game::solver::Tablebase;
+    use crate::game::tournament::{Format, Tournament};
+
+    #[test]
+    fn test_ai_never_loses() {
+        for ai_enum in [PlayerEnum::X, PlayerEnum::O] {
+            let mut ai = AIPlayer::new();
+            ai.assign_piece(ai_enum);
+            assert_never_loses(GameState::new(), ai.variant, &mut ai, ai_enum);
+        }
+    }
+
+    #[test]
+    fn test_ai_never_loses_misere() {
+        let tablebase = Rc::new(Tablebase::solve_variant(Variant::Misere));
+        for ai_enum in [PlayerEnum::X, PlayerEnum::O] {
+            let mut ai = AIPlayer::new().variant(Variant::Misere);
+            ai.assign_piece(ai_enum);
+            assert_never_loses(GameState::new(), ai.variant, &mut ai, ai_enum);
+            let mut ai = AIPlayer::with_tablebase(tablebase.clone()).variant(Variant::Misere);
+            ai.assign_piece(ai_enum);
+            assert_never_loses(GameState::new(), ai.variant, &mut ai, ai_enum);
+        }
+        // Never completes a line when it has a choice
+        let mut ai = AIPlayer::new().variant(Variant::Misere);
+        ai.assign_piece(PlayerEnum::X);
+        let board = GameState::from_str("XX OO    ").unwrap();
+        assert_ne!(ai.play_turn(board), 2);
+    }
+
+    #[test]
+    fn test_ai_wild() {
+        // Either player can complete the line of Os, so O takes it
+        let mut board = GameState::new();
+        for (pos, piece) in [(0, PlayerEnum::O), (1, PlayerEnum::O), (4, PlayerEnum::X)].iter() {
+            board.set(*pos, *piece).unwrap();
+        }
+        let mut ai = AIPlayer::new().variant(Variant::Wild);
+        ai.assign_piece(PlayerEnum::O);
+        assert_eq!(
+            ai.play_action(board),
+            PlayerAction::Place(Move::new(2, PlayerEnum::O))
+        );
+        // Wild tic-tac-toe is a win for the first player
+        for seed in 0..3 {
+            let table = TranspositionTable::<GameState>::new(1 << 14).symmetric(true);
+            let ai = AIPlayer::new()
+                .variant(Variant::Wild)
+                .transposition_table(table);
+            let random = RandomPlayer::seeded(seed).variant(Variant::Wild);
+            let mut game = Game::new(Box::new(ai), Box::new(random)).with_variant(Variant::Wild);
+            assert_eq!(game.play(), Some(PlayerEnum::X));
+        }
+    }
+
+    #[test]
+    fn test_ai_never_loses_mnk() {
+        let mut ai = AIPlayer::new();
+        ai.assign_piece(PlayerEnum::O);
+        assert_never_loses(
+            MnkState::<3, 3, 3>::new(),
+            ai.variant,
+            &mut ai,
+            PlayerEnum::O,
+        );
+    }
+
+    #[test]
+    fn test_ai_play_turn_mnk() {
+        let test_cases = vec![
+            ("XX  OO          ", PlayerEnum::X, 2),
+            ("XX  OO  X       ", PlayerEnum::O, 6),
+            ("O  X  X     O   ", PlayerEnum::X, 9),
+        ];
+        for (board_str, player_enum, expected_pos) in test_cases {
+            let mut ai = AIPlayer::new();
+            ai.assign_piece(player_enum);
+            let board = MnkState::<4, 4, 3>::from_str(board_str).unwrap();
+            assert_eq!(ai.play_turn(board), expected_pos, "{}", board_str);
+        }
+    }
+
+    #[test]
+    fn test_transposition_table_matches_search() {
+        let boards = vec!["         ", "X        ", "XO  X    ", "X   O   X"];
+        for board_str in boards {
+            let board = GameState::from_str(board_str).unwrap();
+            let node = BoardNode {
+                game_state: board,
+                parent: None,
+            };
+            for max_depth in [2, 4, i32::MAX] {
+                let expected = minmax_to_depth(
+                    &node,
+                    0,
+                    max_depth,
+                    f32::NEG_INFINITY,
+                    f32::INFINITY,
+                    PlayerEnum::X,
+                );
+                for symmetric in [false, true] {
+                    let mut table = TranspositionTable::new(1 << 12).symmetric(symmetric);
+                    let score = minmax_with_table(
+                        &node,
+                        0,
+                        max_depth,
+                        f32::NEG_INFINITY,
+                        f32::INFINITY,
+                        PlayerEnum::X,
+                        &mut table,
+                    );
+                    assert_eq!(score, expected, "{} {}", board_str, max_depth);
+                    assert!(!table.is_empty());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ai_with_transposition_table() {
+        for ai_enum in [PlayerEnum::X, PlayerEnum::O] {
+            let table = TranspositionTable::new(1 << 10).symmetric(true);
+            let mut ai = AIPlayer::new().transposition_table(table);
+            ai.assign_piece(ai_enum);
+            assert_never_loses(GameState::new(), ai.variant, &mut ai, ai_enum);
+        }
+        let mut ai = AIPlayer::new().transposition_table(TranspositionTable::new(1 << 16));
+        ai.assign_piece(PlayerEnum::X);
+        let board = MnkState::<4, 4, 3>::from_str("O  X  X     O   ").unwrap();
+        assert_eq!(ai.play_turn(board), 9);
+    }
+
+    #[test]
+    fn test_tablebase_ai_never_loses() {
+        let tablebase = Rc::new(Tablebase::solve());
+        for ai_enum in [PlayerEnum::X, PlayerEnum::O] {
+            let mut ai = AIPlayer::with_tablebase(tablebase.clone());
+            ai.assign_piece(ai_enum);
+            assert_never_loses(GameState::new(), ai.variant, &mut ai, ai_enum);
+        }
+    }
+
+    #[test]
+    fn test_difficulty_settings() {
+        let mut easy = AIPlayer::new().difficulty(Difficulty::Easy).seeded(1);
+        easy.assign_piece(PlayerEnum::O);
+        // Easy can miss a win in one, the others never do
+        let board = GameState::from_str("XX OO X  ").unwrap();
+        let moves = (0..50)
+            .map(|_| easy.play_turn(board))
+            .collect::<HashSet<_>>();
+        assert!(moves.len() > 1);
+        for difficulty in &Difficulty::ALL[1..] {
+            let mut ai = AIPlayer::new().difficulty(*difficulty).seeded(1);
+            ai.assign_piece(PlayerEnum::O);
+            assert!(
+                (0..50).all(|_| ai.play_turn(board) == 5),
+                "{:?}",
+                difficulty
+            );
+        }
+        assert_eq!(Difficulty::from_name("hard"), Some(Difficulty::Hard));
+        assert_eq!(
+            Difficulty::from_name(Difficulty::Easy.name()),
+            Some(Difficulty::Easy)
+        );
+    }
+
+    #[test]
+    fn test_difficulty_strength() {
+        // Every level plays a gauntlet against the perfect player
+        let tablebase = Rc::new(Tablebase::solve());
+        let perfect = tablebase.clone();
+        let mut tournament = Tournament::new(Format::Gauntlet)
+            .add(
+                "perfect",
+                Box::new(move || Ok(Box::new(AIPlayer::with_tablebase(perfect.clone())))),
+            )
+            .games_per_pairing(100);
+        for (i, difficulty) in Difficulty::ALL.iter().enumerate() {
+            let (tablebase, difficulty) = (tablebase.clone(), *difficulty);
+            let seed = Rc::new(std::cell::Cell::new(i as u64 * 1000));
+            tournament = tournament.add(
+                difficulty.name(),
+                Box::new(move || {
+                    seed.set(seed.get() + 1);
+                    let ai = AIPlayer::with_tablebase(tablebase.clone());
+                    Ok(Box::new(ai.difficulty(difficulty).seeded(seed.get())))
+                }),
+            );
+        }
+        let result = tournament.run();
+        let losses = (1..=4)
+            .map(|i| result.record(i, 0).losses)
+            .collect::<Vec<_>>();
+        assert!(losses[0] > losses[1], "{:?}", losses);
+        assert!(losses[1] > losses[2], "{:?}", losses);
+        assert!(losses[2] > losses[3], "{:?}", losses);
+        assert_eq!(losses[3], 0);
+    }
+
+    #[test]
+    fn test_ai_play_turn() {
+        let test_cases = vec![
+            // Takes the win over the block
+            ("XX OO    ", PlayerEnum::X, 2),
+            ("XX OO X  ", PlayerEnum::O, 5),
+            // Blocks the only threat
+            ("XX  O    ", PlayerEnum::O, 2),
+            ("XO  X    ", PlayerEnum::O, 8),
+        ];
+        for (board_str, player_enum, expected_pos) in test_cases {
+            let mut ai = AIPlayer::new();
+            ai.assign_piece(player_enum);
+            let board = GameState::from_str(board_str).unwrap();
+            assert_eq!(ai.play_turn(board), expected_pos, "{}", board_str);
+        }
+    }
+}
@@ -0,0 +1,373 @@
+// A PGN-like text format for whole games:
+//
+// [Variant "Standard"]
+// [Date "2022.03.14"]
+// [X "human"]
+// [O "ai"]
+// [Result "1/2-1/2"]
+//
+// 1. b2 a1 2. c3 a3 3. a2 c2 4. b1 b3 5. c1 1/2-1/2
+//
+// Squares are written either as 1-9 or as a file a-c (left to right) and a rank 1-3
+// (top to bottom), so a1 is 1 and c3 is 9. A [Start "X   O    "] tag gives a starting
+// position other than the empty board, in the same format as `GameState::from_str`.
+// The Variant tag is Standard or Misere, which use the same moves but score them
+// differently. Variants where the mover chooses the piece can't be written as squares
+// alone, so they can't be replayed.
+
+use crate::game::board::{Board, Variant};
+use crate::game::errors::{RecordError, TicTacToeError};
+use crate::game::game::Game;
+use crate::game::game_state::{GameState, PlayerEnum};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+const STANDARD_VARIANT: &str = "Standard";
+const UNKNOWN_DATE: &str = "????.??.??";
+const RESULTS: [&str; 4] = ["1-0", "0-1", "1/2-1/2", "*"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Coordinates {
+    Numeric,
+    Algebraic,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameRecord {
+    tags: Vec<(String, String)>,
+    pub moves: Vec<usize>,
+}
+
+impl GameRecord {
+    pub fn new() -> GameRecord {
+        GameRecord {
+            tags: vec![
+                ("Variant".to_string(), STANDARD_VARIANT.to_string()),
+                ("Date".to_string(), UNKNOWN_DATE.to_string()),
+                ("X".to_string(), "?".to_string()),
+                ("O".to_string(), "?".to_string()),
+                ("Result".to_string(), "*".to_string()),
+            ],
+            moves: vec![],
+        }
+    }
+
+    pub fn from_game(game: &Game, x_name: &str, o_name: &str) -> GameRecord {
+        let mut record = GameRecord::new();
+        record.set_tag("Variant", game.variant().name());
+        record.set_tag("X", x_name);
+        record.set_tag("O", o_name);
+        record.moves = game.history().iter().map(|r| r.position).collect();
+        record.set_tag("Result", Self::result_str(game.game_state(), game.winner()));
+        record
+    }
+
+    fn result_str(board: GameState, winner: Option<PlayerEnum>) -> &'static str {
+        match winner {
+            Some(PlayerEnum::X) => "1-0",
+            Some(_) => "0-1",
+            None if board.is_tie() => "1/2-1/2",
+            None => "*",
+        }
+    }
+
+    /// The rules named by the Variant tag.
+    pub fn variant(&self) -> Result<Variant, RecordError> {
+        let name = self.tag("Variant").unwrap_or(STANDARD_VARIANT);
+        Variant::from_name(name).ok_or_else(|| RecordError::UnsupportedVariant(name.to_string()))
+    }
+
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    pub fn set_tag(&mut self, name: &str, value: &str) {
+        match self.tags.iter_mut().find(|(n, _)| n == name) {
+            Some(tag) => tag.1 = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    pub fn tags(&self) -> &[(String, String)] {
+        &self.tags
+    }
+
+    pub fn start(&self) -> Result<GameState, RecordError> {
+        match self.tag("Start") {
+            Some(board) => GameState::from_str(board).map_err(RecordError::InvalidStart),
+            None => Ok(GameState::new()),
+        }
+    }
+
+    /// Plays every move from the starting position, returning the board after each ply
+    /// or the first ply (counting from 1) that is not a legal move.
+    pub fn replay(&self) -> Result<Vec<GameState>, RecordError> {
+        // Every supported variant is played with the same moves
+        let variant = self.variant()?;
+        if !variant.own_pieces() {
+            return Err(RecordError::UnsupportedVariant(variant.name().to_string()));
+        }
+        let mut board = self.start()?;
+        let mut states = Vec::with_capacity(self.moves.len());
+        for (i, &pos) in self.moves.iter().enumerate() {
+            let illegal = |error| RecordError::IllegalMove { ply: i + 1, error };
+            if board.is_over() || !board.is_empty(pos).map_err(illegal)? {
+                return Err(illegal(TicTacToeError::IllegalMove));
+            }
+            let turn = board.get_turn();
+            board.set(pos, turn).map_err(illegal)?;
+            states.push(board);
+        }
+        Ok(states)
+    }
+
+    /// Reads a record from a file and checks that every move in it is legal.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<GameRecord, RecordError> {
+        let record = GameRecord::from_str(&fs::read_to_string(path)?)?;
+        record.replay()?;
+        Ok(record)
+    }
+
+    pub fn save<P: AsRef<Path>>(
+        &self,
+        path: P,
+        coordinates: Coordinates,
+    ) -> Result<(), RecordError> {
+        fs::write(path, self.write(coordinates))?;
+        Ok(())
+    }
+
+    pub fn write(&self, coordinates: Coordinates) -> String {
+        let mut text = String::new();
+        for (name, value) in self.tags.iter() {
+            let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+            text += &format!("[{} \"{}\"]\n", name, escaped);
+        }
+        text += "\n";
+        // Move numbers count X and O moves as a pair, like chess
+        let o_first = self.start().is_ok_and(|b| b.get_turn() == PlayerEnum::O);
+        let mut tokens = vec![];
+        for (i, &pos) in self.moves.iter().enumerate() {
+            let ply = i + o_first as usize;
+            if ply.is_multiple_of(2) {
+                tokens.push(format!("{}.", ply / 2 + 1));
+            } else if i == 0 {
+                tokens.push("1...".to_string());
+            }
+            tokens.push(Self::square_str(pos, coordinates));
+        }
+        tokens.push(self.tag("Result").unwrap_or("*").to_string());
+        text += &tokens.join(" ");
+        text += "\n";
+        text
+    }
+
+    fn square_str(pos: usize, coordinates: Coordinates) -> String {
+        match coordinates {
+            Coordinates::Numeric => (pos + 1).to_string(),
+            Coordinates::Algebraic => {
+                format!("{}{}", (b'a' + (pos % 3) as u8) as char, pos / 3 + 1)
+            }
+        }
+    }
+
+    fn parse_square(token: &str) -> Result<usize, RecordError> {
+        let invalid = || RecordError::InvalidToken(token.to_string());
+        if let Ok(n) = token.parse::<usize>() {
+            return n.checked_sub(1).ok_or_else(invalid);
+        }
+        let chars: Vec<char> = token.chars().collect();
+        match chars.as_slice() {
+            [file @ 'a'..='c', rank @ '1'..='3'] => {
+                Ok((*rank as usize - '1' as usize) * 3 + (*file as usize - 'a' as usize))
+            }
+            _ => Err(invalid()),
+        }
+    }
+
+    fn parse_tag(line: &str) -> Result<(String, String), RecordError> {
+        let invalid = || RecordError::InvalidTag(line.to_string());
+        let inner = line
+            .strip_prefix('[')
+            .and_then(|l| l.strip_suffix(']'))
+            .ok_or_else(invalid)?;
+        let (name, value) = inner.split_once(' ').ok_or_else(invalid)?;
+        let value = value
+            .trim()
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .ok_or_else(invalid)?;
+        let mut unescaped = String::new();
+        let mut chars = value.chars();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' => unescaped.push(chars.next().ok_or_else(invalid)?),
+                _ => unescaped.push(ch),
+            }
+        }
+        Ok((name.to_string(), unescaped))
+    }
+}
+
+impl Default for GameRecord {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for GameRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.write(Coordinates::Algebraic))
+    }
+}
+
+impl FromStr for GameRecord {
+    type Err = RecordError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut record = GameRecord::new();
+        let mut movetext = String::new();
+        for line in value.lines().map(str::trim) {
+            if line.starts_with('[') {
+                let (name, value) = Self::parse_tag(line)?;
+                record.set_tag(&name, &value);
+            } else {
+                movetext += line;
+                movetext += " ";
+            }
+        }
+        for token in movetext.split_whitespace() {
+            if RESULTS.contains(&token) {
+                record.set_tag("Result", token);
+                break;
+            }
+            let is_move_number = token
+                .trim_end_matches('.')
+                .chars()
+                .all(|c| c.is_ascii_digit())
+                && token.ends_with('.');
+            if !is_move_number {
+                record.moves.push(Self::parse_square(token)?);
+            }
+        }
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DRAW: &str = "[Variant \"Standard\"]
+[Date \"2022.03.14\"]
+[X \"human\"]
+[O \"ai\"]
+[Result \"1/2-1/2\"]
+
+1. b2 a1 2. c3 a3 3. a2 c2 4. b1 b3 5. c1 1/2-1/2
+";
+
+    #[test]
+    fn test_parse_and_write() {
+        let record = GameRecord::from_str(DRAW).unwrap();
+        assert_eq!(record.tag("X"), Some("human"));
+        assert_eq!(record.tag("Date"), Some("2022.03.14"));
+        assert_eq!(record.moves, vec![4, 0, 8, 6, 3, 5, 1, 7, 2]);
+        assert_eq!(record.write(Coordinates::Algebraic), DRAW);
+        assert_eq!(
+            record.write(Coordinates::Numeric).lines().last(),
+            Some("1. 5 1 2. 9 7 3. 4 6 4. 2 8 5. 3 1/2-1/2")
+        );
+        let numeric = GameRecord::from_str(&record.write(Coordinates::Numeric)).unwrap();
+        assert_eq!(numeric, record);
+    }
+
+    #[test]
+    fn test_replay() {
+        let record = GameRecord::from_str(DRAW).unwrap();
+        let states = record.replay().unwrap();
+        assert_eq!(states.len(), 9);
+        assert_eq!(states[0].to_string(), "    X    ");
+        assert_eq!(states[8].to_string(), "OXXXXOOOX");
+        assert!(states[8].is_tie());
+    }
+
+    #[test]
+    fn test_replay_reports_ply() {
+        let test_cases = vec![
+            ("1. b2 a1 2. b2 *", 3),
+            ("1. a1 b1 2. a2 b2 3. a3 b3 *", 6),
+            ("1. 5 1 2. 10 *", 3),
+        ];
+        for (movetext, expected_ply) in test_cases {
+            let record = GameRecord::from_str(movetext).unwrap();
+            match record.replay() {
+                Err(RecordError::IllegalMove { ply, .. }) => assert_eq!(ply, expected_ply),
+                other => panic!("{}: {:?}", movetext, other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_start_position() {
+        let text = "[Start \"X   O   X\"]\n\n1... c1 2. a3 *";
+        let record = GameRecord::from_str(text).unwrap();
+        let states = record.replay().unwrap();
+        assert_eq!(states[1].to_string(), "X O O X X");
+        assert_eq!(
+            record.write(Coordinates::Algebraic).lines().last(),
+            Some("1... c1 2. a3 *")
+        );
+    }
+
+    #[test]
+    fn test_invalid_records() {
+        assert!(matches!(
+            GameRecord::from_str("[X human]"),
+            Err(RecordError::InvalidTag(_))
+        ));
+        assert!(matches!(
+            GameRecord::from_str("1. d4 *"),
+            Err(RecordError::InvalidToken(_))
+        ));
+        for variant in ["Atomic", "Wild", "Notakto"] {
+            let text = format!("[Variant \"{}\"]\n1. a1 *", variant);
+            let record = GameRecord::from_str(&text).unwrap();
+            assert!(matches!(
+                record.replay(),
+                Err(RecordError::UnsupportedVariant(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_from_game() {
+        use crate::game::player::AIPlayer;
+        let mut game = Game::new(Box::new(AIPlayer::new()), Box::new(AIPlayer::new()));
+        game.play();
+        let record = GameRecord::from_game(&game, "ai", "ai");
+        assert_eq!(record.tag("Result"), Some("1/2-1/2"));
+        let states = record.replay().unwrap();
+        assert_eq!(states.last(), Some(&game.game_state()));
+    }
+
+    #[test]
+    fn test_misere_record() {
+        use crate::game::player::AIPlayer;
+        let x = AIPlayer::new().variant(Variant::Misere);
+        let o = AIPlayer::new().variant(Variant::Misere);
+        let mut game = Game::new(Box::new(x), Box::new(o)).with_variant(Variant::Misere);
+        game.play();
+        let record = GameRecord::from_game(&game, "ai", "ai");
+        assert_eq!(record.tag("Variant"), Some("Misere"));
+        assert_eq!(record.tag("Result"), Some("1/2-1/2"));
+        let record = GameRecord::from_str(&record.to_string()).unwrap();
+        assert_eq!(record.variant().unwrap(), Variant::Misere);
+        assert_eq!(record.replay().unwrap().last(), Some(&game.game_state()));
+    }
+}
@@ -0,0 +1,263 @@
+use crate::game::board::{Board, Variant};
+use crate::game::game_state::GameState;
+use crate::game::player::simplified_board;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Loss,
+    Draw,
+}
+
+/// The result of a position for the side to move, assuming perfect play from both sides,
+/// and the number of plies until the game ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Evaluation {
+    pub outcome: Outcome,
+    pub distance: u8,
+}
+
+impl Evaluation {
+    // Higher is better for the side to move: win fast, lose slow
+    fn rank(&self) -> i32 {
+        match self.outcome {
+            Outcome::Win => 100 - self.distance as i32,
+            Outcome::Draw => 0,
+            Outcome::Loss => -100 + self.distance as i32,
+        }
+    }
+}
+
+/// A source of precomputed best moves that players can consult instead of searching.
+pub trait MoveLookup<B: Board> {
+    fn best_move(&self, board: B) -> Option<usize>;
+}
+
+pub struct Tablebase {
+    positions: HashMap<u32, Evaluation>,
+    canonical: HashMap<u32, Evaluation>,
+    variant: Variant,
+}
+
+impl Tablebase {
+    /// Solves every position reachable from an empty board.
+    pub fn solve() -> Tablebase {
+        Self::solve_variant(Variant::Standard)
+    }
+
+    /// Solves every position reachable from an empty board under the rules of `variant`.
+    /// Only standard tablebases can be written to a tablebase file, and variants where
+    /// the mover does not place their own piece are not supported.
+    pub fn solve_variant(variant: Variant) -> Tablebase {
+        assert!(
+            variant.own_pieces(),
+            "tablebases only hold moves of the mover's own piece"
+        );
+        let mut tablebase = Tablebase {
+            positions: HashMap::new(),
+            canonical: HashMap::new(),
+            variant,
+        };
+        tablebase.solve_position(GameState::new());
+        tablebase
+    }
+
+    fn solve_position(&mut self, board: GameState) -> Evaluation {
+        if let Some(evaluation) = self.positions.get(&board.get_raw()) {
+            return *evaluation;
+        }
+        let evaluation = if board.is_over() {
+            // The previous move ended the game, which is scored for the side to move
+            let outcome = match self.variant.winner(&board) {
+                Some(winner) if winner == board.get_turn() => Outcome::Win,
+                Some(_) => Outcome::Loss,
+                None => Outcome::Draw,
+            };
+            Evaluation {
+                outcome,
+                distance: 0,
+            }
+        } else {
+            let mut best: Option<Evaluation> = None;
+            for pos in board.empty_positions() {
+                let mut child = board;
+                child.auto_set(pos).unwrap();
+                let evaluation = Self::from_child(self.solve_position(child));
+                if best.is_none_or(|b| evaluation.rank() > b.rank()) {
+                    best = Some(evaluation);
+                }
+            }
+            best.unwrap()
+        };
+        self.positions.insert(board.get_raw(), evaluation);
+        self.canonical
+            .insert(simplified_board(board).get_raw(), evaluation);
+        evaluation
+    }
+
+    /// Rebuilds the full lookup from symmetry-reduced entries, returning `None` if any
+    /// entry is not a valid canonical board.
+    pub(crate) fn from_canonical(canonical: HashMap<u32, Evaluation>) -> Option<Tablebase> {
+        let mut positions = HashMap::with_capacity(canonical.len() * 8);
+        for (&raw, &evaluation) in canonical.iter() {
+            let board = GameState::try_from(raw).ok()?;
+            if simplified_board(board) != board {
+                return None;
+            }
+            for i in 0..8 {
+                positions.insert(board.transform(i % 4, i > 3).get_raw(), evaluation);
+            }
+        }
+        Some(Tablebase {
+            positions,
+            canonical,
+            variant: Variant::Standard,
+        })
+    }
+
+    // Converts a child's evaluation into the parent's point of view
+    fn from_child(child: Evaluation) -> Evaluation {
+        let outcome = match child.outcome {
+            Outcome::Win => Outcome::Loss,
+            Outcome::Loss => Outcome::Win,
+            Outcome::Draw => Outcome::Draw,
+        };
+        Evaluation {
+            outcome,
+            distance: child.distance + 1,
+        }
+    }
+
+    pub fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    pub fn get(&self, board: GameState) -> Option<Evaluation> {
+        self.get_raw(board.get_raw())
+    }
+
+    pub fn get_raw(&self, raw: u32) -> Option<Evaluation> {
+        self.positions.get(&raw).copied()
+    }
+
+    /// Looks up a position by its symmetry-reduced board.
+    pub fn get_canonical(&self, board: GameState) -> Option<Evaluation> {
+        self.canonical
+            .get(&simplified_board(board).get_raw())
+            .copied()
+    }
+
+    /// Picks the move that leads to the best evaluation for the side to move.
+    pub fn best_move(&self, board: GameState) -> Option<usize> {
+        board
+            .empty_positions()
+            .into_iter()
+            .filter_map(|pos| {
+                let mut child = board;
+                child.auto_set(pos).unwrap();
+                let evaluation = Self::from_child(self.get(child)?);
+                Some((pos, evaluation.rank()))
+            })
+            .max_by_key(|&(pos, rank)| (rank, std::cmp::Reverse(pos)))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn canonical_len(&self) -> usize {
+        self.canonical.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    pub fn canonical_iter(&self) -> impl Iterator<Item = (u32, Evaluation)> + '_ {
+        self.canonical
+            .iter()
+            .map(|(raw, evaluation)| (*raw, *evaluation))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (GameState, Evaluation)> + '_ {
+        self.positions
+            .iter()
+            .map(|(raw, evaluation)| (GameState::try_from(*raw).unwrap(), *evaluation))
+    }
+}
+
+impl MoveLookup<GameState> for Tablebase {
+    fn best_move(&self, board: GameState) -> Option<usize> {
+        Tablebase::best_move(self, board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_solve_counts() {
+        let tablebase = Tablebase::solve();
+        assert_eq!(tablebase.len(), 5478);
+        assert_eq!(tablebase.canonical_len(), 765);
+    }
+
+    #[test]
+    fn test_evaluations() {
+        let tablebase = Tablebase::solve();
+        let test_cases = vec![
+            ("         ", Outcome::Draw, 9),
+            ("XX OO    ", Outcome::Win, 1),
+            ("X XOX   O", Outcome::Loss, 2),
+            ("XXXOO    ", Outcome::Loss, 0),
+            ("XXOOOXXOX", Outcome::Draw, 0),
+        ];
+        for (board_str, outcome, distance) in test_cases {
+            let board = GameState::from_str(board_str).unwrap();
+            let expected = Evaluation { outcome, distance };
+            assert_eq!(tablebase.get(board), Some(expected), "{}", board_str);
+            assert_eq!(
+                tablebase.get_canonical(board.transform(1, true)),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_best_move() {
+        let tablebase = Tablebase::solve();
+        let test_cases = vec![
+            ("XX OO    ", Some(2)),
+            ("XX  O    ", Some(2)),
+            ("XXXOO    ", None),
+        ];
+        for (board_str, expected) in test_cases {
+            let board = GameState::from_str(board_str).unwrap();
+            assert_eq!(tablebase.best_move(board), expected, "{}", board_str);
+        }
+    }
+
+    #[test]
+    fn test_misere() {
+        let tablebase = Tablebase::solve_variant(Variant::Misere);
+        assert_eq!(tablebase.variant(), Variant::Misere);
+        assert_eq!(tablebase.len(), 5478);
+        // The first player can force a draw, but only by taking the center
+        let empty = GameState::new();
+        assert_eq!(tablebase.get(empty).unwrap().outcome, Outcome::Draw);
+        assert_eq!(tablebase.best_move(empty), Some(4));
+        let corner = GameState::from_str("X        ").unwrap();
+        assert_eq!(tablebase.get(corner).unwrap().outcome, Outcome::Win);
+        // Completing a line loses, so the side to move has won
+        let board = GameState::from_str("XXXOO    ").unwrap();
+        assert_eq!(tablebase.get(board).unwrap().outcome, Outcome::Win);
+        // X has to avoid 2 and O has already lost
+        let board = GameState::from_str("XX OO    ").unwrap();
+        assert_ne!(tablebase.best_move(board), Some(2));
+    }
+}
@@ -0,0 +1,177 @@
+use crate::game::consts::{BOARD_SIZE, POSITION_MAP, TRANSFORM_SHIFTS};
+use crate::game::errors::TicTacToeError;
+use std::fmt;
+
+/// One of the 8 symmetries of the board: an optional top to bottom flip followed by
+/// clockwise quarter turns, like `GameState::transform`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symmetry {
+    rotations: u8,
+    flip: bool,
+}
+
+impl Symmetry {
+    pub const IDENTITY: Symmetry = Symmetry {
+        rotations: 0,
+        flip: false,
+    };
+
+    /// Every symmetry, in the order of `TRANSFORM_SHIFTS`.
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::IDENTITY,
+        Symmetry {
+            rotations: 1,
+            flip: false,
+        },
+        Symmetry {
+            rotations: 2,
+            flip: false,
+        },
+        Symmetry {
+            rotations: 3,
+            flip: false,
+        },
+        Symmetry {
+            rotations: 0,
+            flip: true,
+        },
+        Symmetry {
+            rotations: 1,
+            flip: true,
+        },
+        Symmetry {
+            rotations: 2,
+            flip: true,
+        },
+        Symmetry {
+            rotations: 3,
+            flip: true,
+        },
+    ];
+
+    pub fn new(rotations: i32, flip: bool) -> Symmetry {
+        Symmetry {
+            rotations: rotations.rem_euclid(4) as u8,
+            flip,
+        }
+    }
+
+    pub fn rotations(&self) -> i32 {
+        self.rotations as i32
+    }
+
+    pub fn flip(&self) -> bool {
+        self.flip
+    }
+
+    /// The index of this symmetry in `ALL` and `TRANSFORM_SHIFTS`.
+    pub fn index(&self) -> usize {
+        self.rotations as usize + if self.flip { 4 } else { 0 }
+    }
+
+    /// The symmetry that applies `self` and then `other`.
+    pub fn then(&self, other: Symmetry) -> Symmetry {
+        // A flip reverses the direction of the rotations made before it
+        let rotations = if other.flip {
+            other.rotations() - self.rotations()
+        } else {
+            other.rotations() + self.rotations()
+        };
+        Symmetry::new(rotations, self.flip != other.flip)
+    }
+
+    /// The symmetry that undoes this one. A flip followed by rotations is a reflection,
+    /// which is its own inverse.
+    pub fn inverse(&self) -> Symmetry {
+        if self.flip {
+            *self
+        } else {
+            Symmetry::new(-self.rotations(), false)
+        }
+    }
+
+    /// Moves every cell of a raw 3x3 board.
+    pub fn apply(&self, raw: u32) -> u32 {
+        let mut new_raw = 0;
+        for (translation, bit_mask) in TRANSFORM_SHIFTS[self.index()].iter() {
+            if *translation < 0 {
+                new_raw |= (raw & *bit_mask) << (translation.abs() * 2);
+            } else if *translation > 0 {
+                new_raw |= (raw & *bit_mask) >> (translation.abs() * 2);
+            } else {
+                new_raw |= raw & *bit_mask;
+            }
+        }
+        new_raw
+    }
+
+    /// Where the piece at `pos` ends up under this symmetry.
+    pub fn map_position(&self, pos: usize) -> Result<usize, TicTacToeError> {
+        if pos >= BOARD_SIZE {
+            return Err(TicTacToeError::OutOfBounds);
+        }
+        let cell = self.apply(1 << (POSITION_MAP[pos] * 2)).trailing_zeros() as usize / 2;
+        Ok(POSITION_MAP.iter().position(|&c| c == cell).unwrap())
+    }
+}
+
+impl Default for Symmetry {
+    fn default() -> Self {
+        Symmetry::IDENTITY
+    }
+}
+
+impl fmt::Display for Symmetry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.flip {
+            write!(f, "flip, ")?;
+        }
+        write!(f, "rotate {} degrees", self.rotations as u32 * 90)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::game_state::GameState;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_all_matches_transform() {
+        let board = GameState::from_str("X O XOXO ").unwrap();
+        for (i, symmetry) in Symmetry::ALL.iter().enumerate() {
+            assert_eq!(symmetry.index(), i);
+            assert_eq!(Symmetry::new(i as i32 % 4, i > 3), *symmetry);
+            let transformed = board.transform(symmetry.rotations(), symmetry.flip());
+            assert_eq!(symmetry.apply(board.get_raw()), transformed.get_raw());
+        }
+        assert_eq!(Symmetry::new(-1, false), Symmetry::ALL[3]);
+    }
+
+    #[test]
+    fn test_then_and_inverse() {
+        let board = GameState::from_str("X O XOXO ").unwrap().get_raw();
+        for a in Symmetry::ALL.iter() {
+            assert_eq!(a.inverse().apply(a.apply(board)), board, "{}", a);
+            assert_eq!(a.then(a.inverse()), Symmetry::IDENTITY, "{}", a);
+            for b in Symmetry::ALL.iter() {
+                assert_eq!(a.then(*b).apply(board), b.apply(a.apply(board)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_map_position() {
+        let board = GameState::from_str("X O XOXO ").unwrap();
+        for symmetry in Symmetry::ALL.iter() {
+            let transformed = board.apply(*symmetry);
+            for pos in 0..BOARD_SIZE {
+                let new_pos = symmetry.map_position(pos).unwrap();
+                assert_eq!(transformed.get(new_pos).unwrap(), board.get(pos).unwrap());
+                assert_eq!(symmetry.inverse().map_position(new_pos).unwrap(), pos);
+            }
+        }
+        assert_eq!(Symmetry::new(1, false).map_position(0).unwrap(), 2);
+        assert!(Symmetry::IDENTITY.map_position(9).is_err());
+    }
+}
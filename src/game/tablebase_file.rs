@@ -0,0 +1,235 @@
+// Binary layout (all integers little endian):
+//
+// magic         4 bytes  "TTTB"
+// version       u16
+// board size    u8
+// position map  BOARD_SIZE bytes, POSITION_MAP from consts.rs
+// entry count   u32
+// entries       raw u32, outcome u8, distance u8 (sorted by raw)
+// checksum      u32 FNV-1a over every preceding byte
+
+use crate::game::board::Variant;
+use crate::game::consts::{BOARD_SIZE, POSITION_MAP};
+use crate::game::errors::TablebaseError;
+use crate::game::solver::{Evaluation, Outcome, Tablebase};
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+const MAGIC: &[u8; 4] = b"TTTB";
+pub const TABLEBASE_VERSION: u16 = 1;
+const ENTRY_SIZE: usize = 6;
+
+fn checksum(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c9dc5, |hash, &byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    })
+}
+
+fn outcome_to_byte(outcome: Outcome) -> u8 {
+    match outcome {
+        Outcome::Win => 0,
+        Outcome::Loss => 1,
+        Outcome::Draw => 2,
+    }
+}
+
+fn byte_to_outcome(byte: u8) -> Result<Outcome, TablebaseError> {
+    match byte {
+        0 => Ok(Outcome::Win),
+        1 => Ok(Outcome::Loss),
+        2 => Ok(Outcome::Draw),
+        _ => Err(TablebaseError::Corrupt),
+    }
+}
+
+// Splits `len` bytes off the front of the buffer
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Result<&'a [u8], TablebaseError> {
+    if bytes.len() < len {
+        return Err(TablebaseError::Corrupt);
+    }
+    let (head, tail) = bytes.split_at(len);
+    *bytes = tail;
+    Ok(head)
+}
+
+impl Tablebase {
+    /// Writes a standard tablebase. The file has no room for a variant, so any other would
+    /// be read back as standard.
+    pub fn write_to<W: Write>(&self, writer: &mut W) -> Result<(), TablebaseError> {
+        if self.variant() != Variant::Standard {
+            return Err(TablebaseError::UnsupportedVariant(
+                self.variant().name().to_string(),
+            ));
+        }
+        let mut entries = self.canonical_iter().collect::<Vec<_>>();
+        entries.sort_by_key(|(raw, _)| *raw);
+        let mut bytes = Vec::with_capacity(16 + BOARD_SIZE + entries.len() * ENTRY_SIZE);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&TABLEBASE_VERSION.to_le_bytes());
+        bytes.push(BOARD_SIZE as u8);
+        bytes.extend(POSITION_MAP.iter().map(|&p| p as u8));
+        bytes.extend_from_slice(&(entries.len() as u32).to_le_bytes());
+        for (raw, evaluation) in entries {
+            bytes.extend_from_slice(&raw.to_le_bytes());
+            bytes.push(outcome_to_byte(evaluation.outcome));
+            bytes.push(evaluation.distance);
+        }
+        let sum = checksum(&bytes);
+        bytes.extend_from_slice(&sum.to_le_bytes());
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    pub fn read_from<R: Read>(reader: &mut R) -> Result<Tablebase, TablebaseError> {
+        let mut buffer = vec![];
+        reader.read_to_end(&mut buffer)?;
+        if buffer.len() < MAGIC.len() || &buffer[..MAGIC.len()] != MAGIC {
+            return Err(TablebaseError::InvalidMagic);
+        }
+        if buffer.len() < MAGIC.len() + 2 + 4 {
+            return Err(TablebaseError::Corrupt);
+        }
+        let (body, sum) = buffer.split_at(buffer.len() - 4);
+        if checksum(body) != u32::from_le_bytes(sum.try_into().unwrap()) {
+            return Err(TablebaseError::ChecksumMismatch);
+        }
+
+        let mut bytes = &body[MAGIC.len()..];
+        let version = u16::from_le_bytes(take(&mut bytes, 2)?.try_into().unwrap());
+        if version != TABLEBASE_VERSION {
+            return Err(TablebaseError::UnsupportedVersion(version));
+        }
+        let board_size = take(&mut bytes, 1)?[0] as usize;
+        let position_map = take(&mut bytes, board_size)?;
+        if board_size != BOARD_SIZE
+            || position_map
+                .iter()
+                .zip(POSITION_MAP.iter())
+                .any(|(&a, &b)| a as usize != b)
+        {
+            return Err(TablebaseError::MappingMismatch);
+        }
+        let count = u32::from_le_bytes(take(&mut bytes, 4)?.try_into().unwrap()) as usize;
+        if bytes.len() != count * ENTRY_SIZE {
+            return Err(TablebaseError::Corrupt);
+        }
+
+        let mut canonical = HashMap::with_capacity(count);
+        for entry in bytes.chunks_exact(ENTRY_SIZE) {
+            let raw = u32::from_le_bytes(entry[..4].try_into().unwrap());
+            let evaluation = Evaluation {
+                outcome: byte_to_outcome(entry[4])?,
+                distance: entry[5],
+            };
+            if canonical.insert(raw, evaluation).is_some() {
+                return Err(TablebaseError::Corrupt);
+            }
+        }
+        Tablebase::from_canonical(canonical).ok_or(TablebaseError::Corrupt)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), TablebaseError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Tablebase, TablebaseError> {
+        Tablebase::read_from(&mut BufReader::new(File::open(path)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::game_state::GameState;
+
+    fn solved_bytes() -> Vec<u8> {
+        let mut bytes = vec![];
+        Tablebase::solve().write_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    // Rewrites the trailing checksum so only the targeted field is invalid
+    fn resign(bytes: &mut [u8]) {
+        let len = bytes.len() - 4;
+        let sum = checksum(&bytes[..len]);
+        bytes[len..].copy_from_slice(&sum.to_le_bytes());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let tablebase = Tablebase::solve();
+        let bytes = solved_bytes();
+        assert_eq!(
+            bytes.len(),
+            4 + 2 + 1 + BOARD_SIZE + 4 + 765 * ENTRY_SIZE + 4
+        );
+        let loaded = Tablebase::read_from(&mut bytes.as_slice()).unwrap();
+        assert_eq!(loaded.len(), tablebase.len());
+        assert_eq!(loaded.canonical_len(), tablebase.canonical_len());
+        for (board, evaluation) in tablebase.iter() {
+            assert_eq!(loaded.get(board), Some(evaluation));
+        }
+        assert_eq!(
+            loaded.get(GameState::new()),
+            tablebase.get(GameState::new())
+        );
+    }
+
+    #[test]
+    fn test_rejects_variants() {
+        let tablebase = Tablebase::solve_variant(Variant::Misere);
+        assert!(matches!(
+            tablebase.write_to(&mut vec![]),
+            Err(TablebaseError::UnsupportedVariant(name)) if name == "Misere"
+        ));
+    }
+
+    #[test]
+    fn test_rejects_bad_files() {
+        let bytes = solved_bytes();
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        assert!(matches!(
+            Tablebase::read_from(&mut bad_magic.as_slice()),
+            Err(TablebaseError::InvalidMagic)
+        ));
+
+        let mut flipped = bytes.clone();
+        flipped[20] ^= 1;
+        assert!(matches!(
+            Tablebase::read_from(&mut flipped.as_slice()),
+            Err(TablebaseError::ChecksumMismatch)
+        ));
+
+        let mut old_version = bytes.clone();
+        old_version[4] = 0;
+        resign(&mut old_version);
+        assert!(matches!(
+            Tablebase::read_from(&mut old_version.as_slice()),
+            Err(TablebaseError::UnsupportedVersion(0))
+        ));
+
+        let mut remapped = bytes.clone();
+        remapped.swap(7, 8);
+        resign(&mut remapped);
+        assert!(matches!(
+            Tablebase::read_from(&mut remapped.as_slice()),
+            Err(TablebaseError::MappingMismatch)
+        ));
+
+        let mut truncated = bytes[..bytes.len() - 4 - ENTRY_SIZE].to_vec();
+        truncated.extend_from_slice(&[0; 4]);
+        resign(&mut truncated);
+        assert!(matches!(
+            Tablebase::read_from(&mut truncated.as_slice()),
+            Err(TablebaseError::Corrupt)
+        ));
+    }
+}
@@ -0,0 +1,611 @@
+use crate::game::board::Board;
+use crate::game::game::Game;
+use crate::game::game_state::{GameState, PlayerEnum};
+use crate::game::player::Player;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Builds an entrant's player for one game, or says why it couldn't.
+pub type PlayerFactory<B> = Box<dyn Fn() -> Result<Box<dyn Player<B>>, String>>;
+
+const BASE_RATING: f64 = 1500.0;
+// z-score of a 95% confidence interval
+const CONFIDENCE_Z: f64 = 1.96;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Every entrant plays every other entrant.
+    RoundRobin,
+    /// The first entrant plays every other entrant, who don't play each other.
+    Gauntlet,
+}
+
+struct Entrant<B: Board> {
+    name: String,
+    factory: PlayerFactory<B>,
+}
+
+pub struct Tournament<B: Board = GameState> {
+    entrants: Vec<Entrant<B>>,
+    format: Format,
+    games_per_pairing: usize,
+}
+
+impl<B: Board> Tournament<B> {
+    pub fn new(format: Format) -> Tournament<B> {
+        Tournament {
+            entrants: vec![],
+            format,
+            games_per_pairing: 2,
+        }
+    }
+
+    /// Adds an entrant whose players are built fresh for every game. An entrant whose
+    /// player can't be built forfeits that game.
+    pub fn add(mut self, name: &str, factory: PlayerFactory<B>) -> Tournament<B> {
+        self.entrants.push(Entrant {
+            name: name.to_string(),
+            factory,
+        });
+        self
+    }
+
+    /// Colors alternate between games, so an even number gives both sides the same
+    /// number of games as X.
+    pub fn games_per_pairing(mut self, games: usize) -> Tournament<B> {
+        self.games_per_pairing = games;
+        self
+    }
+
+    fn pairings(&self) -> Vec<(usize, usize)> {
+        let n = self.entrants.len();
+        match self.format {
+            Format::RoundRobin => (0..n)
+                .flat_map(|a| (a + 1..n).map(move |b| (a, b)))
+                .collect(),
+            Format::Gauntlet => (1..n).map(|b| (0, b)).collect(),
+        }
+    }
+
+    pub fn run(&self) -> TournamentResult {
+        let mut games = vec![];
+        let mut errors = vec![];
+        for (a, b) in self.pairings() {
+            for i in 0..self.games_per_pairing {
+                let (x, o) = if i % 2 == 0 { (a, b) } else { (b, a) };
+                let winner = match ((self.entrants[x].factory)(), (self.entrants[o].factory)()) {
+                    (Ok(x_player), Ok(o_player)) => Game::new(x_player, o_player).play(),
+                    (Ok(_), Err(e)) => {
+                        errors.push(e);
+                        Some(PlayerEnum::X)
+                    }
+                    (Err(e), Ok(_)) => {
+                        errors.push(e);
+                        Some(PlayerEnum::O)
+                    }
+                    // Nobody played, so there is no result to rate
+                    (Err(x_error), Err(o_error)) => {
+                        errors.extend([x_error, o_error]);
+                        continue;
+                    }
+                };
+                games.push(GameResult { x, o, winner });
+            }
+        }
+        let names = self.entrants.iter().map(|e| e.name.clone()).collect();
+        let mut result = TournamentResult::new(names, games);
+        result.errors = errors;
+        result
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameResult {
+    pub x: usize,
+    pub o: usize,
+    pub winner: Option<PlayerEnum>,
+}
+
+impl GameResult {
+    /// The points `player` earned from this game, or None if they didn't play in it.
+    fn score(&self, player: usize) -> Option<f64> {
+        let side = if player == self.x {
+            PlayerEnum::X
+        } else if player == self.o {
+            PlayerEnum::O
+        } else {
+            return None;
+        };
+        Some(match self.winner {
+            None => 0.5,
+            Some(winner) if winner == side => 1.0,
+            Some(_) => 0.0,
+        })
+    }
+
+    fn opponent(&self, player: usize) -> usize {
+        if player == self.x {
+            self.o
+        } else {
+            self.x
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Record {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+impl Record {
+    pub fn games(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    pub fn score(&self) -> f64 {
+        self.wins as f64 + self.draws as f64 / 2.0
+    }
+
+    fn add(&mut self, score: f64) {
+        if score == 1.0 {
+            self.wins += 1;
+        } else if score == 0.0 {
+            self.losses += 1;
+        } else {
+            self.draws += 1;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rating {
+    pub elo: f64,
+    /// Half the width of the 95% confidence interval.
+    pub margin: f64,
+}
+
+pub struct TournamentResult {
+    names: Vec<String>,
+    games: Vec<GameResult>,
+    // records[a][b] is a's record against b
+    records: Vec<Vec<Record>>,
+    ratings: Vec<Rating>,
+    errors: Vec<String>,
+}
+
+impl TournamentResult {
+    pub fn new(names: Vec<String>, games: Vec<GameResult>) -> TournamentResult {
+        let n = names.len();
+        let mut records = vec![vec![Record::default(); n]; n];
+        for game in games.iter() {
+            for player in [game.x, game.o] {
+                let score = game.score(player).unwrap();
+                records[player][game.opponent(player)].add(score);
+            }
+        }
+        let mut result = TournamentResult {
+            names,
+            games,
+            records,
+            ratings: vec![],
+            errors: vec![],
+        };
+        result.ratings = result.compute_ratings();
+        result
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn games(&self) -> &[GameResult] {
+        &self.games
+    }
+
+    /// Why players couldn't be built for the games their entrants forfeited.
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
+    pub fn record(&self, player: usize, opponent: usize) -> Record {
+        self.records[player][opponent]
+    }
+
+    pub fn total(&self, player: usize) -> Record {
+        let mut total = Record::default();
+        for record in self.records[player].iter() {
+            total.wins += record.wins;
+            total.draws += record.draws;
+            total.losses += record.losses;
+        }
+        total
+    }
+
+    pub fn rating(&self, player: usize) -> Rating {
+        self.ratings[player]
+    }
+
+    /// Player indices from the highest rating to the lowest.
+    pub fn standings(&self) -> Vec<usize> {
+        let mut order = (0..self.names.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| self.ratings[b].elo.total_cmp(&self.ratings[a].elo));
+        order
+    }
+
+    // The Elo difference that predicts an expected score of `p`
+    fn elo_difference(p: f64) -> f64 {
+        -400.0 * (1.0 / p - 1.0).log10()
+    }
+
+    // Each rating is the performance rating against the average of its opponents'
+    // ratings, iterated until it settles and then centered on BASE_RATING. A perfect or
+    // zero score is treated as half a game short of it so the rating stays finite.
+    fn compute_ratings(&self) -> Vec<Rating> {
+        let n = self.names.len();
+        let mut elos = vec![0.0; n];
+        let mut differences = vec![0.0; n];
+        let mut margins = vec![0.0; n];
+        for player in 0..n {
+            let scores = self
+                .games
+                .iter()
+                .filter_map(|g| g.score(player))
+                .collect::<Vec<_>>();
+            if scores.is_empty() {
+                continue;
+            }
+            let count = scores.len() as f64;
+            let p = (scores.iter().sum::<f64>() / count).clamp(0.5 / count, 1.0 - 0.5 / count);
+            let variance = scores.iter().map(|s| (s - p).powi(2)).sum::<f64>() / count;
+            let error = (variance / count).sqrt() * CONFIDENCE_Z;
+            let low = (p - error).max(0.5 / count);
+            let high = (p + error).min(1.0 - 0.5 / count);
+            differences[player] = Self::elo_difference(p);
+            margins[player] = (Self::elo_difference(high) - Self::elo_difference(low)) / 2.0;
+        }
+        for _ in 0..100 {
+            let previous = elos.clone();
+            for player in 0..n {
+                let opponents = self
+                    .games
+                    .iter()
+                    .filter(|g| g.score(player).is_some())
+                    .map(|g| previous[g.opponent(player)])
+                    .collect::<Vec<f64>>();
+                if !opponents.is_empty() {
+                    let average = opponents.iter().sum::<f64>() / opponents.len() as f64;
+                    // Half steps keep a gauntlet from oscillating between its two sides
+                    elos[player] = (previous[player] + average + differences[player]) / 2.0;
+                }
+            }
+            let mean = elos.iter().sum::<f64>() / n as f64;
+            elos.iter_mut().for_each(|e| *e -= mean);
+        }
+        elos.iter()
+            .zip(margins)
+            .map(|(elo, margin)| Rating {
+                elo: BASE_RATING + elo,
+                margin,
+            })
+            .collect()
+    }
+
+    /// One row per player: name, games, wins, draws, losses, score, elo and margin.
+    pub fn to_csv(&self) -> String {
+        let mut csv = "player,games,wins,draws,losses,score,elo,margin\n".to_string();
+        for player in self.standings() {
+            let total = self.total(player);
+            let rating = self.ratings[player];
+            csv += &format!(
+                "{},{},{},{},{},{},{:.0},{:.0}\n",
+                csv_field(&self.names[player]),
+                total.games(),
+                total.wins,
+                total.draws,
+                total.losses,
+                total.score(),
+                rating.elo,
+                rating.margin
+            );
+        }
+        csv
+    }
+
+    /// The standings, every pairing's record and every game.
+    pub fn to_json(&self) -> String {
+        let players = self
+            .standings()
+            .into_iter()
+            .map(|player| {
+                let total = self.total(player);
+                let rating = self.ratings[player];
+                format!(
+                    "{{\"name\": {}, \"games\": {}, \"wins\": {}, \"draws\": {}, \"losses\": {}, \"score\": {}, \"elo\": {:.1}, \"margin\": {:.1}}}",
+                    json_string(&self.names[player]),
+                    total.games(),
+                    total.wins,
+                    total.draws,
+                    total.losses,
+                    total.score(),
+                    rating.elo,
+                    rating.margin
+                )
+            })
+            .collect::<Vec<_>>();
+        let mut pairings = vec![];
+        for (a, row) in self.records.iter().enumerate() {
+            for (b, record) in row.iter().enumerate() {
+                if a < b && record.games() > 0 {
+                    pairings.push(format!(
+                        "{{\"player\": {}, \"opponent\": {}, \"wins\": {}, \"draws\": {}, \"losses\": {}}}",
+                        json_string(&self.names[a]),
+                        json_string(&self.names[b]),
+                        record.wins,
+                        record.draws,
+                        record.losses
+                    ));
+                }
+            }
+        }
+        let games = self
+            .games
+            .iter()
+            .map(|g| {
+                let result = match g.winner {
+                    Some(PlayerEnum::X) => "1-0",
+                    Some(PlayerEnum::O) => "0-1",
+                    _ => "1/2-1/2",
+                };
+                format!(
+                    "{{\"x\": {}, \"o\": {}, \"result\": \"{}\"}}",
+                    json_string(&self.names[g.x]),
+                    json_string(&self.names[g.o]),
+                    result
+                )
+            })
+            .collect::<Vec<_>>();
+        format!(
+            "{{\n  \"players\": [\n    {}\n  ],\n  \"pairings\": [\n    {}\n  ],\n  \"games\": [\n    {}\n  ]\n}}\n",
+            players.join(",\n    "),
+            pairings.join(",\n    "),
+            games.join(",\n    ")
+        )
+    }
+
+    pub fn save_csv<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_csv())
+    }
+
+    pub fn save_json<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_json())
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = "\"".to_string();
+    for ch in value.chars() {
+        match ch {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            c if (c as u32) < 0x20 => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+    json + "\""
+}
+
+// A crosstable with each player's record against every opponent as +wins =draws -losses
+impl fmt::Display for TournamentResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let standings = self.standings();
+        let width = self.names.iter().map(|n| n.len()).max().unwrap_or(0).max(6);
+        let cell = |record: Record| {
+            if record.games() == 0 {
+                String::new()
+            } else {
+                format!("+{} ={} -{}", record.wins, record.draws, record.losses)
+            }
+        };
+        let cell_width = standings
+            .iter()
+            .flat_map(|&a| {
+                standings
+                    .iter()
+                    .map(move |&b| cell(self.records[a][b]).len())
+            })
+            .max()
+            .unwrap_or(0)
+            .max(3);
+        write!(
+            f,
+            "{:>3} {:<width$} {:>11} {:>6}",
+            "#",
+            "Player",
+            "Elo",
+            "Score",
+            width = width
+        )?;
+        for i in 1..=standings.len() {
+            write!(f, " | {:^cell_width$}", i, cell_width = cell_width)?;
+        }
+        writeln!(f)?;
+        for (rank, &a) in standings.iter().enumerate() {
+            let rating = self.ratings[a];
+            let total = self.total(a);
+            write!(
+                f,
+                "{:>3} {:<width$} {:>5.0} ± {:<3.0} {:>6}",
+                rank + 1,
+                self.names[a],
+                rating.elo,
+                rating.margin,
+                format!("{}/{}", total.score(), total.games()),
+                width = width
+            )?;
+            for &b in standings.iter() {
+                let text = if a == b {
+                    "-".to_string()
+                } else {
+                    cell(self.records[a][b])
+                };
+                write!(f, " | {:^cell_width$}", text, cell_width = cell_width)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::player::AIPlayer;
+
+    // Always plays the first empty square
+    struct FirstEmptyPlayer;
+
+    impl Player for FirstEmptyPlayer {
+        fn play_turn(&mut self, board: GameState) -> usize {
+            board.empty_positions()[0]
+        }
+    }
+
+    fn tournament(format: Format) -> Tournament {
+        Tournament::new(format)
+            .add("ai", Box::new(|| Ok(Box::new(AIPlayer::new()))))
+            .add("first", Box::new(|| Ok(Box::new(FirstEmptyPlayer))))
+            .add("ai2", Box::new(|| Ok(Box::new(AIPlayer::new()))))
+            .games_per_pairing(2)
+    }
+
+    #[test]
+    fn test_round_robin() {
+        let result = tournament(Format::RoundRobin).run();
+        assert_eq!(result.games().len(), 6);
+        // Colors alternate within a pairing
+        assert_eq!((result.games()[0].x, result.games()[1].x), (0, 1));
+        assert_eq!(
+            result.record(0, 1),
+            Record {
+                wins: 2,
+                draws: 0,
+                losses: 0
+            }
+        );
+        assert_eq!(
+            result.record(1, 0),
+            Record {
+                wins: 0,
+                draws: 0,
+                losses: 2
+            }
+        );
+        assert_eq!(
+            result.record(0, 2),
+            Record {
+                wins: 0,
+                draws: 2,
+                losses: 0
+            }
+        );
+        assert_eq!(result.total(1).score(), 0.0);
+        let standings = result.standings();
+        assert_eq!(standings[2], 1);
+        let (ai, ai2, first) = (result.rating(0), result.rating(2), result.rating(1));
+        assert!((ai.elo - ai2.elo).abs() < 1e-6);
+        assert!(ai.elo > first.elo + 200.0);
+        assert!(((ai.elo + ai2.elo + first.elo) / 3.0 - BASE_RATING).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_gauntlet() {
+        let result = tournament(Format::Gauntlet).run();
+        assert_eq!(result.games().len(), 4);
+        assert_eq!(result.record(1, 2).games(), 0);
+        assert_eq!(
+            result.total(0),
+            Record {
+                wins: 2,
+                draws: 2,
+                losses: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_failed_player_forfeits() {
+        let result = Tournament::new(Format::RoundRobin)
+            .add("first", Box::new(|| Ok(Box::new(FirstEmptyPlayer))))
+            .add(
+                "broken",
+                Box::new(|| Err("could not start broken".to_string())),
+            )
+            .run();
+        assert_eq!(result.total(0).wins, 2);
+        assert_eq!(result.total(1).losses, 2);
+        assert_eq!(result.errors(), ["could not start broken"; 2]);
+    }
+
+    #[test]
+    fn test_failed_players_skip_the_game() {
+        let result = Tournament::<GameState>::new(Format::RoundRobin)
+            .add(
+                "broken",
+                Box::new(|| Err("could not start broken".to_string())),
+            )
+            .add(
+                "broken2",
+                Box::new(|| Err("could not start broken2".to_string())),
+            )
+            .run();
+        assert!(result.games().is_empty());
+        assert_eq!(result.errors().len(), 4);
+        for player in 0..2 {
+            assert_eq!(result.total(player), Record::default());
+            assert_eq!(result.rating(player).elo, BASE_RATING);
+        }
+    }
+
+    #[test]
+    fn test_exports() {
+        let result = TournamentResult::new(
+            vec!["a".to_string(), "b, \"c\"".to_string()],
+            vec![
+                GameResult {
+                    x: 0,
+                    o: 1,
+                    winner: Some(PlayerEnum::X),
+                },
+                GameResult {
+                    x: 1,
+                    o: 0,
+                    winner: None,
+                },
+            ],
+        );
+        let csv = result.to_csv();
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("a,2,1,1,0,1.5,"));
+        assert!(lines[2].starts_with("\"b, \"\"c\"\"\",2,0,1,1,0.5,"));
+        let json = result.to_json();
+        assert!(json.contains("{\"x\": \"a\", \"o\": \"b, \\\"c\\\"\", \"result\": \"1-0\"}"));
+        assert!(json.contains("{\"player\": \"a\", \"opponent\": \"b, \\\"c\\\"\", \"wins\": 1, \"draws\": 1, \"losses\": 0}"));
+        let table = result.to_string();
+        assert!(table.contains("+1 =1 -0"));
+        assert_eq!(table.lines().count(), 3);
+    }
+}
@@ -0,0 +1,235 @@
+use crate::game::board::Board;
+use crate::game::game_state::PlayerEnum;
+use std::marker::PhantomData;
+
+/// How a stored value relates to the true value of its position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bound {
+    Exact,
+    /// The search failed high, so the true value is at least this.
+    Lower,
+    /// The search failed low, so the true value is at most this.
+    Upper,
+}
+
+/// What a search learned about one position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Entry {
+    /// How many plies were searched below the position.
+    pub depth: i32,
+    pub bound: Bound,
+    pub value: f32,
+    pub best_move: Option<usize>,
+}
+
+struct Slot {
+    key: u64,
+    entry: Entry,
+}
+
+/// A fixed size table of search results keyed by Zobrist key, so a position reached by
+/// different move orders is only searched once. When two positions share a slot the one
+/// searched deeper is kept.
+pub struct TranspositionTable<B: Board> {
+    slots: Vec<Option<Slot>>,
+    symmetric: bool,
+    // Where each position goes under each of `symmetries()`, and back, when symmetric
+    moves: Vec<Vec<usize>>,
+    inverse_moves: Vec<Vec<usize>>,
+    len: usize,
+    board: PhantomData<B>,
+}
+
+impl<B: Board> TranspositionTable<B> {
+    /// A table with room for `capacity` entries, rounded up to a power of two.
+    pub fn new(capacity: usize) -> TranspositionTable<B> {
+        let mut slots = vec![];
+        slots.resize_with(capacity.max(1).next_power_of_two(), || None);
+        TranspositionTable {
+            slots,
+            symmetric: false,
+            moves: vec![],
+            inverse_moves: vec![],
+            len: 0,
+            board: PhantomData,
+        }
+    }
+
+    /// Shares entries between boards that are equivalent by symmetry. Best moves are
+    /// translated to and from the stored board.
+    pub fn symmetric(mut self, symmetric: bool) -> TranspositionTable<B> {
+        self.symmetric = symmetric;
+        if symmetric && self.moves.is_empty() {
+            let (moves, inverse_moves) = Self::move_maps();
+            self.moves = moves;
+            self.inverse_moves = inverse_moves;
+        }
+        self
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+        self.len = 0;
+    }
+
+    // The key `board` is stored under and which of its symmetries that key belongs to
+    fn key(&self, board: B) -> (u64, usize) {
+        if !self.symmetric {
+            return (board.zobrist_key(), 0);
+        }
+        let (index, canonical) = board
+            .symmetries()
+            .into_iter()
+            .enumerate()
+            .min_by_key(|(_, b)| b.get_raw())
+            .unwrap();
+        (canonical.zobrist_key(), index)
+    }
+
+    fn slot(&self, key: u64) -> usize {
+        key as usize & (self.slots.len() - 1)
+    }
+
+    // Follows a single piece through every symmetry, once for each position
+    fn move_maps() -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
+        let mut moves = vec![vec![]; B::new().symmetries().len()];
+        for pos in 0..B::SIZE {
+            let mut board = B::new();
+            board.set(pos, PlayerEnum::X).unwrap();
+            for (index, transformed) in board.symmetries().into_iter().enumerate() {
+                let mapped = (0..B::SIZE)
+                    .find(|&p| matches!(transformed.get(p), Ok(PlayerEnum::X)))
+                    .unwrap();
+                moves[index].push(mapped);
+            }
+        }
+        let inverse_moves = moves
+            .iter()
+            .map(|mapped| {
+                let mut inverse = vec![0; B::SIZE];
+                for (pos, &to) in mapped.iter().enumerate() {
+                    inverse[to] = pos;
+                }
+                inverse
+            })
+            .collect();
+        (moves, inverse_moves)
+    }
+
+    pub fn get(&self, board: B) -> Option<Entry> {
+        let (key, index) = self.key(board);
+        let slot = self.slots[self.slot(key)].as_ref()?;
+        if slot.key != key {
+            return None;
+        }
+        let mut entry = slot.entry;
+        if index != 0 {
+            entry.best_move = entry.best_move.map(|pos| self.inverse_moves[index][pos]);
+        }
+        Some(entry)
+    }
+
+    pub fn store(&mut self, board: B, mut entry: Entry) {
+        let (key, index) = self.key(board);
+        if index != 0 {
+            entry.best_move = entry.best_move.map(|pos| self.moves[index][pos]);
+        }
+        let i = self.slot(key);
+        match &self.slots[i] {
+            None => self.len += 1,
+            Some(slot) if slot.key != key && slot.entry.depth > entry.depth => return,
+            Some(_) => {}
+        }
+        self.slots[i] = Some(Slot { key, entry });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::game_state::GameState;
+    use crate::game::mnk_state::MnkState;
+    use std::str::FromStr;
+
+    fn entry(depth: i32, best_move: Option<usize>) -> Entry {
+        Entry {
+            depth,
+            bound: Bound::Exact,
+            value: 1.0,
+            best_move,
+        }
+    }
+
+    #[test]
+    fn test_store_and_get() {
+        let mut table = TranspositionTable::new(100);
+        assert_eq!(table.capacity(), 128);
+        let board = GameState::from_str("X   O    ").unwrap();
+        assert_eq!(table.get(board), None);
+        table.store(board, entry(3, Some(8)));
+        assert_eq!(table.get(board), Some(entry(3, Some(8))));
+        assert_eq!(table.get(board.transform(1, false)), None);
+        assert_eq!(table.len(), 1);
+        table.clear();
+        assert!(table.is_empty());
+        assert_eq!(table.get(board), None);
+    }
+
+    #[test]
+    fn test_deeper_entries_are_kept() {
+        let mut table = TranspositionTable::new(1);
+        let a = GameState::from_str("X        ").unwrap();
+        let b = GameState::from_str(" X       ").unwrap();
+        table.store(a, entry(5, None));
+        table.store(b, entry(2, None));
+        assert_eq!(table.get(a), Some(entry(5, None)));
+        assert_eq!(table.get(b), None);
+        table.store(b, entry(5, None));
+        assert_eq!(table.get(a), None);
+        assert_eq!(table.get(b), Some(entry(5, None)));
+        // The same position is always replaced
+        table.store(b, entry(1, Some(4)));
+        assert_eq!(table.get(b), Some(entry(1, Some(4))));
+    }
+
+    #[test]
+    fn test_symmetric_keys() {
+        let mut table = TranspositionTable::new(64).symmetric(true);
+        let board = GameState::from_str("X   O    ").unwrap();
+        // X in the corner, O in the center, X takes the opposite corner
+        table.store(board, entry(3, Some(8)));
+        for i in 0..8 {
+            let (rotations, flip) = (i % 4, i > 3);
+            let transformed = board.transform(rotations, flip);
+            let best_move = table.get(transformed).unwrap().best_move.unwrap();
+            let mut expected = GameState::new();
+            expected.set(8, PlayerEnum::X).unwrap();
+            let expected = expected.transform(rotations, flip);
+            assert_eq!(expected.get(best_move).unwrap(), PlayerEnum::X);
+        }
+        // Moves are mapped the same way on bigger boards
+        let mut table = TranspositionTable::new(64).symmetric(true);
+        let board = MnkState::<4, 4, 3>::from_str("XO              ").unwrap();
+        table.store(board, entry(3, Some(6)));
+        let transformed = board.transform(1, true);
+        let best_move = table.get(transformed).unwrap().best_move.unwrap();
+        let mut expected = MnkState::<4, 4, 3>::new();
+        expected.set(6, PlayerEnum::X).unwrap();
+        assert_eq!(
+            expected.transform(1, true).get(best_move).unwrap(),
+            PlayerEnum::X
+        );
+    }
+}
@@ -0,0 +1,505 @@
+use crate::game::board::Board;
+use crate::game::consts::BOARD_SIZE;
+use crate::game::errors::TicTacToeError;
+use crate::game::game_state::{GameState, PlayerEnum};
+use crate::game::symmetry::Symmetry;
+use crate::game::zobrist;
+use itertools::Itertools;
+use std::fmt;
+use std::str::FromStr;
+
+// The state key for a free move, after the keys for the nine sub-boards
+const FREE_MOVE_KEY: usize = BOARD_SIZE;
+
+/// Ultimate tic-tac-toe: a 3x3 grid of 3x3 boards. The cell a piece is played in picks the
+/// sub-board the opponent has to play in next, and winning three sub-boards in a row wins
+/// the game. When the picked sub-board is already won or full the move is free.
+///
+/// Positions are numbered row by row across the whole 9x9 grid like any other board, so
+/// `empty_positions` only gives the moves the rules allow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UltimateState {
+    boards: [GameState; BOARD_SIZE],
+    // The winner of each sub-board. Tied sub-boards stay empty
+    meta: GameState,
+    // The sub-board the next move has to be played in, if it is not a free move
+    target: Option<usize>,
+    // The Zobrist key of the pieces and the target, kept up to date by `set`
+    key: u64,
+}
+
+impl UltimateState {
+    pub const SIZE: usize = BOARD_SIZE * BOARD_SIZE;
+
+    pub fn new() -> UltimateState {
+        UltimateState::from_parts([GameState::new(); BOARD_SIZE], None)
+    }
+
+    fn from_parts(boards: [GameState; BOARD_SIZE], target: Option<usize>) -> UltimateState {
+        let mut meta = GameState::new();
+        for (i, board) in boards.iter().enumerate() {
+            if let Some((player, _)) = board.winner() {
+                meta.set(i, player).unwrap();
+            }
+        }
+        let mut state = UltimateState {
+            boards,
+            meta,
+            target,
+            key: 0,
+        };
+        state.key = zobrist::board_key((0..Self::SIZE).map(|pos| {
+            let (board, cell) = Self::split(pos);
+            state.boards[board].get(cell).unwrap() as u32
+        })) ^ Self::target_key(target);
+        state
+    }
+
+    /// The sub-board and the cell within it of a position on the 9x9 grid.
+    pub fn split(pos: usize) -> (usize, usize) {
+        let (row, col) = (pos / 9, pos % 9);
+        (row / 3 * 3 + col / 3, row % 3 * 3 + col % 3)
+    }
+
+    /// The position on the 9x9 grid of a cell of a sub-board.
+    pub fn join(board: usize, cell: usize) -> usize {
+        (board / 3 * 3 + cell / 3) * 9 + board % 3 * 3 + cell % 3
+    }
+
+    fn target_key(target: Option<usize>) -> u64 {
+        zobrist::state_key(target.unwrap_or(FREE_MOVE_KEY))
+    }
+
+    fn is_decided(board: &GameState) -> bool {
+        board.is_over()
+    }
+
+    pub fn sub_board(&self, index: usize) -> Result<GameState, TicTacToeError> {
+        self.boards
+            .get(index)
+            .copied()
+            .ok_or(TicTacToeError::OutOfBounds)
+    }
+
+    /// The board of sub-board winners that decides the game.
+    pub fn meta_board(&self) -> GameState {
+        self.meta
+    }
+
+    /// The sub-board the next move has to be played in, or `None` for a free move.
+    pub fn target(&self) -> Option<usize> {
+        self.target
+    }
+
+    /// The sub-boards the next move can be played in.
+    pub fn open_boards(&self) -> Vec<usize> {
+        if self.is_winner(PlayerEnum::X) || self.is_winner(PlayerEnum::O) {
+            return vec![];
+        }
+        match self.target {
+            Some(board) => vec![board],
+            None => (0..BOARD_SIZE)
+                .filter(|&i| !Self::is_decided(&self.boards[i]))
+                .collect(),
+        }
+    }
+
+    pub fn legal_moves(&self) -> Vec<usize> {
+        self.open_boards()
+            .into_iter()
+            .flat_map(|board| {
+                self.boards[board]
+                    .empty_positions()
+                    .into_iter()
+                    .map(move |cell| Self::join(board, cell))
+            })
+            .sorted()
+            .collect()
+    }
+
+    /// Plays a piece, following the rules for which sub-board it can go in.
+    pub fn set(&mut self, pos: usize, value: PlayerEnum) -> Result<(), TicTacToeError> {
+        if pos >= Self::SIZE {
+            return Err(TicTacToeError::OutOfBounds);
+        }
+        let (board, cell) = Self::split(pos);
+        if value == PlayerEnum::None
+            || !self.boards[board].is_empty(cell)?
+            || !self.open_boards().contains(&board)
+        {
+            return Err(TicTacToeError::IllegalMove);
+        }
+        self.boards[board].set(cell, value)?;
+        if self.boards[board].is_winner(value) {
+            self.meta.set(board, value)?;
+        }
+        let target = if Self::is_decided(&self.boards[cell]) {
+            None
+        } else {
+            Some(cell)
+        };
+        self.key ^= zobrist::piece_key(pos, value as u32)
+            ^ Self::target_key(self.target)
+            ^ Self::target_key(target);
+        self.target = target;
+        Ok(())
+    }
+
+    pub fn get(&self, pos: usize) -> Result<PlayerEnum, TicTacToeError> {
+        if pos >= Self::SIZE {
+            return Err(TicTacToeError::OutOfBounds);
+        }
+        let (board, cell) = Self::split(pos);
+        self.boards[board].get(cell)
+    }
+
+    pub fn get_raw(&self) -> ([u32; BOARD_SIZE], Option<usize>) {
+        let mut raw = [0; BOARD_SIZE];
+        for (r, board) in raw.iter_mut().zip(self.boards.iter()) {
+            *r = board.get_raw();
+        }
+        (raw, self.target)
+    }
+
+    /// A hash of the pieces and the target sub-board, updated incrementally as pieces
+    /// are set.
+    pub fn zobrist_key(&self) -> u64 {
+        self.key
+    }
+
+    pub fn is_winner(&self, player: PlayerEnum) -> bool {
+        self.meta.is_winner(player)
+    }
+
+    pub fn is_tie(&self) -> bool {
+        !self.is_winner(PlayerEnum::X)
+            && !self.is_winner(PlayerEnum::O)
+            && self.boards.iter().all(Self::is_decided)
+    }
+
+    /// Applies the same symmetry to the grid of sub-boards and to each sub-board.
+    pub fn transform(&self, rotations: i32, flip: bool) -> UltimateState {
+        let symmetry = Symmetry::new(rotations, flip);
+        let mut boards = [GameState::new(); BOARD_SIZE];
+        for (i, board) in self.boards.iter().enumerate() {
+            boards[symmetry.map_position(i).unwrap()] = board.apply(symmetry);
+        }
+        let target = self.target.map(|t| symmetry.map_position(t).unwrap());
+        UltimateState::from_parts(boards, target)
+    }
+
+    // Pieces on a sub-board's winning line are shown in green, like `GameState`, and empty
+    // cells that can't be played are left blank when numbered
+    fn display_cells(&self, numbered: bool, cell_width: usize) -> Vec<String> {
+        let legal_moves = self.legal_moves();
+        (0..Self::SIZE)
+            .map(|pos| {
+                let (board, cell) = Self::split(pos);
+                let piece = self.boards[board].get(cell).unwrap();
+                let padding = " ".repeat(cell_width - 1);
+                let winning = self.boards[board]
+                    .winner()
+                    .is_some_and(|(_, lines)| lines.concat().contains(&cell));
+                if piece == PlayerEnum::None && numbered && legal_moves.contains(&pos) {
+                    format!("{:>width$}", pos + 1, width = cell_width)
+                } else if winning {
+                    padding + &format!("\x1b[92m{}\x1b[0m", piece)
+                } else if numbered && piece != PlayerEnum::None {
+                    padding + &format!("\x1b[93m{}\x1b[0m", piece)
+                } else {
+                    padding + &piece.to_string()
+                }
+            })
+            .collect()
+    }
+
+    // Sub-boards are separated by double lines
+    fn grid(cells: Vec<String>, cell_width: usize) -> String {
+        let thin = vec!["-".repeat(cell_width + 2); 3].join("+");
+        let thin = vec![thin; 3].join("||");
+        let thick = "=".repeat(thin.len());
+        cells
+            .chunks(9)
+            .enumerate()
+            .map(|(row, cells)| {
+                let line = cells
+                    .chunks(3)
+                    .map(|group| group.iter().map(|c| format!(" {} ", c)).join("|"))
+                    .join("||");
+                match row {
+                    0 => line,
+                    _ if row % 3 == 0 => format!("{}\n{}", thick, line),
+                    _ => format!("{}\n{}", thin, line),
+                }
+            })
+            .join("\n")
+    }
+
+    pub fn get_display(&self) -> String {
+        Self::grid(self.display_cells(false, 1), 1)
+    }
+
+    pub fn get_numbered_display(&self) -> String {
+        Self::grid(self.display_cells(true, 2), 2)
+    }
+}
+
+impl Default for UltimateState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Board for UltimateState {
+    type Raw = ([u32; BOARD_SIZE], Option<usize>);
+    const SIZE: usize = UltimateState::SIZE;
+
+    fn new() -> Self {
+        UltimateState::new()
+    }
+
+    fn set(&mut self, pos: usize, value: PlayerEnum) -> Result<(), TicTacToeError> {
+        UltimateState::set(self, pos, value)
+    }
+
+    fn get(&self, pos: usize) -> Result<PlayerEnum, TicTacToeError> {
+        UltimateState::get(self, pos)
+    }
+
+    fn get_raw(&self) -> Self::Raw {
+        UltimateState::get_raw(self)
+    }
+
+    fn zobrist_key(&self) -> u64 {
+        self.key
+    }
+
+    fn is_winner(&self, player: PlayerEnum) -> bool {
+        UltimateState::is_winner(self, player)
+    }
+
+    fn is_tie(&self) -> bool {
+        UltimateState::is_tie(self)
+    }
+
+    fn transform(&self, rotations: i32, flip: bool) -> Self {
+        UltimateState::transform(self, rotations, flip)
+    }
+
+    fn get_display(&self) -> String {
+        UltimateState::get_display(self)
+    }
+
+    fn get_numbered_display(&self) -> String {
+        UltimateState::get_numbered_display(self)
+    }
+
+    fn empty_positions(&self) -> Vec<usize> {
+        self.legal_moves()
+    }
+}
+
+/// The 81 cells row by row, followed by `/` and the 1-based target sub-board when the
+/// next move is not free.
+impl fmt::Display for UltimateState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for pos in 0..Self::SIZE {
+            write!(f, "{}", self.get(pos).unwrap())?;
+        }
+        match self.target {
+            Some(target) => write!(f, "/{}", target + 1),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for UltimateState {
+    type Err = TicTacToeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (cells, target) = match value.split_once('/') {
+            Some((cells, target)) => match target.parse::<usize>() {
+                Ok(target) if (1..=BOARD_SIZE).contains(&target) => (cells, Some(target - 1)),
+                _ => return Err(TicTacToeError::InvalidBoard),
+            },
+            None => (value, None),
+        };
+        if cells.chars().count() != Self::SIZE {
+            return Err(TicTacToeError::InvalidBoard);
+        }
+        // Sub-boards can hold any mix of pieces, so they are filled without the rules
+        let mut boards = [GameState::new(); BOARD_SIZE];
+        let (mut x_count, mut o_count) = (0, 0);
+        for (pos, ch) in cells.chars().enumerate() {
+            let piece = PlayerEnum::from_str(ch.to_string().as_str())?;
+            match piece {
+                PlayerEnum::X => x_count += 1,
+                PlayerEnum::O => o_count += 1,
+                PlayerEnum::None => {}
+            }
+            let (board, cell) = Self::split(pos);
+            boards[board].set(cell, piece)?;
+        }
+        let state = UltimateState::from_parts(boards, target);
+        let both_won = state.is_winner(PlayerEnum::X) && state.is_winner(PlayerEnum::O);
+        let decided_target = target.is_some_and(|t| Self::is_decided(&boards[t]));
+        if !(x_count == o_count || x_count == o_count + 1) || both_won || decided_target {
+            return Err(TicTacToeError::InvalidBoard);
+        }
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::game::Game;
+    use crate::game::mcts::MctsPlayer;
+    use crate::game::player::{Player, RandomPlayer};
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    // A board with the given sub-board positions filled, alternating X and O
+    fn played(moves: &[(usize, usize)]) -> UltimateState {
+        let mut state = UltimateState::new();
+        for &(board, cell) in moves {
+            state.auto_set(UltimateState::join(board, cell)).unwrap();
+        }
+        state
+    }
+
+    #[test]
+    fn test_split_and_join() {
+        assert_eq!(UltimateState::split(0), (0, 0));
+        assert_eq!(UltimateState::split(4), (1, 1));
+        assert_eq!(UltimateState::split(40), (4, 4));
+        assert_eq!(UltimateState::split(80), (8, 8));
+        for pos in 0..UltimateState::SIZE {
+            let (board, cell) = UltimateState::split(pos);
+            assert_eq!(UltimateState::join(board, cell), pos);
+        }
+    }
+
+    #[test]
+    fn test_sends_to_matching_board() {
+        let mut state = UltimateState::new();
+        assert_eq!(state.legal_moves().len(), 81);
+        // X plays the center of the top left board, so O has to play in the center board
+        state.auto_set(UltimateState::join(0, 4)).unwrap();
+        assert_eq!(state.target(), Some(4));
+        let moves = state.legal_moves();
+        assert_eq!(moves.len(), 9);
+        assert!(moves.iter().all(|&pos| UltimateState::split(pos).0 == 4));
+        assert!(matches!(
+            state.auto_set(UltimateState::join(0, 0)),
+            Err(TicTacToeError::IllegalMove)
+        ));
+        state.auto_set(UltimateState::join(4, 0)).unwrap();
+        assert_eq!(state.target(), Some(0));
+        assert!(matches!(
+            state.auto_set(UltimateState::join(0, 4)),
+            Err(TicTacToeError::IllegalMove)
+        ));
+    }
+
+    #[test]
+    fn test_free_move() {
+        // O keeps sending X back to the top left board until X wins it
+        let state = played(&[(4, 4), (4, 0), (0, 1), (1, 0), (0, 2), (2, 0)]);
+        assert_eq!(state.target(), Some(0));
+        let mut state = state;
+        state.auto_set(UltimateState::join(0, 0)).unwrap();
+        assert!(state.sub_board(0).unwrap().is_winner(PlayerEnum::X));
+        assert_eq!(state.meta_board().get(0).unwrap(), PlayerEnum::X);
+        // O is sent to the won board, so can play anywhere else
+        assert_eq!(state.target(), None);
+        assert_eq!(state.open_boards(), (1..9).collect::<Vec<_>>());
+        assert!(state
+            .legal_moves()
+            .iter()
+            .all(|&pos| UltimateState::split(pos).0 != 0));
+        state.auto_set(UltimateState::join(5, 5)).unwrap();
+        assert_eq!(state.target(), Some(5));
+    }
+
+    #[test]
+    fn test_meta_board_decides() {
+        let mut cells = vec![' '; 81];
+        for cell in 0..3 {
+            for board in 0..3 {
+                cells[UltimateState::join(board, cell)] = 'X';
+            }
+            cells[UltimateState::join(3, cell + 3)] = 'O';
+            cells[UltimateState::join(4, cell + 3)] = 'O';
+            cells[UltimateState::join(8, [0, 1, 5][cell])] = 'O';
+        }
+        let state = UltimateState::from_str(&cells.iter().collect::<String>()).unwrap();
+        assert!(state.is_winner(PlayerEnum::X));
+        assert!(!state.is_winner(PlayerEnum::O));
+        assert!(state.is_over());
+        assert!(state.legal_moves().is_empty());
+        assert_eq!(state.meta_board().to_string(), "XXXOO    ");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let state = played(&[(0, 4), (4, 2)]);
+        let string = state.to_string();
+        assert!(string.ends_with("/3"));
+        assert_eq!(UltimateState::from_str(&string).unwrap(), state);
+        assert_eq!(
+            UltimateState::from_str(&" ".repeat(81)).unwrap(),
+            UltimateState::new()
+        );
+        assert!(UltimateState::from_str(&" ".repeat(80)).is_err());
+        assert!(UltimateState::from_str(&format!("{}/0", " ".repeat(81))).is_err());
+        assert!(UltimateState::from_str(&format!("XX{}", " ".repeat(79))).is_err());
+    }
+
+    #[test]
+    fn test_zobrist_key_and_transform() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut state = UltimateState::new();
+        for _ in 0..30 {
+            let pos = *state.legal_moves().choose(&mut rng).unwrap();
+            state.auto_set(pos).unwrap();
+            let rebuilt = UltimateState::from_str(&state.to_string()).unwrap();
+            assert_eq!(rebuilt.zobrist_key(), state.zobrist_key());
+        }
+        let rotated = state.transform(1, false);
+        assert_ne!(rotated.zobrist_key(), state.zobrist_key());
+        assert_eq!(rotated.transform(3, false), state);
+        assert_eq!(state.transform(0, true).transform(0, true), state);
+        // The move that was just played turns with the board, and so does its target
+        let target = Symmetry::new(1, false).map_position(state.target().unwrap());
+        assert_eq!(rotated.target(), target.ok());
+    }
+
+    #[test]
+    fn test_game() {
+        let random = Box::new(RandomPlayer::seeded(1));
+        let mcts = Box::new(MctsPlayer::seeded(2).iterations(100));
+        let mut game = Game::<UltimateState>::new(
+            mcts as Box<dyn Player<UltimateState>>,
+            random as Box<dyn Player<UltimateState>>,
+        );
+        let winner = game.play();
+        let history = game.history();
+        let last = history.last().unwrap().state;
+        assert!(last.is_over());
+        assert_eq!(
+            winner,
+            [PlayerEnum::X, PlayerEnum::O]
+                .iter()
+                .copied()
+                .find(|&p| last.is_winner(p))
+        );
+        // Every move after the first was played in the sub-board it was sent to
+        for pair in history.windows(2) {
+            if let Some(target) = pair[0].state.target() {
+                assert_eq!(UltimateState::split(pair[1].position).0, target);
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// Zobrist keys: every (position, piece) pair gets a fixed random number and a board's key
+// is the xor of the numbers for its pieces, so it can be updated one move at a time.
+
+use crate::game::consts::{BOARD_SIZE, PLAYER_O, PLAYER_X, POSITION_MAP};
+
+/// The most positions any board has keys for, the 81 of `UltimateState`.
+pub const MAX_CELLS: usize = 81;
+
+// How many keys there are for state other than pieces
+const STATE_KEY_COUNT: usize = 16;
+
+// splitmix64, so the keys are the same on every run without needing an RNG at compile time
+const fn next_key(state: u64) -> (u64, u64) {
+    let state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    (state, z ^ (z >> 31))
+}
+
+const fn piece_keys() -> [[u64; 2]; MAX_CELLS] {
+    let mut keys = [[0; 2]; MAX_CELLS];
+    let mut state = 0x5454_5454_5454_5454;
+    let mut pos = 0;
+    while pos < MAX_CELLS {
+        let (next_state, x_key) = next_key(state);
+        let (next_state, o_key) = next_key(next_state);
+        keys[pos] = [x_key, o_key];
+        state = next_state;
+        pos += 1;
+    }
+    keys
+}
+
+const PIECE_KEYS: [[u64; 2]; MAX_CELLS] = piece_keys();
+
+const fn state_keys() -> [u64; STATE_KEY_COUNT] {
+    let mut keys = [0; STATE_KEY_COUNT];
+    let mut state = 0x5354_4154_4553_5453;
+    let mut i = 0;
+    while i < STATE_KEY_COUNT {
+        let (next_state, key) = next_key(state);
+        keys[i] = key;
+        state = next_state;
+        i += 1;
+    }
+    keys
+}
+
+const STATE_KEYS: [u64; STATE_KEY_COUNT] = state_keys();
+
+/// The key for a piece at `pos`, given as its two bit cell value. Empty cells add nothing.
+pub const fn piece_key(pos: usize, piece: u32) -> u64 {
+    const X: u32 = PLAYER_X as u32;
+    const O: u32 = PLAYER_O as u32;
+    match piece {
+        X => PIECE_KEYS[pos][0],
+        O => PIECE_KEYS[pos][1],
+        _ => 0,
+    }
+}
+
+/// A key for state that is not a piece, like which sub-board `UltimateState` sends the
+/// next move to. `index` must be less than 16.
+pub const fn state_key(index: usize) -> u64 {
+    STATE_KEYS[index]
+}
+
+// The keys for every way of filling `CELLS` cells of a raw 3x3 board starting at `first`,
+// so that a whole board can be keyed with two lookups instead of a loop
+const fn raw_keys<const LEN: usize>(first: usize, cells: usize) -> [u64; LEN] {
+    let mut keys = [0; LEN];
+    let mut bits = 0;
+    while bits < LEN {
+        let mut cell = 0;
+        while cell < cells {
+            let mut pos = 0;
+            while POSITION_MAP[pos] != first + cell {
+                pos += 1;
+            }
+            keys[bits] ^= piece_key(pos, (bits >> (cell * 2) & 3) as u32);
+            cell += 1;
+        }
+        bits += 1;
+    }
+    keys
+}
+
+const LOW_CELLS: usize = 5;
+const LOW_RAW_KEYS: [u64; 1 << (LOW_CELLS * 2)] = raw_keys(0, LOW_CELLS);
+const HIGH_RAW_KEYS: [u64; 1 << ((BOARD_SIZE - LOW_CELLS) * 2)] =
+    raw_keys(LOW_CELLS, BOARD_SIZE - LOW_CELLS);
+
+/// The key for a raw 3x3 board, the same as keying its pieces one by one.
+pub fn raw_key(raw: u32) -> u64 {
+    let low = raw as usize & (LOW_RAW_KEYS.len() - 1);
+    let high = (raw >> (LOW_CELLS * 2)) as usize & (HIGH_RAW_KEYS.len() - 1);
+    LOW_RAW_KEYS[low] ^ HIGH_RAW_KEYS[high]
+}
+
+/// The key for a whole board, given the cell value at each position.
+pub fn board_key(pieces: impl Iterator<Item = u32>) -> u64 {
+    pieces
+        .enumerate()
+        .fold(0, |key, (pos, piece)| key ^ piece_key(pos, piece))
+}
@@ -1,92 +1,533 @@
-extern crate core;
+use itertools::Itertools;
+use std::cell::RefCell;
+use std::rc::Rc;
+use ttt::game::board::{Board, Variant};
+use ttt::game::board_iterator::{BoardIterator, PlyCounts};
+use ttt::game::cube_state::CubeState;
+use ttt::game::engine::{self, ExternalPlayer};
+use ttt::game::game::Game;
+use ttt::game::game_state::{GameState, PlayerEnum};
+use ttt::game::heuristic::HeuristicPlayer;
+use ttt::game::learning::{LearnedPlayer, Trainer, ValueTable};
+use ttt::game::mcts::MctsPlayer;
+use ttt::game::menace::{Matchboxes, MenacePlayer};
+use ttt::game::mnk_state::MnkState;
+use ttt::game::notakto::{NotaktoSolver, NotaktoState};
+use ttt::game::player::{AIPlayer, Difficulty, HumanPlayer, Player, RandomPlayer};
+use ttt::game::record::{Coordinates, GameRecord};
+use ttt::game::solver::{MoveLookup, Outcome, Tablebase};
+use ttt::game::tournament::{Format, Tournament};
+use ttt::game::transposition::TranspositionTable;
+use ttt::game::ultimate_state::UltimateState;
 
-mod game;
+fn board_eval() {
+    let counts = BoardIterator::new().canonical().counts();
+    for ply_counts in counts.iter() {
+        println!("{}", ply_counts);
+    }
+    let total = |f: fn(&PlyCounts) -> usize| counts.iter().map(f).sum::<usize>();
+    println!("Total: {}", total(PlyCounts::total));
+    println!("O winners: {}", total(|c| c.o_wins));
+    println!("X winners: {}", total(|c| c.x_wins));
+    println!("Ties: {}", total(|c| c.ties));
+    println!("Endings: {}", total(|c| c.x_wins + c.o_wins + c.ties));
+    println!("Incomplete: {}", total(|c| c.ongoing));
+    for tie_board in BoardIterator::new().canonical().filter(|b| b.is_tie()) {
+        println!("{}\n", tie_board.get_display());
+    }
+}
 
-use crate::game::board_iterator::BoardIterator;
-use crate::game::game::Game;
-use crate::game::game_state::{GameState, PlayerEnum};
-use crate::game::player::HumanPlayer;
-use itertools::Itertools;
-use std::time::{Duration, Instant};
-
-fn play_game() {
-    let mut b = GameState::new();
-    let mut turn_count = 0;
-    let mut player = PlayerEnum::X;
-    let mut line = String::new();
-    println!("{}", b.get_display());
-    while !(b.is_winner(PlayerEnum::X) || b.is_winner(PlayerEnum::O) || turn_count == 9) {
-        println!("Enter move (0-8): ");
-        let input = std::io::stdin().read_line(&mut line).unwrap();
-        let pos = line.trim().parse::<u32>().unwrap();
-        line.clear();
-        if pos > 9 || !b.is_empty(pos as usize).unwrap() {
-            println!("Invalid move!");
-            continue;
-        }
-        b.set(pos as usize, player).unwrap();
-        if matches!(player, PlayerEnum::X) {
-            player = PlayerEnum::O
-        } else {
-            player = PlayerEnum::X
+const TABLEBASE_PATH: &str = "tablebase.bin";
+
+// Loads the saved tablebase, solving and saving it again if it is missing or unusable
+fn load_tablebase(path: &str) -> Tablebase {
+    match Tablebase::load(path) {
+        Ok(tablebase) => return tablebase,
+        Err(e) => println!("Solving tablebase ({})", e),
+    }
+    let tablebase = Tablebase::solve();
+    if let Err(e) = tablebase.save(path) {
+        println!("Could not save tablebase to {}: {}", path, e);
+    }
+    tablebase
+}
+
+fn solve(path: &str) {
+    let tablebase = Tablebase::solve();
+    if let Err(e) = tablebase.save(path) {
+        println!("Could not save tablebase to {}: {}", path, e);
+    }
+    let count = |outcome| {
+        tablebase
+            .iter()
+            .filter(|(_, e)| e.outcome == outcome)
+            .count()
+    };
+    println!("Positions: {}", tablebase.len());
+    println!("Canonical positions: {}", tablebase.canonical_len());
+    println!("Wins for side to move: {}", count(Outcome::Win));
+    println!("Losses for side to move: {}", count(Outcome::Loss));
+    println!("Draws: {}", count(Outcome::Draw));
+    if let Some(evaluation) = tablebase.get(GameState::new()) {
+        println!(
+            "Empty board: {:?} in {} plies",
+            evaluation.outcome, evaluation.distance
+        );
+    }
+}
+
+const VALUES_PATH: &str = "values.txt";
+
+fn train(episodes: &str, path: &str) {
+    let episodes = match episodes.parse::<usize>() {
+        Ok(episodes) => episodes,
+        Err(_) => return usage(),
+    };
+    let table = ValueTable::load(path).unwrap_or_default();
+    let tablebase = Rc::new(load_tablebase(TABLEBASE_PATH));
+    let mut trainer = Trainer::new(table, rand::random())
+        .report_every((episodes / 10).max(1), 100)
+        .checkpoint(path, (episodes / 10).max(1))
+        .perfect_opponent(Box::new(move || {
+            Box::new(AIPlayer::with_tablebase(tablebase.clone()))
+        }));
+    match trainer.train(episodes) {
+        Ok(reports) => {
+            for report in reports {
+                let (random, perfect) = (report.vs_random, report.vs_perfect);
+                println!(
+                    "Episode {}: vs random +{} ={} -{}, vs perfect +{} ={} -{}",
+                    report.episode,
+                    random.wins,
+                    random.draws,
+                    random.losses,
+                    perfect.wins,
+                    perfect.draws,
+                    perfect.losses
+                );
+            }
+            println!("Values: {}", trainer.table().borrow().len());
         }
-        turn_count += 1;
-        println!("{}", b.get_display());
+        Err(e) => println!("Could not save values to {}: {}", path, e),
     }
-    if b.is_winner(PlayerEnum::X) {
-        println!("X wins!")
-    } else if b.is_winner(PlayerEnum::O) {
-        println!("O wins!")
-    } else {
-        println!("Tie :(")
+}
+
+// Entries for searches without a tablebase, on the bigger m,n,k boards
+const TRANSPOSITION_TABLE_SIZE: usize = 1 << 18;
+
+fn new_player<B: Board>(
+    name: &str,
+    tablebase: &Option<Rc<dyn MoveLookup<B>>>,
+    variant: Variant,
+) -> Option<Box<dyn Player<B>>> {
+    match name {
+        "human" => Some(Box::new(HumanPlayer::new().variant(variant))),
+        "random" => Some(Box::new(RandomPlayer::new().variant(variant))),
+        "ai" => match tablebase {
+            Some(tablebase) => Some(Box::new(
+                AIPlayer::with_tablebase(tablebase.clone()).variant(variant),
+            )),
+            None => {
+                let table = TranspositionTable::new(TRANSPOSITION_TABLE_SIZE).symmetric(true);
+                Some(Box::new(
+                    AIPlayer::new().transposition_table(table).variant(variant),
+                ))
+            }
+        },
+        _ if name.starts_with("ai:") => {
+            let difficulty = Difficulty::from_name(&name["ai:".len()..])?;
+            let ai = match tablebase {
+                Some(tablebase) => AIPlayer::with_tablebase(tablebase.clone()),
+                None => AIPlayer::new(),
+            };
+            Some(Box::new(ai.difficulty(difficulty).variant(variant)))
+        }
+        // The remaining players only know the standard rules
+        _ if variant != Variant::Standard => None,
+        "mcts" => Some(Box::new(MctsPlayer::new().iterations(20000))),
+        _ if name.starts_with("engine:") => {
+            let command = name["engine:".len()..].split_whitespace().collect_vec();
+            match ExternalPlayer::spawn(command.first()?, &command[1..]) {
+                Ok(player) => Some(Box::new(player)),
+                Err(e) => {
+                    println!("Could not start {}: {}", name, e);
+                    None
+                }
+            }
+        }
+        _ => None,
     }
 }
 
-pub fn get_true_board(board: GameState) -> GameState {
-    let num_transformations = 8;
-    let mut min_board = board.clone();
-    for i in 0..num_transformations {
-        let transformed_board = board.transform(i % 4, i > 3);
-        if transformed_board.get_raw() < min_board.get_raw() {
-            min_board = transformed_board
+// A full search only finishes on boards up to 4x4, so bigger ones get the depth limited
+// AI levels like the cubes
+fn play_mnk<B: Board>(names: &[&str]) {
+    play(names, Variant::Standard, |name| match name {
+        "ai" | "ai:impossible" if B::SIZE > 16 => None,
+        _ => new_player::<B>(name, &None, Variant::Standard),
+    });
+}
+
+// A full search can't finish on a cube, so only the depth limited AI levels play
+fn play_cube<B: Board>(names: &[&str]) {
+    play(names, Variant::Standard, |name| match name {
+        "ai" | "ai:impossible" => None,
+        _ => new_player::<B>(name, &None, Variant::Standard),
+    });
+}
+
+// Order has a choice of two pieces on 36 cells, too many for more than a shallow search
+fn play_order_and_chaos(names: &[&str]) {
+    let variant = Variant::OrderAndChaos;
+    play(names, variant, |name| match name {
+        "human" | "random" | "ai:easy" | "ai:medium" => {
+            new_player::<MnkState<6, 6, 5>>(name, &None, variant)
         }
+        _ => None,
+    });
+}
+
+// The searching AI can't see far enough into 81 cells to be useful
+fn play_ultimate(names: &[&str]) {
+    play(names, Variant::Standard, |name| match name {
+        "human" | "random" | "mcts" => new_player::<UltimateState>(name, &None, Variant::Standard),
+        _ => None,
+    });
+}
+
+// Both players place X, so the ai plays perfectly from a solver shared by both sides
+fn play_notakto<const N: usize>(names: &[&str]) {
+    let solver: Option<Rc<dyn MoveLookup<NotaktoState<N>>>> = Some(Rc::new(NotaktoSolver::new()));
+    play(names, Variant::Notakto, |name| {
+        new_player(name, &solver, Variant::Notakto)
+    });
+}
+
+const MENACE_PATH: &str = "menace.txt";
+
+// Players that only know the 3x3 board, and the state they share between games
+#[derive(Clone)]
+struct ClassicPlayers {
+    tablebase: Option<Rc<dyn MoveLookup<GameState>>>,
+    menace: Option<Rc<RefCell<Matchboxes>>>,
+}
+
+// Whether `player` would build a 3x3 player by name, without building it
+fn is_classic_player(name: &str) -> bool {
+    match name {
+        "human" | "random" | "ai" | "mcts" | "heuristic" | "menace" | "learned" => true,
+        _ if name.starts_with("ai:") => Difficulty::from_name(&name["ai:".len()..]).is_some(),
+        _ if name.starts_with("engine:") => !name["engine:".len()..].trim().is_empty(),
+        _ => false,
     }
-    return min_board;
 }
 
-fn board_eval() {
-    let boards = BoardIterator::new();
-    let unique_boards = boards.map(|b| get_true_board(b)).unique().collect_vec();
-    let o_winner_boards = unique_boards
-        .iter()
-        .filter(|b| b.is_winner(PlayerEnum::O))
-        .collect_vec();
-    let x_winner_boards = unique_boards
-        .iter()
-        .filter(|b| b.is_winner(PlayerEnum::X))
-        .collect_vec();
-    let tie_boards = unique_boards.iter().filter(|b| b.is_tie()).collect_vec();
-    println!("Total: {}", unique_boards.len());
-    println!("O winners: {}", o_winner_boards.len());
-    println!("X winners: {}", x_winner_boards.len());
-    println!("Ties: {}", tie_boards.len());
-    let endings = o_winner_boards.len() + x_winner_boards.len() + tie_boards.len();
-    println!("Endings: {}", endings);
-    let incomplete = unique_boards.len() - endings;
-    println!("Incomplete: {}", incomplete);
-    for tie_board in tie_boards {
-        println!("{}\n", tie_board.get_display());
+impl ClassicPlayers {
+    fn new<S: AsRef<str>>(names: &[S]) -> ClassicPlayers {
+        let uses = |name: &str| names.iter().any(|n| n.as_ref() == name);
+        let tablebase: Option<Rc<dyn MoveLookup<GameState>>> =
+            if uses("ai") || uses("ai:impossible") {
+                Some(Rc::new(load_tablebase(TABLEBASE_PATH)))
+            } else {
+                None
+            };
+        let menace = if uses("menace") {
+            let boxes = Matchboxes::load(MENACE_PATH).unwrap_or_default();
+            Some(Rc::new(RefCell::new(boxes)))
+        } else {
+            None
+        };
+        ClassicPlayers { tablebase, menace }
+    }
+
+    fn player(&self, name: &str) -> Option<Box<dyn Player>> {
+        match (name, &self.menace) {
+            ("heuristic", _) => Some(Box::new(HeuristicPlayer::new())),
+            ("menace", Some(boxes)) => Some(Box::new(MenacePlayer::new(boxes.clone()))),
+            ("learned", _) => {
+                let table = ValueTable::load(VALUES_PATH).unwrap_or_else(|e| {
+                    println!("Could not load {} ({}), playing untrained", VALUES_PATH, e);
+                    ValueTable::new()
+                });
+                Some(Box::new(LearnedPlayer::greedy(Rc::new(RefCell::new(
+                    table,
+                )))))
+            }
+            _ => new_player(name, &self.tablebase, Variant::Standard),
+        }
+    }
+
+    // MENACE keeps what it learned for the next session
+    fn save(&self) {
+        if let Some(boxes) = &self.menace {
+            if let Err(e) = boxes.borrow().save(MENACE_PATH) {
+                println!("Could not save matchboxes to {}: {}", MENACE_PATH, e);
+            }
+        }
     }
 }
 
-fn main() {
-    let mut p1 = Box::new(HumanPlayer::new());
-    let mut p2 = Box::new(HumanPlayer::new());
-    let mut game = Game::new(p1, p2);
-    match game.play() {
+fn print_menace(path: &str) {
+    match Matchboxes::load(path) {
+        Ok(boxes) => print!("{}", boxes),
+        Err(e) => println!("Could not load {}: {}", path, e),
+    }
+}
+
+fn play<B: Board, F>(names: &[&str], variant: Variant, new_player: F) -> Option<Game<B>>
+where
+    F: Fn(&str) -> Option<Box<dyn Player<B>>>,
+{
+    let p1 = new_player(names[0]);
+    let p2 = new_player(names[1]);
+    let (p1, p2) = match (p1, p2) {
+        (Some(p1), Some(p2)) => (p1, p2),
+        _ => {
+            usage();
+            return None;
+        }
+    };
+    let mut game = Game::new(p1, p2).with_variant(variant);
+    let winner = game.play();
+    println!("{}", game.game_state().get_display());
+    if let Some(player) = game.forfeited() {
+        println!("{} forfeits", player);
+    }
+    match winner {
         Some(PlayerEnum::X) => println!("X wins!"),
         Some(PlayerEnum::O) => println!("O wins!"),
         None => println!("Bummer! A tie..."),
         _ => {}
     }
+    Some(game)
+}
+
+// Today's date as YYYY.MM.DD, converted from days since the Unix epoch
+fn today() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let days = (secs / 86400) as i64 + 719468;
+    let era = days / 146097;
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    format!("{:04}.{:02}.{:02}", year, month, day)
+}
+
+fn record(game: &Game, names: &[&str], path: &str) {
+    let mut record = GameRecord::from_game(game, names[0], names[1]);
+    record.set_tag("Date", &today());
+    match record.save(path, Coordinates::Algebraic) {
+        Ok(_) => println!("Game saved to {}", path),
+        Err(e) => println!("Could not save game to {}: {}", path, e),
+    }
+}
+
+fn replay(path: &str) {
+    let record = match GameRecord::load(path) {
+        Ok(record) => record,
+        Err(e) => return println!("Could not replay {}: {}", path, e),
+    };
+    for (name, value) in record.tags() {
+        println!("{}: {}", name, value);
+    }
+    if let Ok(start) = record.start() {
+        println!("\n{}", start.get_display());
+    }
+    for (ply, board) in record.replay().unwrap_or_default().iter().enumerate() {
+        println!("\nPly {}\n{}", ply + 1, board.get_display());
+    }
+}
+
+// Takes the value following `flag` out of the arguments
+fn take_option(args: &mut Vec<String>, flag: &str) -> Result<Option<String>, ()> {
+    match args.iter().position(|a| a == flag) {
+        Some(i) if i + 1 < args.len() => {
+            let value = args.remove(i + 1);
+            args.remove(i);
+            Ok(Some(value))
+        }
+        Some(_) => Err(()),
+        None => Ok(None),
+    }
+}
+
+fn tournament(mut args: Vec<String>) {
+    let format = match args.iter().position(|a| a == "--gauntlet") {
+        Some(i) => {
+            args.remove(i);
+            Format::Gauntlet
+        }
+        None => Format::RoundRobin,
+    };
+    let (games, csv, json) = match (
+        take_option(&mut args, "--games"),
+        take_option(&mut args, "--csv"),
+        take_option(&mut args, "--json"),
+    ) {
+        (Ok(games), Ok(csv), Ok(json)) => (games, csv, json),
+        _ => return usage(),
+    };
+    let games = match games.map(|g| g.parse::<usize>()) {
+        Some(Ok(games)) => games,
+        Some(Err(_)) => return usage(),
+        None => 2,
+    };
+    if args.len() < 2 {
+        return usage();
+    }
+    // Players are only built for their games, so engines aren't started just to check them
+    if let Some(name) = args.iter().find(|name| !is_classic_player(name)) {
+        println!("Unknown player {}", name);
+        return usage();
+    }
+    if let Some(name) = args.iter().duplicates().next() {
+        println!("{} is entered more than once", name);
+        return usage();
+    }
+    let players = ClassicPlayers::new(&args);
+    let mut tournament = Tournament::new(format).games_per_pairing(games);
+    for name in args {
+        let players = players.clone();
+        let spec = name.clone();
+        tournament = tournament.add(
+            &name,
+            Box::new(move || {
+                players
+                    .player(&spec)
+                    .ok_or_else(|| format!("{} could not play and forfeited", spec))
+            }),
+        );
+    }
+    let result = tournament.run();
+    players.save();
+    print!("{}", result);
+    for error in result.errors().iter().unique() {
+        println!("{}", error);
+    }
+    if let Some(path) = csv {
+        if let Err(e) = result.save_csv(&path) {
+            println!("Could not save results to {}: {}", path, e);
+        }
+    }
+    if let Some(path) = json {
+        if let Err(e) = result.save_json(&path) {
+            println!("Could not save results to {}: {}", path, e);
+        }
+    }
+}
+
+// Stdout belongs to the protocol, so the tablebase is solved quietly if it can't be loaded
+fn run_engine() {
+    let tablebase = Tablebase::load(TABLEBASE_PATH).unwrap_or_else(|_| Tablebase::solve());
+    let mut ai = AIPlayer::with_tablebase(Rc::new(tablebase));
+    let stdin = std::io::stdin();
+    if let Err(e) = engine::serve(&mut ai, stdin.lock(), std::io::stdout()) {
+        eprintln!("{}", e);
+    }
+}
+
+fn usage() {
+    println!("Usage: ttt [--variant VARIANT] [--record FILE] [X-PLAYER] [O-PLAYER]");
+    println!("       ttt replay FILE");
+    println!("       ttt engine");
+    println!("       ttt train EPISODES [FILE]");
+    println!("       ttt menace [FILE]");
+    println!("       ttt tournament [--gauntlet] [--games N] [--csv FILE] [--json FILE] PLAYER...");
+    println!("       ttt eval");
+    println!("       ttt solve [FILE]");
+    println!(
+        "Players: human (default), ai, ai:LEVEL, random, mcts, learned (3x3), menace (3x3), heuristic (3x3), \"engine:COMMAND [ARGS]\""
+    );
+    println!(
+        "Variants: 3x3 (default), misere and wild (human, random and ai only), order-and-chaos (human, random, ai:easy and ai:medium), 4x4k3, 4x4k4, 5x5k4, 7x7k5, 3x3x3 and 4x4x4 (the last four with no full depth ai), ultimate (human, random and mcts only), notakto, notakto2 and notakto3 (1 to 3 boards; human, random and ai only)"
+    );
+    println!("Levels: easy, medium, hard, impossible (same as ai)");
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1).collect_vec();
+    match args.first().map(String::as_str) {
+        Some("eval") => return board_eval(),
+        Some("engine") => return run_engine(),
+        Some("train") => {
+            return match args.get(1) {
+                Some(episodes) => train(episodes, args.get(2).map_or(VALUES_PATH, String::as_str)),
+                None => usage(),
+            }
+        }
+        Some("menace") => return print_menace(args.get(1).map_or(MENACE_PATH, String::as_str)),
+        Some("tournament") => return tournament(args.split_off(1)),
+        Some("solve") => return solve(args.get(1).map_or(TABLEBASE_PATH, String::as_str)),
+        Some("replay") => {
+            return match args.get(1) {
+                Some(path) => replay(path),
+                None => usage(),
+            }
+        }
+        _ => {}
+    }
+    let mut variant = "3x3".to_string();
+    if let Some(i) = args.iter().position(|a| a == "--variant") {
+        match args.get(i + 1) {
+            Some(v) => variant = v.clone(),
+            None => return usage(),
+        }
+        args.drain(i..i + 2);
+    }
+    let mut record_path = None;
+    if let Some(i) = args.iter().position(|a| a == "--record") {
+        match args.get(i + 1) {
+            Some(path) => record_path = Some(path.clone()),
+            None => return usage(),
+        }
+        args.drain(i..i + 2);
+    }
+    let names = [
+        args.first().map_or("human", String::as_str),
+        args.get(1).map_or("human", String::as_str),
+    ];
+    match variant.as_str() {
+        "3x3" => {
+            let players = ClassicPlayers::new(&names);
+            let game = play(&names, Variant::Standard, |name| players.player(name));
+            players.save();
+            if let (Some(game), Some(path)) = (game, record_path) {
+                record(&game, &names, &path);
+            }
+        }
+        "misere" => {
+            let tablebase: Option<Rc<dyn MoveLookup<GameState>>> =
+                Some(Rc::new(Tablebase::solve_variant(Variant::Misere)));
+            let game = play(&names, Variant::Misere, |name| {
+                new_player(name, &tablebase, Variant::Misere)
+            });
+            if let (Some(game), Some(path)) = (game, record_path) {
+                record(&game, &names, &path);
+            }
+        }
+        _ if record_path.is_some() => println!("Only 3x3 and misere games can be recorded"),
+        "wild" => {
+            play(&names, Variant::Wild, |name| {
+                new_player(name, &None::<Rc<dyn MoveLookup<GameState>>>, Variant::Wild)
+            });
+        }
+        "order-and-chaos" => play_order_and_chaos(&names),
+        "4x4k3" => play_mnk::<MnkState<4, 4, 3>>(&names),
+        "4x4k4" => play_mnk::<MnkState<4, 4, 4>>(&names),
+        "5x5k4" => play_mnk::<MnkState<5, 5, 4>>(&names),
+        "7x7k5" => play_mnk::<MnkState<7, 7, 5>>(&names),
+        "3x3x3" => play_cube::<CubeState<3>>(&names),
+        "4x4x4" => play_cube::<CubeState<4>>(&names),
+        "ultimate" => play_ultimate(&names),
+        "notakto" => play_notakto::<1>(&names),
+        "notakto2" => play_notakto::<2>(&names),
+        "notakto3" => play_notakto::<3>(&names),
+        _ => usage(),
+    }
 }